@@ -0,0 +1,95 @@
+//! Generates the emoji lookup maps as perfect hash maps, so the static emoji
+//! data needs no hashing or allocation at startup. Without the `emoji-data`
+//! feature only the emoji of the gitmojis and the built-in devmojis are kept.
+
+use std::collections::HashSet;
+use std::env;
+use std::fs;
+use std::path::Path;
+
+const EMOJIS: &[(&str, &[&str])] = include!("data/github_emojis.rs");
+const GITMOJIS: &[(&str, &str, &str)] = include!("data/gitmojis.rs");
+const DEVMOJIS: &[(&str, &str, &str)] = include!("data/devmojis.rs");
+
+fn main() {
+    println!("cargo:rerun-if-changed=data/github_emojis.rs");
+    println!("cargo:rerun-if-changed=data/gitmojis.rs");
+    println!("cargo:rerun-if-changed=data/devmojis.rs");
+
+    let emojis: Vec<(&str, &[&str])> = if env::var_os("CARGO_FEATURE_EMOJI_DATA").is_some() {
+        EMOJIS.to_vec()
+    } else {
+        let gitmoji = |emoji: &str| {
+            let emoji = emoji.replace('\u{fe0f}', "");
+            GITMOJIS.iter().any(|(_, e, _)| e.replace('\u{fe0f}', "") == emoji)
+        };
+        EMOJIS
+            .iter()
+            .filter(|(emoji, codes)| {
+                gitmoji(emoji) || DEVMOJIS.iter().any(|(_, code, _)| codes.contains(code))
+            })
+            .copied()
+            .collect()
+    };
+    let emojis = emojis.as_slice();
+
+    // Shortcode, canonical or alias, to its emoji
+    let mut shortcodes = phf_codegen::Map::new();
+    for &(emoji, codes) in emojis {
+        for &code in codes {
+            shortcodes.entry(code, &format!("{:?}", emoji));
+        }
+    }
+
+    // Emoji, also without variation selectors, to the canonical shortcode:
+    // GitHub's, then the gitmojis GitHub doesn't know
+    let mut reverse: Vec<(String, &str)> = Vec::new();
+    let mut seen = HashSet::new();
+    let github = emojis.iter().map(|&(emoji, codes)| (emoji, codes[0]));
+    let gitmoji = GITMOJIS
+        .iter()
+        .filter(|(code, _, _)| !emojis.iter().any(|(_, codes)| codes.contains(code)))
+        .map(|&(code, emoji, _)| (emoji, code));
+    for (emoji, code) in github.chain(gitmoji) {
+        for emoji in [emoji.to_string(), emoji.replace('\u{fe0f}', "")] {
+            if seen.insert(emoji.clone()) {
+                reverse.push((emoji, code));
+            }
+        }
+    }
+    let mut emoji_codes = phf_codegen::Map::new();
+    for (emoji, code) in &reverse {
+        emoji_codes.entry(emoji.as_str(), &format!("{:?}", code));
+    }
+
+    let mut gitmoji_index = phf_codegen::Map::new();
+    for (i, &(code, _, _)) in GITMOJIS.iter().enumerate() {
+        gitmoji_index.entry(code, &i.to_string());
+    }
+
+    let table: String = emojis
+        .iter()
+        .map(|(emoji, codes)| format!("    ({:?}, &{:?}),\n", emoji, codes))
+        .collect();
+    let emoji_maps = format!(
+        "/// GitHub's emoji, each with its shortcodes. The first shortcode is the\n\
+         /// canonical one, used when converting an emoji back to a shortcode.\n\
+         pub static EMOJIS: &[(&str, &[&str])] = &[\n{}];\n\n\
+         /// Shortcode, canonical or alias, to its unicode emoji\n\
+         pub static GITHUB_EMOJIS: phf::Map<&'static str, &'static str> = {};\n\n\
+         /// Unicode emoji, also without variation selectors, to its canonical\n\
+         /// shortcode, GitHub's or the bundled gitmoji's\n\
+         pub static EMOJI_CODES: phf::Map<&'static str, &'static str> = {};\n",
+        table,
+        shortcodes.build(),
+        emoji_codes.build()
+    );
+    let gitmoji_maps = format!(
+        "/// Bundled gitmoji code to its index in the bundled table\n\
+         static BUNDLED_INDEX: phf::Map<&'static str, usize> = {};\n",
+        gitmoji_index.build()
+    );
+    let out = env::var("OUT_DIR").unwrap();
+    fs::write(Path::new(&out).join("emoji_maps.rs"), emoji_maps).unwrap();
+    fs::write(Path::new(&out).join("gitmoji_maps.rs"), gitmoji_maps).unwrap();
+}
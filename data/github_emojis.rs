@@ -0,0 +1,1874 @@
+// GitHub's emoji, each with its shortcodes, the canonical one first. Included
+// by build.rs, which generates the table and lookup maps of src/github_emoji.rs.
+&[
+    ("\u{1F44D}", &["+1", "thumbsup"]),
+    ("\u{1F44E}", &["-1", "thumbsdown"]),
+    ("\u{1F4AF}", &["100"]),
+    ("\u{1F522}", &["1234"]),
+    ("\u{1F947}", &["1st_place_medal"]),
+    ("\u{1F948}", &["2nd_place_medal"]),
+    ("\u{1F949}", &["3rd_place_medal"]),
+    ("\u{1F3B1}", &["8ball"]),
+    ("\u{1F170}\u{FE0F}", &["a"]),
+    ("\u{1F18E}", &["ab"]),
+    ("\u{1F9EE}", &["abacus"]),
+    ("\u{1F524}", &["abc"]),
+    ("\u{1F521}", &["abcd"]),
+    ("\u{1F251}", &["accept"]),
+    ("\u{1FA97}", &["accordion"]),
+    ("\u{1FA79}", &["adhesive_bandage"]),
+    ("\u{1F9D1}", &["adult"]),
+    ("\u{1F6A1}", &["aerial_tramway"]),
+    ("\u{1F1E6}\u{1F1EB}", &["afghanistan"]),
+    ("\u{2708}\u{FE0F}", &["airplane"]),
+    ("\u{1F1E6}\u{1F1FD}", &["aland_islands"]),
+    ("\u{23F0}", &["alarm_clock"]),
+    ("\u{1F1E6}\u{1F1F1}", &["albania"]),
+    ("\u{2697}\u{FE0F}", &["alembic"]),
+    ("\u{1F1E9}\u{1F1FF}", &["algeria"]),
+    ("\u{1F47D}", &["alien"]),
+    ("\u{1F691}", &["ambulance"]),
+    ("\u{1F1E6}\u{1F1F8}", &["american_samoa"]),
+    ("\u{1F3FA}", &["amphora"]),
+    ("\u{1FAC0}", &["anatomical_heart"]),
+    ("\u{2693}", &["anchor"]),
+    ("\u{1F1E6}\u{1F1E9}", &["andorra"]),
+    ("\u{1F47C}", &["angel"]),
+    ("\u{1F4A2}", &["anger"]),
+    ("\u{1F1E6}\u{1F1F4}", &["angola"]),
+    ("\u{1F620}", &["angry"]),
+    ("\u{1F1E6}\u{1F1EE}", &["anguilla"]),
+    ("\u{1F627}", &["anguished"]),
+    ("\u{1F41C}", &["ant"]),
+    ("\u{1F1E6}\u{1F1F6}", &["antarctica"]),
+    ("\u{1F1E6}\u{1F1EC}", &["antigua_barbuda"]),
+    ("\u{1F34E}", &["apple"]),
+    ("\u{2652}", &["aquarius"]),
+    ("\u{1F1E6}\u{1F1F7}", &["argentina"]),
+    ("\u{2648}", &["aries"]),
+    ("\u{1F1E6}\u{1F1F2}", &["armenia"]),
+    ("\u{25C0}\u{FE0F}", &["arrow_backward"]),
+    ("\u{23EC}", &["arrow_double_down"]),
+    ("\u{23EB}", &["arrow_double_up"]),
+    ("\u{2B07}\u{FE0F}", &["arrow_down"]),
+    ("\u{1F53D}", &["arrow_down_small"]),
+    ("\u{25B6}\u{FE0F}", &["arrow_forward"]),
+    ("\u{2935}\u{FE0F}", &["arrow_heading_down"]),
+    ("\u{2934}\u{FE0F}", &["arrow_heading_up"]),
+    ("\u{2B05}\u{FE0F}", &["arrow_left"]),
+    ("\u{2199}\u{FE0F}", &["arrow_lower_left"]),
+    ("\u{2198}\u{FE0F}", &["arrow_lower_right"]),
+    ("\u{27A1}\u{FE0F}", &["arrow_right"]),
+    ("\u{21AA}\u{FE0F}", &["arrow_right_hook"]),
+    ("\u{2B06}\u{FE0F}", &["arrow_up"]),
+    ("\u{2195}\u{FE0F}", &["arrow_up_down"]),
+    ("\u{1F53C}", &["arrow_up_small"]),
+    ("\u{2196}\u{FE0F}", &["arrow_upper_left"]),
+    ("\u{2197}\u{FE0F}", &["arrow_upper_right"]),
+    ("\u{1F503}", &["arrows_clockwise"]),
+    ("\u{1F504}", &["arrows_counterclockwise"]),
+    ("\u{1F3A8}", &["art"]),
+    ("\u{1F69B}", &["articulated_lorry"]),
+    ("\u{1F6F0}\u{FE0F}", &["artificial_satellite"]),
+    ("\u{1F9D1}\u{200D}\u{1F3A8}", &["artist"]),
+    ("\u{1F1E6}\u{1F1FC}", &["aruba"]),
+    ("\u{1F1E6}\u{1F1E8}", &["ascension_island"]),
+    ("\u{002A}\u{FE0F}\u{20E3}", &["asterisk"]),
+    ("\u{1F632}", &["astonished"]),
+    ("\u{1F9D1}\u{200D}\u{1F680}", &["astronaut"]),
+    ("\u{1F45F}", &["athletic_shoe"]),
+    ("\u{1F3E7}", &["atm"]),
+    ("\u{269B}\u{FE0F}", &["atom_symbol"]),
+    ("\u{1F1E6}\u{1F1FA}", &["australia"]),
+    ("\u{1F1E6}\u{1F1F9}", &["austria"]),
+    ("\u{1F6FA}", &["auto_rickshaw"]),
+    ("\u{1F951}", &["avocado"]),
+    ("\u{1FA93}", &["axe"]),
+    ("\u{1F1E6}\u{1F1FF}", &["azerbaijan"]),
+    ("\u{1F171}\u{FE0F}", &["b"]),
+    ("\u{1F476}", &["baby"]),
+    ("\u{1F37C}", &["baby_bottle"]),
+    ("\u{1F424}", &["baby_chick"]),
+    ("\u{1F6BC}", &["baby_symbol"]),
+    ("\u{1F519}", &["back"]),
+    ("\u{1F953}", &["bacon"]),
+    ("\u{1F9A1}", &["badger"]),
+    ("\u{1F3F8}", &["badminton"]),
+    ("\u{1F96F}", &["bagel"]),
+    ("\u{1F6C4}", &["baggage_claim"]),
+    ("\u{1F956}", &["baguette_bread"]),
+    ("\u{1F1E7}\u{1F1F8}", &["bahamas"]),
+    ("\u{1F1E7}\u{1F1ED}", &["bahrain"]),
+    ("\u{2696}\u{FE0F}", &["balance_scale"]),
+    ("\u{1F468}\u{200D}\u{1F9B2}", &["bald_man"]),
+    ("\u{1F469}\u{200D}\u{1F9B2}", &["bald_woman"]),
+    ("\u{1FA70}", &["ballet_shoes"]),
+    ("\u{1F388}", &["balloon"]),
+    ("\u{1F5F3}\u{FE0F}", &["ballot_box"]),
+    ("\u{2611}\u{FE0F}", &["ballot_box_with_check"]),
+    ("\u{1F38D}", &["bamboo"]),
+    ("\u{1F34C}", &["banana"]),
+    ("\u{203C}\u{FE0F}", &["bangbang"]),
+    ("\u{1F1E7}\u{1F1E9}", &["bangladesh"]),
+    ("\u{1FA95}", &["banjo"]),
+    ("\u{1F3E6}", &["bank"]),
+    ("\u{1F4CA}", &["bar_chart"]),
+    ("\u{1F1E7}\u{1F1E7}", &["barbados"]),
+    ("\u{1F488}", &["barber"]),
+    ("\u{26BE}", &["baseball"]),
+    ("\u{1F9FA}", &["basket"]),
+    ("\u{1F3C0}", &["basketball"]),
+    ("\u{1F987}", &["bat"]),
+    ("\u{1F6C0}", &["bath"]),
+    ("\u{1F6C1}", &["bathtub"]),
+    ("\u{1F50B}", &["battery"]),
+    ("\u{1F3D6}\u{FE0F}", &["beach_umbrella"]),
+    ("\u{1FAD8}", &["beans"]),
+    ("\u{1F43B}", &["bear"]),
+    ("\u{1F9D4}", &["bearded_person"]),
+    ("\u{1F9AB}", &["beaver"]),
+    ("\u{1F6CF}\u{FE0F}", &["bed"]),
+    ("\u{1F41D}", &["bee", "honeybee"]),
+    ("\u{1F37A}", &["beer"]),
+    ("\u{1F37B}", &["beers"]),
+    ("\u{1FAB2}", &["beetle"]),
+    ("\u{1F530}", &["beginner"]),
+    ("\u{1F1E7}\u{1F1FE}", &["belarus"]),
+    ("\u{1F1E7}\u{1F1EA}", &["belgium"]),
+    ("\u{1F1E7}\u{1F1FF}", &["belize"]),
+    ("\u{1F514}", &["bell"]),
+    ("\u{1FAD1}", &["bell_pepper"]),
+    ("\u{1F6CE}\u{FE0F}", &["bellhop_bell"]),
+    ("\u{1F1E7}\u{1F1EF}", &["benin"]),
+    ("\u{1F371}", &["bento"]),
+    ("\u{1F1E7}\u{1F1F2}", &["bermuda"]),
+    ("\u{1F9C3}", &["beverage_box"]),
+    ("\u{1F1E7}\u{1F1F9}", &["bhutan"]),
+    ("\u{1F6B4}", &["bicyclist"]),
+    ("\u{1F6B2}", &["bike"]),
+    ("\u{1F6B4}\u{200D}\u{2642}\u{FE0F}", &["biking_man"]),
+    ("\u{1F6B4}\u{200D}\u{2640}\u{FE0F}", &["biking_woman"]),
+    ("\u{1F459}", &["bikini"]),
+    ("\u{1F9E2}", &["billed_cap"]),
+    ("\u{2623}\u{FE0F}", &["biohazard"]),
+    ("\u{1F426}", &["bird"]),
+    ("\u{1F382}", &["birthday"]),
+    ("\u{1F9AC}", &["bison"]),
+    ("\u{1FAE6}", &["biting_lip"]),
+    ("\u{1F426}\u{200D}\u{2B1B}", &["black_bird"]),
+    ("\u{1F408}\u{200D}\u{2B1B}", &["black_cat"]),
+    ("\u{26AB}", &["black_circle"]),
+    ("\u{1F3F4}", &["black_flag"]),
+    ("\u{1F5A4}", &["black_heart"]),
+    ("\u{1F0CF}", &["black_joker"]),
+    ("\u{2B1B}", &["black_large_square"]),
+    ("\u{25FE}", &["black_medium_small_square"]),
+    ("\u{25FC}\u{FE0F}", &["black_medium_square"]),
+    ("\u{2712}\u{FE0F}", &["black_nib"]),
+    ("\u{25AA}\u{FE0F}", &["black_small_square"]),
+    ("\u{1F532}", &["black_square_button"]),
+    ("\u{1F471}\u{200D}\u{2642}\u{FE0F}", &["blond_haired_man"]),
+    ("\u{1F471}", &["blond_haired_person"]),
+    ("\u{1F471}\u{200D}\u{2640}\u{FE0F}", &["blond_haired_woman", "blonde_woman"]),
+    ("\u{1F33C}", &["blossom"]),
+    ("\u{1F421}", &["blowfish"]),
+    ("\u{1F4D8}", &["blue_book"]),
+    ("\u{1F699}", &["blue_car"]),
+    ("\u{1F499}", &["blue_heart"]),
+    ("\u{1F7E6}", &["blue_square"]),
+    ("\u{1FAD0}", &["blueberries"]),
+    ("\u{1F60A}", &["blush"]),
+    ("\u{1F417}", &["boar"]),
+    ("\u{26F5}", &["boat", "sailboat"]),
+    ("\u{1F1E7}\u{1F1F4}", &["bolivia"]),
+    ("\u{1F4A3}", &["bomb"]),
+    ("\u{1F9B4}", &["bone"]),
+    ("\u{1F4D6}", &["book", "open_book"]),
+    ("\u{1F516}", &["bookmark"]),
+    ("\u{1F4D1}", &["bookmark_tabs"]),
+    ("\u{1F4DA}", &["books"]),
+    ("\u{1F4A5}", &["boom", "collision"]),
+    ("\u{1FA83}", &["boomerang"]),
+    ("\u{1F462}", &["boot"]),
+    ("\u{1F1E7}\u{1F1E6}", &["bosnia_herzegovina"]),
+    ("\u{1F1E7}\u{1F1FC}", &["botswana"]),
+    ("\u{26F9}\u{FE0F}\u{200D}\u{2642}\u{FE0F}", &["bouncing_ball_man", "basketball_man"]),
+    ("\u{26F9}\u{FE0F}", &["bouncing_ball_person"]),
+    ("\u{26F9}\u{FE0F}\u{200D}\u{2640}\u{FE0F}", &["bouncing_ball_woman", "basketball_woman"]),
+    ("\u{1F490}", &["bouquet"]),
+    ("\u{1F1E7}\u{1F1FB}", &["bouvet_island"]),
+    ("\u{1F647}", &["bow"]),
+    ("\u{1F3F9}", &["bow_and_arrow"]),
+    ("\u{1F647}\u{200D}\u{2642}\u{FE0F}", &["bowing_man"]),
+    ("\u{1F647}\u{200D}\u{2640}\u{FE0F}", &["bowing_woman"]),
+    ("\u{1F963}", &["bowl_with_spoon"]),
+    ("\u{1F3B3}", &["bowling"]),
+    ("\u{1F94A}", &["boxing_glove"]),
+    ("\u{1F466}", &["boy"]),
+    ("\u{1F9E0}", &["brain"]),
+    ("\u{1F1E7}\u{1F1F7}", &["brazil"]),
+    ("\u{1F35E}", &["bread"]),
+    ("\u{1F931}", &["breast_feeding"]),
+    ("\u{1F9F1}", &["bricks"]),
+    ("\u{1F309}", &["bridge_at_night"]),
+    ("\u{1F4BC}", &["briefcase"]),
+    ("\u{1F1EE}\u{1F1F4}", &["british_indian_ocean_territory"]),
+    ("\u{1F1FB}\u{1F1EC}", &["british_virgin_islands"]),
+    ("\u{1F966}", &["broccoli"]),
+    ("\u{1F494}", &["broken_heart"]),
+    ("\u{1F9F9}", &["broom"]),
+    ("\u{1F7E4}", &["brown_circle"]),
+    ("\u{1F90E}", &["brown_heart"]),
+    ("\u{1F7EB}", &["brown_square"]),
+    ("\u{1F1E7}\u{1F1F3}", &["brunei"]),
+    ("\u{1F9CB}", &["bubble_tea"]),
+    ("\u{1FAE7}", &["bubbles"]),
+    ("\u{1FAA3}", &["bucket"]),
+    ("\u{1F41B}", &["bug"]),
+    ("\u{1F3D7}\u{FE0F}", &["building_construction"]),
+    ("\u{1F4A1}", &["bulb"]),
+    ("\u{1F1E7}\u{1F1EC}", &["bulgaria"]),
+    ("\u{1F685}", &["bullettrain_front"]),
+    ("\u{1F684}", &["bullettrain_side"]),
+    ("\u{1F1E7}\u{1F1EB}", &["burkina_faso"]),
+    ("\u{1F32F}", &["burrito"]),
+    ("\u{1F1E7}\u{1F1EE}", &["burundi"]),
+    ("\u{1F68C}", &["bus"]),
+    ("\u{1F574}\u{FE0F}", &["business_suit_levitating"]),
+    ("\u{1F68F}", &["busstop"]),
+    ("\u{1F464}", &["bust_in_silhouette"]),
+    ("\u{1F465}", &["busts_in_silhouette"]),
+    ("\u{1F9C8}", &["butter"]),
+    ("\u{1F98B}", &["butterfly"]),
+    ("\u{1F335}", &["cactus"]),
+    ("\u{1F370}", &["cake"]),
+    ("\u{1F4C6}", &["calendar"]),
+    ("\u{1F919}", &["call_me_hand"]),
+    ("\u{1F4F2}", &["calling"]),
+    ("\u{1F1F0}\u{1F1ED}", &["cambodia"]),
+    ("\u{1F42B}", &["camel"]),
+    ("\u{1F4F7}", &["camera"]),
+    ("\u{1F4F8}", &["camera_flash"]),
+    ("\u{1F1E8}\u{1F1F2}", &["cameroon"]),
+    ("\u{1F3D5}\u{FE0F}", &["camping"]),
+    ("\u{1F1E8}\u{1F1E6}", &["canada"]),
+    ("\u{1F1EE}\u{1F1E8}", &["canary_islands"]),
+    ("\u{264B}", &["cancer"]),
+    ("\u{1F56F}\u{FE0F}", &["candle"]),
+    ("\u{1F36C}", &["candy"]),
+    ("\u{1F96B}", &["canned_food"]),
+    ("\u{1F6F6}", &["canoe"]),
+    ("\u{1F1E8}\u{1F1FB}", &["cape_verde"]),
+    ("\u{1F520}", &["capital_abcd"]),
+    ("\u{2651}", &["capricorn"]),
+    ("\u{1F697}", &["car", "red_car"]),
+    ("\u{1F5C3}\u{FE0F}", &["card_file_box"]),
+    ("\u{1F4C7}", &["card_index"]),
+    ("\u{1F5C2}\u{FE0F}", &["card_index_dividers"]),
+    ("\u{1F1E7}\u{1F1F6}", &["caribbean_netherlands"]),
+    ("\u{1F3A0}", &["carousel_horse"]),
+    ("\u{1FA9A}", &["carpentry_saw"]),
+    ("\u{1F955}", &["carrot"]),
+    ("\u{1F938}", &["cartwheeling"]),
+    ("\u{1F431}", &["cat"]),
+    ("\u{1F408}", &["cat2"]),
+    ("\u{1F1F0}\u{1F1FE}", &["cayman_islands"]),
+    ("\u{1F4BF}", &["cd"]),
+    ("\u{1F1E8}\u{1F1EB}", &["central_african_republic"]),
+    ("\u{1F1EA}\u{1F1E6}", &["ceuta_melilla"]),
+    ("\u{1F1F9}\u{1F1E9}", &["chad"]),
+    ("\u{26D3}\u{FE0F}", &["chains"]),
+    ("\u{1FA91}", &["chair"]),
+    ("\u{1F37E}", &["champagne"]),
+    ("\u{1F4B9}", &["chart"]),
+    ("\u{1F4C9}", &["chart_with_downwards_trend"]),
+    ("\u{1F4C8}", &["chart_with_upwards_trend"]),
+    ("\u{1F3C1}", &["checkered_flag"]),
+    ("\u{1F9C0}", &["cheese"]),
+    ("\u{1F352}", &["cherries"]),
+    ("\u{1F338}", &["cherry_blossom"]),
+    ("\u{265F}\u{FE0F}", &["chess_pawn"]),
+    ("\u{1F330}", &["chestnut"]),
+    ("\u{1F414}", &["chicken"]),
+    ("\u{1F9D2}", &["child"]),
+    ("\u{1F6B8}", &["children_crossing"]),
+    ("\u{1F1E8}\u{1F1F1}", &["chile"]),
+    ("\u{1F43F}\u{FE0F}", &["chipmunk"]),
+    ("\u{1F36B}", &["chocolate_bar"]),
+    ("\u{1F962}", &["chopsticks"]),
+    ("\u{1F1E8}\u{1F1FD}", &["christmas_island"]),
+    ("\u{1F384}", &["christmas_tree"]),
+    ("\u{26EA}", &["church"]),
+    ("\u{1F3A6}", &["cinema"]),
+    ("\u{1F3AA}", &["circus_tent"]),
+    ("\u{1F307}", &["city_sunrise"]),
+    ("\u{1F306}", &["city_sunset"]),
+    ("\u{1F3D9}\u{FE0F}", &["cityscape"]),
+    ("\u{1F191}", &["cl"]),
+    ("\u{1F5DC}\u{FE0F}", &["clamp"]),
+    ("\u{1F44F}", &["clap"]),
+    ("\u{1F3AC}", &["clapper"]),
+    ("\u{1F3DB}\u{FE0F}", &["classical_building"]),
+    ("\u{1F9D7}", &["climbing"]),
+    ("\u{1F9D7}\u{200D}\u{2642}\u{FE0F}", &["climbing_man"]),
+    ("\u{1F9D7}\u{200D}\u{2640}\u{FE0F}", &["climbing_woman"]),
+    ("\u{1F942}", &["clinking_glasses"]),
+    ("\u{1F4CB}", &["clipboard"]),
+    ("\u{1F1E8}\u{1F1F5}", &["clipperton_island"]),
+    ("\u{1F550}", &["clock1"]),
+    ("\u{1F559}", &["clock10"]),
+    ("\u{1F565}", &["clock1030"]),
+    ("\u{1F55A}", &["clock11"]),
+    ("\u{1F566}", &["clock1130"]),
+    ("\u{1F55B}", &["clock12"]),
+    ("\u{1F567}", &["clock1230"]),
+    ("\u{1F55C}", &["clock130"]),
+    ("\u{1F551}", &["clock2"]),
+    ("\u{1F55D}", &["clock230"]),
+    ("\u{1F552}", &["clock3"]),
+    ("\u{1F55E}", &["clock330"]),
+    ("\u{1F553}", &["clock4"]),
+    ("\u{1F55F}", &["clock430"]),
+    ("\u{1F554}", &["clock5"]),
+    ("\u{1F560}", &["clock530"]),
+    ("\u{1F555}", &["clock6"]),
+    ("\u{1F561}", &["clock630"]),
+    ("\u{1F556}", &["clock7"]),
+    ("\u{1F562}", &["clock730"]),
+    ("\u{1F557}", &["clock8"]),
+    ("\u{1F563}", &["clock830"]),
+    ("\u{1F558}", &["clock9"]),
+    ("\u{1F564}", &["clock930"]),
+    ("\u{1F4D5}", &["closed_book"]),
+    ("\u{1F510}", &["closed_lock_with_key"]),
+    ("\u{1F302}", &["closed_umbrella"]),
+    ("\u{2601}\u{FE0F}", &["cloud"]),
+    ("\u{1F329}\u{FE0F}", &["cloud_with_lightning"]),
+    ("\u{26C8}\u{FE0F}", &["cloud_with_lightning_and_rain"]),
+    ("\u{1F327}\u{FE0F}", &["cloud_with_rain"]),
+    ("\u{1F328}\u{FE0F}", &["cloud_with_snow"]),
+    ("\u{1F921}", &["clown_face"]),
+    ("\u{2663}\u{FE0F}", &["clubs"]),
+    ("\u{1F1E8}\u{1F1F3}", &["cn"]),
+    ("\u{1F9E5}", &["coat"]),
+    ("\u{1FAB3}", &["cockroach"]),
+    ("\u{1F378}", &["cocktail"]),
+    ("\u{1F965}", &["coconut"]),
+    ("\u{1F1E8}\u{1F1E8}", &["cocos_islands"]),
+    ("\u{2615}", &["coffee"]),
+    ("\u{26B0}\u{FE0F}", &["coffin"]),
+    ("\u{1FA99}", &["coin"]),
+    ("\u{1F976}", &["cold_face"]),
+    ("\u{1F630}", &["cold_sweat"]),
+    ("\u{1F1E8}\u{1F1F4}", &["colombia"]),
+    ("\u{2604}\u{FE0F}", &["comet"]),
+    ("\u{1F1F0}\u{1F1F2}", &["comoros"]),
+    ("\u{1F9ED}", &["compass"]),
+    ("\u{1F4BB}", &["computer"]),
+    ("\u{1F5B1}\u{FE0F}", &["computer_mouse"]),
+    ("\u{1F38A}", &["confetti_ball"]),
+    ("\u{1F616}", &["confounded"]),
+    ("\u{1F615}", &["confused"]),
+    ("\u{1F1E8}\u{1F1EC}", &["congo_brazzaville"]),
+    ("\u{1F1E8}\u{1F1E9}", &["congo_kinshasa"]),
+    ("\u{3297}\u{FE0F}", &["congratulations"]),
+    ("\u{1F6A7}", &["construction"]),
+    ("\u{1F477}", &["construction_worker"]),
+    ("\u{1F477}\u{200D}\u{2642}\u{FE0F}", &["construction_worker_man"]),
+    ("\u{1F477}\u{200D}\u{2640}\u{FE0F}", &["construction_worker_woman"]),
+    ("\u{1F39B}\u{FE0F}", &["control_knobs"]),
+    ("\u{1F3EA}", &["convenience_store"]),
+    ("\u{1F9D1}\u{200D}\u{1F373}", &["cook"]),
+    ("\u{1F1E8}\u{1F1F0}", &["cook_islands"]),
+    ("\u{1F36A}", &["cookie"]),
+    ("\u{1F192}", &["cool"]),
+    ("\u{00A9}\u{FE0F}", &["copyright"]),
+    ("\u{1FAB8}", &["coral"]),
+    ("\u{1F33D}", &["corn"]),
+    ("\u{1F1E8}\u{1F1F7}", &["costa_rica"]),
+    ("\u{1F1E8}\u{1F1EE}", &["cote_divoire"]),
+    ("\u{1F6CB}\u{FE0F}", &["couch_and_lamp"]),
+    ("\u{1F46B}", &["couple"]),
+    ("\u{1F491}", &["couple_with_heart"]),
+    ("\u{1F468}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}", &["couple_with_heart_man_man"]),
+    ("\u{1F469}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}", &["couple_with_heart_woman_man"]),
+    ("\u{1F469}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F469}", &["couple_with_heart_woman_woman"]),
+    ("\u{1F48F}", &["couplekiss"]),
+    ("\u{1F468}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}", &["couplekiss_man_man"]),
+    ("\u{1F469}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}", &["couplekiss_man_woman"]),
+    ("\u{1F469}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F469}", &["couplekiss_woman_woman"]),
+    ("\u{1F42E}", &["cow"]),
+    ("\u{1F404}", &["cow2"]),
+    ("\u{1F920}", &["cowboy_hat_face"]),
+    ("\u{1F980}", &["crab"]),
+    ("\u{1F58D}\u{FE0F}", &["crayon"]),
+    ("\u{1F4B3}", &["credit_card"]),
+    ("\u{1F319}", &["crescent_moon"]),
+    ("\u{1F997}", &["cricket"]),
+    ("\u{1F3CF}", &["cricket_game"]),
+    ("\u{1F1ED}\u{1F1F7}", &["croatia"]),
+    ("\u{1F40A}", &["crocodile"]),
+    ("\u{1F950}", &["croissant"]),
+    ("\u{1F91E}", &["crossed_fingers"]),
+    ("\u{1F38C}", &["crossed_flags"]),
+    ("\u{2694}\u{FE0F}", &["crossed_swords"]),
+    ("\u{1F451}", &["crown"]),
+    ("\u{1FA7C}", &["crutch"]),
+    ("\u{1F622}", &["cry"]),
+    ("\u{1F63F}", &["crying_cat_face"]),
+    ("\u{1F52E}", &["crystal_ball"]),
+    ("\u{1F1E8}\u{1F1FA}", &["cuba"]),
+    ("\u{1F952}", &["cucumber"]),
+    ("\u{1F964}", &["cup_with_straw"]),
+    ("\u{1F9C1}", &["cupcake"]),
+    ("\u{1F498}", &["cupid"]),
+    ("\u{1F1E8}\u{1F1FC}", &["curacao"]),
+    ("\u{1F94C}", &["curling_stone"]),
+    ("\u{1F468}\u{200D}\u{1F9B1}", &["curly_haired_man"]),
+    ("\u{1F469}\u{200D}\u{1F9B1}", &["curly_haired_woman"]),
+    ("\u{27B0}", &["curly_loop"]),
+    ("\u{1F4B1}", &["currency_exchange"]),
+    ("\u{1F35B}", &["curry"]),
+    ("\u{1F92C}", &["cursing_face"]),
+    ("\u{1F36E}", &["custard"]),
+    ("\u{1F6C3}", &["customs"]),
+    ("\u{1F969}", &["cut_of_meat"]),
+    ("\u{1F300}", &["cyclone"]),
+    ("\u{1F1E8}\u{1F1FE}", &["cyprus"]),
+    ("\u{1F1E8}\u{1F1FF}", &["czech_republic"]),
+    ("\u{1F5E1}\u{FE0F}", &["dagger"]),
+    ("\u{1F483}", &["dancer", "woman_dancing"]),
+    ("\u{1F46F}", &["dancers"]),
+    ("\u{1F46F}\u{200D}\u{2642}\u{FE0F}", &["dancing_men"]),
+    ("\u{1F46F}\u{200D}\u{2640}\u{FE0F}", &["dancing_women"]),
+    ("\u{1F361}", &["dango"]),
+    ("\u{1F576}\u{FE0F}", &["dark_sunglasses"]),
+    ("\u{1F3AF}", &["dart"]),
+    ("\u{1F4A8}", &["dash"]),
+    ("\u{1F4C5}", &["date"]),
+    ("\u{1F1E9}\u{1F1EA}", &["de"]),
+    ("\u{1F9CF}\u{200D}\u{2642}\u{FE0F}", &["deaf_man"]),
+    ("\u{1F9CF}", &["deaf_person"]),
+    ("\u{1F9CF}\u{200D}\u{2640}\u{FE0F}", &["deaf_woman"]),
+    ("\u{1F333}", &["deciduous_tree"]),
+    ("\u{1F98C}", &["deer"]),
+    ("\u{1F1E9}\u{1F1F0}", &["denmark"]),
+    ("\u{1F3EC}", &["department_store"]),
+    ("\u{1F3DA}\u{FE0F}", &["derelict_house"]),
+    ("\u{1F3DC}\u{FE0F}", &["desert"]),
+    ("\u{1F3DD}\u{FE0F}", &["desert_island"]),
+    ("\u{1F5A5}\u{FE0F}", &["desktop_computer"]),
+    ("\u{1F575}\u{FE0F}", &["detective"]),
+    ("\u{1F4A0}", &["diamond_shape_with_a_dot_inside"]),
+    ("\u{2666}\u{FE0F}", &["diamonds"]),
+    ("\u{1F1E9}\u{1F1EC}", &["diego_garcia"]),
+    ("\u{1F61E}", &["disappointed"]),
+    ("\u{1F625}", &["disappointed_relieved"]),
+    ("\u{1F978}", &["disguised_face"]),
+    ("\u{1F93F}", &["diving_mask"]),
+    ("\u{1FA94}", &["diya_lamp"]),
+    ("\u{1F4AB}", &["dizzy"]),
+    ("\u{1F635}", &["dizzy_face"]),
+    ("\u{1F1E9}\u{1F1EF}", &["djibouti"]),
+    ("\u{1F9EC}", &["dna"]),
+    ("\u{1F6AF}", &["do_not_litter"]),
+    ("\u{1F9A4}", &["dodo"]),
+    ("\u{1F436}", &["dog"]),
+    ("\u{1F415}", &["dog2"]),
+    ("\u{1F4B5}", &["dollar"]),
+    ("\u{1F38E}", &["dolls"]),
+    ("\u{1F42C}", &["dolphin", "flipper"]),
+    ("\u{1F1E9}\u{1F1F2}", &["dominica"]),
+    ("\u{1F1E9}\u{1F1F4}", &["dominican_republic"]),
+    ("\u{1FACF}", &["donkey"]),
+    ("\u{1F6AA}", &["door"]),
+    ("\u{1FAE5}", &["dotted_line_face"]),
+    ("\u{1F369}", &["doughnut"]),
+    ("\u{1F54A}\u{FE0F}", &["dove"]),
+    ("\u{1F409}", &["dragon"]),
+    ("\u{1F432}", &["dragon_face"]),
+    ("\u{1F457}", &["dress"]),
+    ("\u{1F42A}", &["dromedary_camel"]),
+    ("\u{1F924}", &["drooling_face"]),
+    ("\u{1FA78}", &["drop_of_blood"]),
+    ("\u{1F4A7}", &["droplet"]),
+    ("\u{1F941}", &["drum"]),
+    ("\u{1F986}", &["duck"]),
+    ("\u{1F95F}", &["dumpling"]),
+    ("\u{1F4C0}", &["dvd"]),
+    ("\u{1F985}", &["eagle"]),
+    ("\u{1F442}", &["ear"]),
+    ("\u{1F33E}", &["ear_of_rice"]),
+    ("\u{1F9BB}", &["ear_with_hearing_aid"]),
+    ("\u{1F30D}", &["earth_africa"]),
+    ("\u{1F30E}", &["earth_americas"]),
+    ("\u{1F30F}", &["earth_asia"]),
+    ("\u{1F1EA}\u{1F1E8}", &["ecuador"]),
+    ("\u{1F95A}", &["egg"]),
+    ("\u{1F346}", &["eggplant"]),
+    ("\u{1F1EA}\u{1F1EC}", &["egypt"]),
+    ("\u{0038}\u{FE0F}\u{20E3}", &["eight"]),
+    ("\u{2734}\u{FE0F}", &["eight_pointed_black_star"]),
+    ("\u{2733}\u{FE0F}", &["eight_spoked_asterisk"]),
+    ("\u{23CF}\u{FE0F}", &["eject_button"]),
+    ("\u{1F1F8}\u{1F1FB}", &["el_salvador"]),
+    ("\u{1F50C}", &["electric_plug"]),
+    ("\u{1F418}", &["elephant"]),
+    ("\u{1F6D7}", &["elevator"]),
+    ("\u{1F9DD}", &["elf"]),
+    ("\u{1F9DD}\u{200D}\u{2642}\u{FE0F}", &["elf_man"]),
+    ("\u{1F9DD}\u{200D}\u{2640}\u{FE0F}", &["elf_woman"]),
+    ("\u{1F4E7}", &["email", "e-mail"]),
+    ("\u{1FAB9}", &["empty_nest"]),
+    ("\u{1F51A}", &["end"]),
+    ("\u{1F3F4}\u{E0067}\u{E0062}\u{E0065}\u{E006E}\u{E0067}\u{E007F}", &["england"]),
+    ("\u{2709}\u{FE0F}", &["envelope"]),
+    ("\u{1F4E9}", &["envelope_with_arrow"]),
+    ("\u{1F1EC}\u{1F1F6}", &["equatorial_guinea"]),
+    ("\u{1F1EA}\u{1F1F7}", &["eritrea"]),
+    ("\u{1F1EA}\u{1F1F8}", &["es"]),
+    ("\u{1F1EA}\u{1F1EA}", &["estonia"]),
+    ("\u{1F1EA}\u{1F1F9}", &["ethiopia"]),
+    ("\u{1F1EA}\u{1F1FA}", &["eu", "european_union"]),
+    ("\u{1F4B6}", &["euro"]),
+    ("\u{1F3F0}", &["european_castle"]),
+    ("\u{1F3E4}", &["european_post_office"]),
+    ("\u{1F332}", &["evergreen_tree"]),
+    ("\u{2757}", &["exclamation", "heavy_exclamation_mark"]),
+    ("\u{1F92F}", &["exploding_head"]),
+    ("\u{1F611}", &["expressionless"]),
+    ("\u{1F441}\u{FE0F}", &["eye"]),
+    ("\u{1F441}\u{FE0F}\u{200D}\u{1F5E8}\u{FE0F}", &["eye_speech_bubble"]),
+    ("\u{1F453}", &["eyeglasses"]),
+    ("\u{1F440}", &["eyes"]),
+    ("\u{1F62E}\u{200D}\u{1F4A8}", &["face_exhaling"]),
+    ("\u{1F979}", &["face_holding_back_tears"]),
+    ("\u{1F636}\u{200D}\u{1F32B}\u{FE0F}", &["face_in_clouds"]),
+    ("\u{1FAE4}", &["face_with_diagonal_mouth"]),
+    ("\u{1F915}", &["face_with_head_bandage"]),
+    ("\u{1FAE2}", &["face_with_open_eyes_and_hand_over_mouth"]),
+    ("\u{1FAE3}", &["face_with_peeking_eye"]),
+    ("\u{1F635}\u{200D}\u{1F4AB}", &["face_with_spiral_eyes"]),
+    ("\u{1F912}", &["face_with_thermometer"]),
+    ("\u{1F926}", &["facepalm"]),
+    ("\u{1F3ED}", &["factory"]),
+    ("\u{1F9D1}\u{200D}\u{1F3ED}", &["factory_worker"]),
+    ("\u{1F9DA}", &["fairy"]),
+    ("\u{1F9DA}\u{200D}\u{2642}\u{FE0F}", &["fairy_man"]),
+    ("\u{1F9DA}\u{200D}\u{2640}\u{FE0F}", &["fairy_woman"]),
+    ("\u{1F9C6}", &["falafel"]),
+    ("\u{1F1EB}\u{1F1F0}", &["falkland_islands"]),
+    ("\u{1F342}", &["fallen_leaf"]),
+    ("\u{1F46A}", &["family"]),
+    ("\u{1F468}\u{200D}\u{1F466}", &["family_man_boy"]),
+    ("\u{1F468}\u{200D}\u{1F466}\u{200D}\u{1F466}", &["family_man_boy_boy"]),
+    ("\u{1F468}\u{200D}\u{1F467}", &["family_man_girl"]),
+    ("\u{1F468}\u{200D}\u{1F467}\u{200D}\u{1F466}", &["family_man_girl_boy"]),
+    ("\u{1F468}\u{200D}\u{1F467}\u{200D}\u{1F467}", &["family_man_girl_girl"]),
+    ("\u{1F468}\u{200D}\u{1F468}\u{200D}\u{1F466}", &["family_man_man_boy"]),
+    ("\u{1F468}\u{200D}\u{1F468}\u{200D}\u{1F466}\u{200D}\u{1F466}", &["family_man_man_boy_boy"]),
+    ("\u{1F468}\u{200D}\u{1F468}\u{200D}\u{1F467}", &["family_man_man_girl"]),
+    ("\u{1F468}\u{200D}\u{1F468}\u{200D}\u{1F467}\u{200D}\u{1F466}", &["family_man_man_girl_boy"]),
+    ("\u{1F468}\u{200D}\u{1F468}\u{200D}\u{1F467}\u{200D}\u{1F467}", &["family_man_man_girl_girl"]),
+    ("\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F466}", &["family_man_woman_boy"]),
+    ("\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F466}\u{200D}\u{1F466}", &["family_man_woman_boy_boy"]),
+    ("\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}", &["family_man_woman_girl"]),
+    ("\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}\u{200D}\u{1F466}", &["family_man_woman_girl_boy"]),
+    ("\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}\u{200D}\u{1F467}", &["family_man_woman_girl_girl"]),
+    ("\u{1F469}\u{200D}\u{1F466}", &["family_woman_boy"]),
+    ("\u{1F469}\u{200D}\u{1F466}\u{200D}\u{1F466}", &["family_woman_boy_boy"]),
+    ("\u{1F469}\u{200D}\u{1F467}", &["family_woman_girl"]),
+    ("\u{1F469}\u{200D}\u{1F467}\u{200D}\u{1F466}", &["family_woman_girl_boy"]),
+    ("\u{1F469}\u{200D}\u{1F467}\u{200D}\u{1F467}", &["family_woman_girl_girl"]),
+    ("\u{1F469}\u{200D}\u{1F469}\u{200D}\u{1F466}", &["family_woman_woman_boy"]),
+    ("\u{1F469}\u{200D}\u{1F469}\u{200D}\u{1F466}\u{200D}\u{1F466}", &["family_woman_woman_boy_boy"]),
+    ("\u{1F469}\u{200D}\u{1F469}\u{200D}\u{1F467}", &["family_woman_woman_girl"]),
+    ("\u{1F469}\u{200D}\u{1F469}\u{200D}\u{1F467}\u{200D}\u{1F466}", &["family_woman_woman_girl_boy"]),
+    ("\u{1F469}\u{200D}\u{1F469}\u{200D}\u{1F467}\u{200D}\u{1F467}", &["family_woman_woman_girl_girl"]),
+    ("\u{1F9D1}\u{200D}\u{1F33E}", &["farmer"]),
+    ("\u{1F1EB}\u{1F1F4}", &["faroe_islands"]),
+    ("\u{23E9}", &["fast_forward"]),
+    ("\u{1F4E0}", &["fax"]),
+    ("\u{1F628}", &["fearful"]),
+    ("\u{1FAB6}", &["feather"]),
+    ("\u{1F43E}", &["feet", "paw_prints"]),
+    ("\u{1F575}\u{FE0F}\u{200D}\u{2640}\u{FE0F}", &["female_detective"]),
+    ("\u{2640}\u{FE0F}", &["female_sign"]),
+    ("\u{1F3A1}", &["ferris_wheel"]),
+    ("\u{26F4}\u{FE0F}", &["ferry"]),
+    ("\u{1F3D1}", &["field_hockey"]),
+    ("\u{1F1EB}\u{1F1EF}", &["fiji"]),
+    ("\u{1F5C4}\u{FE0F}", &["file_cabinet"]),
+    ("\u{1F4C1}", &["file_folder"]),
+    ("\u{1F4FD}\u{FE0F}", &["film_projector"]),
+    ("\u{1F39E}\u{FE0F}", &["film_strip"]),
+    ("\u{1F1EB}\u{1F1EE}", &["finland"]),
+    ("\u{1F525}", &["fire"]),
+    ("\u{1F692}", &["fire_engine"]),
+    ("\u{1F9EF}", &["fire_extinguisher"]),
+    ("\u{1F9E8}", &["firecracker"]),
+    ("\u{1F9D1}\u{200D}\u{1F692}", &["firefighter"]),
+    ("\u{1F386}", &["fireworks"]),
+    ("\u{1F313}", &["first_quarter_moon"]),
+    ("\u{1F31B}", &["first_quarter_moon_with_face"]),
+    ("\u{1F41F}", &["fish"]),
+    ("\u{1F365}", &["fish_cake"]),
+    ("\u{1F3A3}", &["fishing_pole_and_fish"]),
+    ("\u{1F91B}", &["fist_left"]),
+    ("\u{1F44A}", &["fist_oncoming", "facepunch", "punch"]),
+    ("\u{270A}", &["fist_raised", "fist"]),
+    ("\u{1F91C}", &["fist_right"]),
+    ("\u{0035}\u{FE0F}\u{20E3}", &["five"]),
+    ("\u{1F38F}", &["flags"]),
+    ("\u{1F9A9}", &["flamingo"]),
+    ("\u{1F526}", &["flashlight"]),
+    ("\u{1F97F}", &["flat_shoe"]),
+    ("\u{1FAD3}", &["flatbread"]),
+    ("\u{269C}\u{FE0F}", &["fleur_de_lis"]),
+    ("\u{1F6EC}", &["flight_arrival"]),
+    ("\u{1F6EB}", &["flight_departure"]),
+    ("\u{1F4BE}", &["floppy_disk"]),
+    ("\u{1F3B4}", &["flower_playing_cards"]),
+    ("\u{1F633}", &["flushed"]),
+    ("\u{1FA88}", &["flute"]),
+    ("\u{1FAB0}", &["fly"]),
+    ("\u{1F94F}", &["flying_disc"]),
+    ("\u{1F6F8}", &["flying_saucer"]),
+    ("\u{1F32B}\u{FE0F}", &["fog"]),
+    ("\u{1F301}", &["foggy"]),
+    ("\u{1FAAD}", &["folding_hand_fan"]),
+    ("\u{1FAD5}", &["fondue"]),
+    ("\u{1F9B6}", &["foot"]),
+    ("\u{1F3C8}", &["football"]),
+    ("\u{1F463}", &["footprints"]),
+    ("\u{1F374}", &["fork_and_knife"]),
+    ("\u{1F960}", &["fortune_cookie"]),
+    ("\u{26F2}", &["fountain"]),
+    ("\u{1F58B}\u{FE0F}", &["fountain_pen"]),
+    ("\u{0034}\u{FE0F}\u{20E3}", &["four"]),
+    ("\u{1F340}", &["four_leaf_clover"]),
+    ("\u{1F98A}", &["fox_face"]),
+    ("\u{1F1EB}\u{1F1F7}", &["fr"]),
+    ("\u{1F5BC}\u{FE0F}", &["framed_picture"]),
+    ("\u{1F193}", &["free"]),
+    ("\u{1F1EC}\u{1F1EB}", &["french_guiana"]),
+    ("\u{1F1F5}\u{1F1EB}", &["french_polynesia"]),
+    ("\u{1F1F9}\u{1F1EB}", &["french_southern_territories"]),
+    ("\u{1F373}", &["fried_egg"]),
+    ("\u{1F364}", &["fried_shrimp"]),
+    ("\u{1F35F}", &["fries"]),
+    ("\u{1F438}", &["frog"]),
+    ("\u{1F626}", &["frowning"]),
+    ("\u{2639}\u{FE0F}", &["frowning_face"]),
+    ("\u{1F64D}\u{200D}\u{2642}\u{FE0F}", &["frowning_man"]),
+    ("\u{1F64D}", &["frowning_person"]),
+    ("\u{1F64D}\u{200D}\u{2640}\u{FE0F}", &["frowning_woman"]),
+    ("\u{26FD}", &["fuelpump"]),
+    ("\u{1F315}", &["full_moon"]),
+    ("\u{1F31D}", &["full_moon_with_face"]),
+    ("\u{26B1}\u{FE0F}", &["funeral_urn"]),
+    ("\u{1F1EC}\u{1F1E6}", &["gabon"]),
+    ("\u{1F1EC}\u{1F1F2}", &["gambia"]),
+    ("\u{1F3B2}", &["game_die"]),
+    ("\u{1F9C4}", &["garlic"]),
+    ("\u{1F1EC}\u{1F1E7}", &["gb", "uk"]),
+    ("\u{2699}\u{FE0F}", &["gear"]),
+    ("\u{1F48E}", &["gem"]),
+    ("\u{264A}", &["gemini"]),
+    ("\u{1F9DE}", &["genie"]),
+    ("\u{1F9DE}\u{200D}\u{2642}\u{FE0F}", &["genie_man"]),
+    ("\u{1F9DE}\u{200D}\u{2640}\u{FE0F}", &["genie_woman"]),
+    ("\u{1F1EC}\u{1F1EA}", &["georgia"]),
+    ("\u{1F1EC}\u{1F1ED}", &["ghana"]),
+    ("\u{1F47B}", &["ghost"]),
+    ("\u{1F1EC}\u{1F1EE}", &["gibraltar"]),
+    ("\u{1F381}", &["gift"]),
+    ("\u{1F49D}", &["gift_heart"]),
+    ("\u{1FADA}", &["ginger_root"]),
+    ("\u{1F992}", &["giraffe"]),
+    ("\u{1F467}", &["girl"]),
+    ("\u{1F310}", &["globe_with_meridians"]),
+    ("\u{1F9E4}", &["gloves"]),
+    ("\u{1F945}", &["goal_net"]),
+    ("\u{1F410}", &["goat"]),
+    ("\u{1F97D}", &["goggles"]),
+    ("\u{26F3}", &["golf"]),
+    ("\u{1F3CC}\u{FE0F}", &["golfing"]),
+    ("\u{1F3CC}\u{FE0F}\u{200D}\u{2642}\u{FE0F}", &["golfing_man"]),
+    ("\u{1F3CC}\u{FE0F}\u{200D}\u{2640}\u{FE0F}", &["golfing_woman"]),
+    ("\u{1FABF}", &["goose"]),
+    ("\u{1F98D}", &["gorilla"]),
+    ("\u{1F347}", &["grapes"]),
+    ("\u{1F1EC}\u{1F1F7}", &["greece"]),
+    ("\u{1F34F}", &["green_apple"]),
+    ("\u{1F4D7}", &["green_book"]),
+    ("\u{1F7E2}", &["green_circle"]),
+    ("\u{1F49A}", &["green_heart"]),
+    ("\u{1F957}", &["green_salad"]),
+    ("\u{1F7E9}", &["green_square"]),
+    ("\u{1F1EC}\u{1F1F1}", &["greenland"]),
+    ("\u{1F1EC}\u{1F1E9}", &["grenada"]),
+    ("\u{2755}", &["grey_exclamation"]),
+    ("\u{1FA76}", &["grey_heart"]),
+    ("\u{2754}", &["grey_question"]),
+    ("\u{1F62C}", &["grimacing"]),
+    ("\u{1F601}", &["grin"]),
+    ("\u{1F600}", &["grinning"]),
+    ("\u{1F1EC}\u{1F1F5}", &["guadeloupe"]),
+    ("\u{1F1EC}\u{1F1FA}", &["guam"]),
+    ("\u{1F482}", &["guard"]),
+    ("\u{1F482}\u{200D}\u{2642}\u{FE0F}", &["guardsman"]),
+    ("\u{1F482}\u{200D}\u{2640}\u{FE0F}", &["guardswoman"]),
+    ("\u{1F1EC}\u{1F1F9}", &["guatemala"]),
+    ("\u{1F1EC}\u{1F1EC}", &["guernsey"]),
+    ("\u{1F9AE}", &["guide_dog"]),
+    ("\u{1F1EC}\u{1F1F3}", &["guinea"]),
+    ("\u{1F1EC}\u{1F1FC}", &["guinea_bissau"]),
+    ("\u{1F3B8}", &["guitar"]),
+    ("\u{1F52B}", &["gun"]),
+    ("\u{1F1EC}\u{1F1FE}", &["guyana"]),
+    ("\u{1FAAE}", &["hair_pick"]),
+    ("\u{1F487}", &["haircut"]),
+    ("\u{1F487}\u{200D}\u{2642}\u{FE0F}", &["haircut_man"]),
+    ("\u{1F487}\u{200D}\u{2640}\u{FE0F}", &["haircut_woman"]),
+    ("\u{1F1ED}\u{1F1F9}", &["haiti"]),
+    ("\u{1F354}", &["hamburger"]),
+    ("\u{1F528}", &["hammer"]),
+    ("\u{2692}\u{FE0F}", &["hammer_and_pick"]),
+    ("\u{1F6E0}\u{FE0F}", &["hammer_and_wrench"]),
+    ("\u{1FAAC}", &["hamsa"]),
+    ("\u{1F439}", &["hamster"]),
+    ("\u{1F92D}", &["hand_over_mouth"]),
+    ("\u{1FAF0}", &["hand_with_index_finger_and_thumb_crossed"]),
+    ("\u{1F45C}", &["handbag"]),
+    ("\u{1F93E}", &["handball_person"]),
+    ("\u{1F91D}", &["handshake"]),
+    ("\u{1F4A9}", &["hankey", "poop", "shit"]),
+    ("\u{0023}\u{FE0F}\u{20E3}", &["hash"]),
+    ("\u{1F425}", &["hatched_chick"]),
+    ("\u{1F423}", &["hatching_chick"]),
+    ("\u{1F3A7}", &["headphones"]),
+    ("\u{1FAA6}", &["headstone"]),
+    ("\u{1F9D1}\u{200D}\u{2695}\u{FE0F}", &["health_worker"]),
+    ("\u{1F649}", &["hear_no_evil"]),
+    ("\u{1F1ED}\u{1F1F2}", &["heard_mcdonald_islands"]),
+    ("\u{2764}\u{FE0F}", &["heart"]),
+    ("\u{1F49F}", &["heart_decoration"]),
+    ("\u{1F60D}", &["heart_eyes"]),
+    ("\u{1F63B}", &["heart_eyes_cat"]),
+    ("\u{1FAF6}", &["heart_hands"]),
+    ("\u{2764}\u{FE0F}\u{200D}\u{1F525}", &["heart_on_fire"]),
+    ("\u{1F493}", &["heartbeat"]),
+    ("\u{1F497}", &["heartpulse"]),
+    ("\u{2665}\u{FE0F}", &["hearts"]),
+    ("\u{2714}\u{FE0F}", &["heavy_check_mark"]),
+    ("\u{2797}", &["heavy_division_sign"]),
+    ("\u{1F4B2}", &["heavy_dollar_sign"]),
+    ("\u{1F7F0}", &["heavy_equals_sign"]),
+    ("\u{2763}\u{FE0F}", &["heavy_heart_exclamation"]),
+    ("\u{2796}", &["heavy_minus_sign"]),
+    ("\u{2716}\u{FE0F}", &["heavy_multiplication_x"]),
+    ("\u{2795}", &["heavy_plus_sign"]),
+    ("\u{1F994}", &["hedgehog"]),
+    ("\u{1F681}", &["helicopter"]),
+    ("\u{1F33F}", &["herb"]),
+    ("\u{1F33A}", &["hibiscus"]),
+    ("\u{1F506}", &["high_brightness"]),
+    ("\u{1F460}", &["high_heel"]),
+    ("\u{1F97E}", &["hiking_boot"]),
+    ("\u{1F6D5}", &["hindu_temple"]),
+    ("\u{1F99B}", &["hippopotamus"]),
+    ("\u{1F52A}", &["hocho", "knife"]),
+    ("\u{1F573}\u{FE0F}", &["hole"]),
+    ("\u{1F1ED}\u{1F1F3}", &["honduras"]),
+    ("\u{1F36F}", &["honey_pot"]),
+    ("\u{1F1ED}\u{1F1F0}", &["hong_kong"]),
+    ("\u{1FA9D}", &["hook"]),
+    ("\u{1F434}", &["horse"]),
+    ("\u{1F3C7}", &["horse_racing"]),
+    ("\u{1F3E5}", &["hospital"]),
+    ("\u{1F975}", &["hot_face"]),
+    ("\u{1F336}\u{FE0F}", &["hot_pepper"]),
+    ("\u{1F32D}", &["hotdog"]),
+    ("\u{1F3E8}", &["hotel"]),
+    ("\u{2668}\u{FE0F}", &["hotsprings"]),
+    ("\u{231B}", &["hourglass"]),
+    ("\u{23F3}", &["hourglass_flowing_sand"]),
+    ("\u{1F3E0}", &["house"]),
+    ("\u{1F3E1}", &["house_with_garden"]),
+    ("\u{1F3D8}\u{FE0F}", &["houses"]),
+    ("\u{1F917}", &["hugs"]),
+    ("\u{1F1ED}\u{1F1FA}", &["hungary"]),
+    ("\u{1F62F}", &["hushed"]),
+    ("\u{1F6D6}", &["hut"]),
+    ("\u{1FABB}", &["hyacinth"]),
+    ("\u{1F368}", &["ice_cream"]),
+    ("\u{1F9CA}", &["ice_cube"]),
+    ("\u{1F3D2}", &["ice_hockey"]),
+    ("\u{26F8}\u{FE0F}", &["ice_skate"]),
+    ("\u{1F366}", &["icecream"]),
+    ("\u{1F1EE}\u{1F1F8}", &["iceland"]),
+    ("\u{1F194}", &["id"]),
+    ("\u{1FAAA}", &["identification_card"]),
+    ("\u{1F250}", &["ideograph_advantage"]),
+    ("\u{1F47F}", &["imp"]),
+    ("\u{1F4E5}", &["inbox_tray"]),
+    ("\u{1F4E8}", &["incoming_envelope"]),
+    ("\u{1FAF5}", &["index_pointing_at_the_viewer"]),
+    ("\u{1F1EE}\u{1F1F3}", &["india"]),
+    ("\u{1F1EE}\u{1F1E9}", &["indonesia"]),
+    ("\u{267E}\u{FE0F}", &["infinity"]),
+    ("\u{2139}\u{FE0F}", &["information_source"]),
+    ("\u{1F607}", &["innocent"]),
+    ("\u{2049}\u{FE0F}", &["interrobang"]),
+    ("\u{1F4F1}", &["iphone"]),
+    ("\u{1F1EE}\u{1F1F7}", &["iran"]),
+    ("\u{1F1EE}\u{1F1F6}", &["iraq"]),
+    ("\u{1F1EE}\u{1F1EA}", &["ireland"]),
+    ("\u{1F1EE}\u{1F1F2}", &["isle_of_man"]),
+    ("\u{1F1EE}\u{1F1F1}", &["israel"]),
+    ("\u{1F1EE}\u{1F1F9}", &["it"]),
+    ("\u{1F3EE}", &["izakaya_lantern", "lantern"]),
+    ("\u{1F383}", &["jack_o_lantern"]),
+    ("\u{1F1EF}\u{1F1F2}", &["jamaica"]),
+    ("\u{1F5FE}", &["japan"]),
+    ("\u{1F3EF}", &["japanese_castle"]),
+    ("\u{1F47A}", &["japanese_goblin"]),
+    ("\u{1F479}", &["japanese_ogre"]),
+    ("\u{1FAD9}", &["jar"]),
+    ("\u{1F456}", &["jeans"]),
+    ("\u{1FABC}", &["jellyfish"]),
+    ("\u{1F1EF}\u{1F1EA}", &["jersey"]),
+    ("\u{1F9E9}", &["jigsaw"]),
+    ("\u{1F1EF}\u{1F1F4}", &["jordan"]),
+    ("\u{1F602}", &["joy"]),
+    ("\u{1F639}", &["joy_cat"]),
+    ("\u{1F579}\u{FE0F}", &["joystick"]),
+    ("\u{1F1EF}\u{1F1F5}", &["jp"]),
+    ("\u{1F9D1}\u{200D}\u{2696}\u{FE0F}", &["judge"]),
+    ("\u{1F939}", &["juggling_person"]),
+    ("\u{1F54B}", &["kaaba"]),
+    ("\u{1F998}", &["kangaroo"]),
+    ("\u{1F1F0}\u{1F1FF}", &["kazakhstan"]),
+    ("\u{1F1F0}\u{1F1EA}", &["kenya"]),
+    ("\u{1F511}", &["key"]),
+    ("\u{2328}\u{FE0F}", &["keyboard"]),
+    ("\u{1F51F}", &["keycap_ten"]),
+    ("\u{1FAAF}", &["khanda"]),
+    ("\u{1F6F4}", &["kick_scooter"]),
+    ("\u{1F458}", &["kimono"]),
+    ("\u{1F1F0}\u{1F1EE}", &["kiribati"]),
+    ("\u{1F48B}", &["kiss"]),
+    ("\u{1F617}", &["kissing"]),
+    ("\u{1F63D}", &["kissing_cat"]),
+    ("\u{1F61A}", &["kissing_closed_eyes"]),
+    ("\u{1F618}", &["kissing_heart"]),
+    ("\u{1F619}", &["kissing_smiling_eyes"]),
+    ("\u{1FA81}", &["kite"]),
+    ("\u{1F95D}", &["kiwi_fruit"]),
+    ("\u{1F9CE}\u{200D}\u{2642}\u{FE0F}", &["kneeling_man"]),
+    ("\u{1F9CE}", &["kneeling_person"]),
+    ("\u{1F9CE}\u{200D}\u{2640}\u{FE0F}", &["kneeling_woman"]),
+    ("\u{1FAA2}", &["knot"]),
+    ("\u{1F428}", &["koala"]),
+    ("\u{1F201}", &["koko"]),
+    ("\u{1F1FD}\u{1F1F0}", &["kosovo"]),
+    ("\u{1F1F0}\u{1F1F7}", &["kr"]),
+    ("\u{1F1F0}\u{1F1FC}", &["kuwait"]),
+    ("\u{1F1F0}\u{1F1EC}", &["kyrgyzstan"]),
+    ("\u{1F97C}", &["lab_coat"]),
+    ("\u{1F3F7}\u{FE0F}", &["label"]),
+    ("\u{1F94D}", &["lacrosse"]),
+    ("\u{1FA9C}", &["ladder"]),
+    ("\u{1F41E}", &["lady_beetle"]),
+    ("\u{1F1F1}\u{1F1E6}", &["laos"]),
+    ("\u{1F535}", &["large_blue_circle"]),
+    ("\u{1F537}", &["large_blue_diamond"]),
+    ("\u{1F536}", &["large_orange_diamond"]),
+    ("\u{1F317}", &["last_quarter_moon"]),
+    ("\u{1F31C}", &["last_quarter_moon_with_face"]),
+    ("\u{271D}\u{FE0F}", &["latin_cross"]),
+    ("\u{1F1F1}\u{1F1FB}", &["latvia"]),
+    ("\u{1F606}", &["laughing", "satisfied"]),
+    ("\u{1F96C}", &["leafy_green"]),
+    ("\u{1F343}", &["leaves"]),
+    ("\u{1F1F1}\u{1F1E7}", &["lebanon"]),
+    ("\u{1F4D2}", &["ledger"]),
+    ("\u{1F6C5}", &["left_luggage"]),
+    ("\u{2194}\u{FE0F}", &["left_right_arrow"]),
+    ("\u{1F5E8}\u{FE0F}", &["left_speech_bubble"]),
+    ("\u{21A9}\u{FE0F}", &["leftwards_arrow_with_hook"]),
+    ("\u{1FAF2}", &["leftwards_hand"]),
+    ("\u{1FAF7}", &["leftwards_pushing_hand"]),
+    ("\u{1F9B5}", &["leg"]),
+    ("\u{1F34B}", &["lemon"]),
+    ("\u{264C}", &["leo"]),
+    ("\u{1F406}", &["leopard"]),
+    ("\u{1F1F1}\u{1F1F8}", &["lesotho"]),
+    ("\u{1F39A}\u{FE0F}", &["level_slider"]),
+    ("\u{1F1F1}\u{1F1F7}", &["liberia"]),
+    ("\u{264E}", &["libra"]),
+    ("\u{1F1F1}\u{1F1FE}", &["libya"]),
+    ("\u{1F1F1}\u{1F1EE}", &["liechtenstein"]),
+    ("\u{1FA75}", &["light_blue_heart"]),
+    ("\u{1F688}", &["light_rail"]),
+    ("\u{1F517}", &["link"]),
+    ("\u{1F981}", &["lion"]),
+    ("\u{1F444}", &["lips"]),
+    ("\u{1F484}", &["lipstick"]),
+    ("\u{1F1F1}\u{1F1F9}", &["lithuania"]),
+    ("\u{1F98E}", &["lizard"]),
+    ("\u{1F999}", &["llama"]),
+    ("\u{1F99E}", &["lobster"]),
+    ("\u{1F512}", &["lock"]),
+    ("\u{1F50F}", &["lock_with_ink_pen"]),
+    ("\u{1F36D}", &["lollipop"]),
+    ("\u{1FA98}", &["long_drum"]),
+    ("\u{27BF}", &["loop"]),
+    ("\u{1F9F4}", &["lotion_bottle"]),
+    ("\u{1FAB7}", &["lotus"]),
+    ("\u{1F9D8}", &["lotus_position"]),
+    ("\u{1F9D8}\u{200D}\u{2642}\u{FE0F}", &["lotus_position_man"]),
+    ("\u{1F9D8}\u{200D}\u{2640}\u{FE0F}", &["lotus_position_woman"]),
+    ("\u{1F50A}", &["loud_sound"]),
+    ("\u{1F4E2}", &["loudspeaker"]),
+    ("\u{1F3E9}", &["love_hotel"]),
+    ("\u{1F48C}", &["love_letter"]),
+    ("\u{1F91F}", &["love_you_gesture"]),
+    ("\u{1FAAB}", &["low_battery"]),
+    ("\u{1F505}", &["low_brightness"]),
+    ("\u{1F9F3}", &["luggage"]),
+    ("\u{1FAC1}", &["lungs"]),
+    ("\u{1F1F1}\u{1F1FA}", &["luxembourg"]),
+    ("\u{1F925}", &["lying_face"]),
+    ("\u{24C2}\u{FE0F}", &["m"]),
+    ("\u{1F1F2}\u{1F1F4}", &["macau"]),
+    ("\u{1F1F2}\u{1F1F0}", &["macedonia"]),
+    ("\u{1F1F2}\u{1F1EC}", &["madagascar"]),
+    ("\u{1F50D}", &["mag"]),
+    ("\u{1F50E}", &["mag_right"]),
+    ("\u{1F9D9}", &["mage"]),
+    ("\u{1F9D9}\u{200D}\u{2642}\u{FE0F}", &["mage_man"]),
+    ("\u{1F9D9}\u{200D}\u{2640}\u{FE0F}", &["mage_woman"]),
+    ("\u{1FA84}", &["magic_wand"]),
+    ("\u{1F9F2}", &["magnet"]),
+    ("\u{1F004}", &["mahjong"]),
+    ("\u{1F4EB}", &["mailbox"]),
+    ("\u{1F4EA}", &["mailbox_closed"]),
+    ("\u{1F4EC}", &["mailbox_with_mail"]),
+    ("\u{1F4ED}", &["mailbox_with_no_mail"]),
+    ("\u{1F1F2}\u{1F1FC}", &["malawi"]),
+    ("\u{1F1F2}\u{1F1FE}", &["malaysia"]),
+    ("\u{1F1F2}\u{1F1FB}", &["maldives"]),
+    ("\u{1F575}\u{FE0F}\u{200D}\u{2642}\u{FE0F}", &["male_detective"]),
+    ("\u{2642}\u{FE0F}", &["male_sign"]),
+    ("\u{1F1F2}\u{1F1F1}", &["mali"]),
+    ("\u{1F1F2}\u{1F1F9}", &["malta"]),
+    ("\u{1F9A3}", &["mammoth"]),
+    ("\u{1F468}", &["man"]),
+    ("\u{1F468}\u{200D}\u{1F3A8}", &["man_artist"]),
+    ("\u{1F468}\u{200D}\u{1F680}", &["man_astronaut"]),
+    ("\u{1F9D4}\u{200D}\u{2642}\u{FE0F}", &["man_beard"]),
+    ("\u{1F938}\u{200D}\u{2642}\u{FE0F}", &["man_cartwheeling"]),
+    ("\u{1F468}\u{200D}\u{1F373}", &["man_cook"]),
+    ("\u{1F57A}", &["man_dancing"]),
+    ("\u{1F926}\u{200D}\u{2642}\u{FE0F}", &["man_facepalming"]),
+    ("\u{1F468}\u{200D}\u{1F3ED}", &["man_factory_worker"]),
+    ("\u{1F468}\u{200D}\u{1F33E}", &["man_farmer"]),
+    ("\u{1F468}\u{200D}\u{1F37C}", &["man_feeding_baby"]),
+    ("\u{1F468}\u{200D}\u{1F692}", &["man_firefighter"]),
+    ("\u{1F468}\u{200D}\u{2695}\u{FE0F}", &["man_health_worker"]),
+    ("\u{1F468}\u{200D}\u{1F9BD}", &["man_in_manual_wheelchair"]),
+    ("\u{1F468}\u{200D}\u{1F9BC}", &["man_in_motorized_wheelchair"]),
+    ("\u{1F935}\u{200D}\u{2642}\u{FE0F}", &["man_in_tuxedo"]),
+    ("\u{1F468}\u{200D}\u{2696}\u{FE0F}", &["man_judge"]),
+    ("\u{1F939}\u{200D}\u{2642}\u{FE0F}", &["man_juggling"]),
+    ("\u{1F468}\u{200D}\u{1F527}", &["man_mechanic"]),
+    ("\u{1F468}\u{200D}\u{1F4BC}", &["man_office_worker"]),
+    ("\u{1F468}\u{200D}\u{2708}\u{FE0F}", &["man_pilot"]),
+    ("\u{1F93E}\u{200D}\u{2642}\u{FE0F}", &["man_playing_handball"]),
+    ("\u{1F93D}\u{200D}\u{2642}\u{FE0F}", &["man_playing_water_polo"]),
+    ("\u{1F468}\u{200D}\u{1F52C}", &["man_scientist"]),
+    ("\u{1F937}\u{200D}\u{2642}\u{FE0F}", &["man_shrugging"]),
+    ("\u{1F468}\u{200D}\u{1F3A4}", &["man_singer"]),
+    ("\u{1F468}\u{200D}\u{1F393}", &["man_student"]),
+    ("\u{1F468}\u{200D}\u{1F3EB}", &["man_teacher"]),
+    ("\u{1F468}\u{200D}\u{1F4BB}", &["man_technologist"]),
+    ("\u{1F472}", &["man_with_gua_pi_mao"]),
+    ("\u{1F468}\u{200D}\u{1F9AF}", &["man_with_probing_cane"]),
+    ("\u{1F473}\u{200D}\u{2642}\u{FE0F}", &["man_with_turban"]),
+    ("\u{1F470}\u{200D}\u{2642}\u{FE0F}", &["man_with_veil"]),
+    ("\u{1F96D}", &["mango"]),
+    ("\u{1F45E}", &["mans_shoe", "shoe"]),
+    ("\u{1F570}\u{FE0F}", &["mantelpiece_clock"]),
+    ("\u{1F9BD}", &["manual_wheelchair"]),
+    ("\u{1F341}", &["maple_leaf"]),
+    ("\u{1FA87}", &["maracas"]),
+    ("\u{1F1F2}\u{1F1ED}", &["marshall_islands"]),
+    ("\u{1F94B}", &["martial_arts_uniform"]),
+    ("\u{1F1F2}\u{1F1F6}", &["martinique"]),
+    ("\u{1F637}", &["mask"]),
+    ("\u{1F486}", &["massage"]),
+    ("\u{1F486}\u{200D}\u{2642}\u{FE0F}", &["massage_man"]),
+    ("\u{1F486}\u{200D}\u{2640}\u{FE0F}", &["massage_woman"]),
+    ("\u{1F9C9}", &["mate"]),
+    ("\u{1F1F2}\u{1F1F7}", &["mauritania"]),
+    ("\u{1F1F2}\u{1F1FA}", &["mauritius"]),
+    ("\u{1F1FE}\u{1F1F9}", &["mayotte"]),
+    ("\u{1F356}", &["meat_on_bone"]),
+    ("\u{1F9D1}\u{200D}\u{1F527}", &["mechanic"]),
+    ("\u{1F9BE}", &["mechanical_arm"]),
+    ("\u{1F9BF}", &["mechanical_leg"]),
+    ("\u{1F396}\u{FE0F}", &["medal_military"]),
+    ("\u{1F3C5}", &["medal_sports"]),
+    ("\u{2695}\u{FE0F}", &["medical_symbol"]),
+    ("\u{1F4E3}", &["mega"]),
+    ("\u{1F348}", &["melon"]),
+    ("\u{1FAE0}", &["melting_face"]),
+    ("\u{1F4DD}", &["memo", "pencil"]),
+    ("\u{1F93C}\u{200D}\u{2642}\u{FE0F}", &["men_wrestling"]),
+    ("\u{2764}\u{FE0F}\u{200D}\u{1FA79}", &["mending_heart"]),
+    ("\u{1F54E}", &["menorah"]),
+    ("\u{1F6B9}", &["mens"]),
+    ("\u{1F9DC}\u{200D}\u{2640}\u{FE0F}", &["mermaid"]),
+    ("\u{1F9DC}\u{200D}\u{2642}\u{FE0F}", &["merman"]),
+    ("\u{1F9DC}", &["merperson"]),
+    ("\u{1F918}", &["metal"]),
+    ("\u{1F687}", &["metro"]),
+    ("\u{1F1F2}\u{1F1FD}", &["mexico"]),
+    ("\u{1F9A0}", &["microbe"]),
+    ("\u{1F1EB}\u{1F1F2}", &["micronesia"]),
+    ("\u{1F3A4}", &["microphone"]),
+    ("\u{1F52C}", &["microscope"]),
+    ("\u{1F595}", &["middle_finger", "fu"]),
+    ("\u{1FA96}", &["military_helmet"]),
+    ("\u{1F95B}", &["milk_glass"]),
+    ("\u{1F30C}", &["milky_way"]),
+    ("\u{1F690}", &["minibus"]),
+    ("\u{1F4BD}", &["minidisc"]),
+    ("\u{1FA9E}", &["mirror"]),
+    ("\u{1FAA9}", &["mirror_ball"]),
+    ("\u{1F4F4}", &["mobile_phone_off"]),
+    ("\u{1F1F2}\u{1F1E9}", &["moldova"]),
+    ("\u{1F1F2}\u{1F1E8}", &["monaco"]),
+    ("\u{1F911}", &["money_mouth_face"]),
+    ("\u{1F4B8}", &["money_with_wings"]),
+    ("\u{1F4B0}", &["moneybag"]),
+    ("\u{1F1F2}\u{1F1F3}", &["mongolia"]),
+    ("\u{1F412}", &["monkey"]),
+    ("\u{1F435}", &["monkey_face"]),
+    ("\u{1F9D0}", &["monocle_face"]),
+    ("\u{1F69D}", &["monorail"]),
+    ("\u{1F1F2}\u{1F1EA}", &["montenegro"]),
+    ("\u{1F1F2}\u{1F1F8}", &["montserrat"]),
+    ("\u{1F314}", &["moon", "waxing_gibbous_moon"]),
+    ("\u{1F96E}", &["moon_cake"]),
+    ("\u{1FACE}", &["moose"]),
+    ("\u{1F1F2}\u{1F1E6}", &["morocco"]),
+    ("\u{1F393}", &["mortar_board"]),
+    ("\u{1F54C}", &["mosque"]),
+    ("\u{1F99F}", &["mosquito"]),
+    ("\u{1F6E5}\u{FE0F}", &["motor_boat"]),
+    ("\u{1F6F5}", &["motor_scooter"]),
+    ("\u{1F3CD}\u{FE0F}", &["motorcycle"]),
+    ("\u{1F9BC}", &["motorized_wheelchair"]),
+    ("\u{1F6E3}\u{FE0F}", &["motorway"]),
+    ("\u{1F5FB}", &["mount_fuji"]),
+    ("\u{26F0}\u{FE0F}", &["mountain"]),
+    ("\u{1F6B5}", &["mountain_bicyclist"]),
+    ("\u{1F6B5}\u{200D}\u{2642}\u{FE0F}", &["mountain_biking_man"]),
+    ("\u{1F6B5}\u{200D}\u{2640}\u{FE0F}", &["mountain_biking_woman"]),
+    ("\u{1F6A0}", &["mountain_cableway"]),
+    ("\u{1F69E}", &["mountain_railway"]),
+    ("\u{1F3D4}\u{FE0F}", &["mountain_snow"]),
+    ("\u{1F42D}", &["mouse"]),
+    ("\u{1F401}", &["mouse2"]),
+    ("\u{1FAA4}", &["mouse_trap"]),
+    ("\u{1F3A5}", &["movie_camera"]),
+    ("\u{1F5FF}", &["moyai"]),
+    ("\u{1F1F2}\u{1F1FF}", &["mozambique"]),
+    ("\u{1F936}", &["mrs_claus"]),
+    ("\u{1F4AA}", &["muscle"]),
+    ("\u{1F344}", &["mushroom"]),
+    ("\u{1F3B9}", &["musical_keyboard"]),
+    ("\u{1F3B5}", &["musical_note"]),
+    ("\u{1F3BC}", &["musical_score"]),
+    ("\u{1F507}", &["mute"]),
+    ("\u{1F9D1}\u{200D}\u{1F384}", &["mx_claus"]),
+    ("\u{1F1F2}\u{1F1F2}", &["myanmar"]),
+    ("\u{1F485}", &["nail_care"]),
+    ("\u{1F4DB}", &["name_badge"]),
+    ("\u{1F1F3}\u{1F1E6}", &["namibia"]),
+    ("\u{1F3DE}\u{FE0F}", &["national_park"]),
+    ("\u{1F1F3}\u{1F1F7}", &["nauru"]),
+    ("\u{1F922}", &["nauseated_face"]),
+    ("\u{1F9FF}", &["nazar_amulet"]),
+    ("\u{1F454}", &["necktie"]),
+    ("\u{274E}", &["negative_squared_cross_mark"]),
+    ("\u{1F1F3}\u{1F1F5}", &["nepal"]),
+    ("\u{1F913}", &["nerd_face"]),
+    ("\u{1FABA}", &["nest_with_eggs"]),
+    ("\u{1FA86}", &["nesting_dolls"]),
+    ("\u{1F1F3}\u{1F1F1}", &["netherlands"]),
+    ("\u{1F610}", &["neutral_face"]),
+    ("\u{1F195}", &["new"]),
+    ("\u{1F1F3}\u{1F1E8}", &["new_caledonia"]),
+    ("\u{1F311}", &["new_moon"]),
+    ("\u{1F31A}", &["new_moon_with_face"]),
+    ("\u{1F1F3}\u{1F1FF}", &["new_zealand"]),
+    ("\u{1F4F0}", &["newspaper"]),
+    ("\u{1F5DE}\u{FE0F}", &["newspaper_roll"]),
+    ("\u{23ED}\u{FE0F}", &["next_track_button"]),
+    ("\u{1F196}", &["ng"]),
+    ("\u{1F1F3}\u{1F1EE}", &["nicaragua"]),
+    ("\u{1F1F3}\u{1F1EA}", &["niger"]),
+    ("\u{1F1F3}\u{1F1EC}", &["nigeria"]),
+    ("\u{1F303}", &["night_with_stars"]),
+    ("\u{0039}\u{FE0F}\u{20E3}", &["nine"]),
+    ("\u{1F977}", &["ninja"]),
+    ("\u{1F1F3}\u{1F1FA}", &["niue"]),
+    ("\u{1F515}", &["no_bell"]),
+    ("\u{1F6B3}", &["no_bicycles"]),
+    ("\u{26D4}", &["no_entry"]),
+    ("\u{1F6AB}", &["no_entry_sign"]),
+    ("\u{1F645}", &["no_good"]),
+    ("\u{1F645}\u{200D}\u{2642}\u{FE0F}", &["no_good_man", "ng_man"]),
+    ("\u{1F645}\u{200D}\u{2640}\u{FE0F}", &["no_good_woman", "ng_woman"]),
+    ("\u{1F4F5}", &["no_mobile_phones"]),
+    ("\u{1F636}", &["no_mouth"]),
+    ("\u{1F6B7}", &["no_pedestrians"]),
+    ("\u{1F6AD}", &["no_smoking"]),
+    ("\u{1F6B1}", &["non-potable_water"]),
+    ("\u{1F1F3}\u{1F1EB}", &["norfolk_island"]),
+    ("\u{1F1F0}\u{1F1F5}", &["north_korea"]),
+    ("\u{1F1F2}\u{1F1F5}", &["northern_mariana_islands"]),
+    ("\u{1F1F3}\u{1F1F4}", &["norway"]),
+    ("\u{1F443}", &["nose"]),
+    ("\u{1F4D3}", &["notebook"]),
+    ("\u{1F4D4}", &["notebook_with_decorative_cover"]),
+    ("\u{1F3B6}", &["notes"]),
+    ("\u{1F529}", &["nut_and_bolt"]),
+    ("\u{2B55}", &["o"]),
+    ("\u{1F17E}\u{FE0F}", &["o2"]),
+    ("\u{1F30A}", &["ocean"]),
+    ("\u{1F419}", &["octopus"]),
+    ("\u{1F362}", &["oden"]),
+    ("\u{1F3E2}", &["office"]),
+    ("\u{1F9D1}\u{200D}\u{1F4BC}", &["office_worker"]),
+    ("\u{1F6E2}\u{FE0F}", &["oil_drum"]),
+    ("\u{1F197}", &["ok"]),
+    ("\u{1F44C}", &["ok_hand"]),
+    ("\u{1F646}\u{200D}\u{2642}\u{FE0F}", &["ok_man"]),
+    ("\u{1F646}", &["ok_person"]),
+    ("\u{1F646}\u{200D}\u{2640}\u{FE0F}", &["ok_woman"]),
+    ("\u{1F5DD}\u{FE0F}", &["old_key"]),
+    ("\u{1F9D3}", &["older_adult"]),
+    ("\u{1F474}", &["older_man"]),
+    ("\u{1F475}", &["older_woman"]),
+    ("\u{1FAD2}", &["olive"]),
+    ("\u{1F549}\u{FE0F}", &["om"]),
+    ("\u{1F1F4}\u{1F1F2}", &["oman"]),
+    ("\u{1F51B}", &["on"]),
+    ("\u{1F698}", &["oncoming_automobile"]),
+    ("\u{1F68D}", &["oncoming_bus"]),
+    ("\u{1F694}", &["oncoming_police_car"]),
+    ("\u{1F696}", &["oncoming_taxi"]),
+    ("\u{0031}\u{FE0F}\u{20E3}", &["one"]),
+    ("\u{1FA71}", &["one_piece_swimsuit"]),
+    ("\u{1F9C5}", &["onion"]),
+    ("\u{1F4C2}", &["open_file_folder"]),
+    ("\u{1F450}", &["open_hands"]),
+    ("\u{1F62E}", &["open_mouth"]),
+    ("\u{2602}\u{FE0F}", &["open_umbrella"]),
+    ("\u{26CE}", &["ophiuchus"]),
+    ("\u{1F4D9}", &["orange_book"]),
+    ("\u{1F7E0}", &["orange_circle"]),
+    ("\u{1F9E1}", &["orange_heart"]),
+    ("\u{1F7E7}", &["orange_square"]),
+    ("\u{1F9A7}", &["orangutan"]),
+    ("\u{2626}\u{FE0F}", &["orthodox_cross"]),
+    ("\u{1F9A6}", &["otter"]),
+    ("\u{1F4E4}", &["outbox_tray"]),
+    ("\u{1F989}", &["owl"]),
+    ("\u{1F402}", &["ox"]),
+    ("\u{1F9AA}", &["oyster"]),
+    ("\u{1F4E6}", &["package"]),
+    ("\u{1F4C4}", &["page_facing_up"]),
+    ("\u{1F4C3}", &["page_with_curl"]),
+    ("\u{1F4DF}", &["pager"]),
+    ("\u{1F58C}\u{FE0F}", &["paintbrush"]),
+    ("\u{1F1F5}\u{1F1F0}", &["pakistan"]),
+    ("\u{1F1F5}\u{1F1FC}", &["palau"]),
+    ("\u{1F1F5}\u{1F1F8}", &["palestinian_territories"]),
+    ("\u{1FAF3}", &["palm_down_hand"]),
+    ("\u{1F334}", &["palm_tree"]),
+    ("\u{1FAF4}", &["palm_up_hand"]),
+    ("\u{1F932}", &["palms_up_together"]),
+    ("\u{1F1F5}\u{1F1E6}", &["panama"]),
+    ("\u{1F95E}", &["pancakes"]),
+    ("\u{1F43C}", &["panda_face"]),
+    ("\u{1F4CE}", &["paperclip"]),
+    ("\u{1F587}\u{FE0F}", &["paperclips"]),
+    ("\u{1F1F5}\u{1F1EC}", &["papua_new_guinea"]),
+    ("\u{1FA82}", &["parachute"]),
+    ("\u{1F1F5}\u{1F1FE}", &["paraguay"]),
+    ("\u{26F1}\u{FE0F}", &["parasol_on_ground"]),
+    ("\u{1F17F}\u{FE0F}", &["parking"]),
+    ("\u{1F99C}", &["parrot"]),
+    ("\u{303D}\u{FE0F}", &["part_alternation_mark"]),
+    ("\u{26C5}", &["partly_sunny"]),
+    ("\u{1F973}", &["partying_face"]),
+    ("\u{1F6F3}\u{FE0F}", &["passenger_ship"]),
+    ("\u{1F6C2}", &["passport_control"]),
+    ("\u{23F8}\u{FE0F}", &["pause_button"]),
+    ("\u{1FADB}", &["pea_pod"]),
+    ("\u{262E}\u{FE0F}", &["peace_symbol"]),
+    ("\u{1F351}", &["peach"]),
+    ("\u{1F99A}", &["peacock"]),
+    ("\u{1F95C}", &["peanuts"]),
+    ("\u{1F350}", &["pear"]),
+    ("\u{1F58A}\u{FE0F}", &["pen"]),
+    ("\u{270F}\u{FE0F}", &["pencil2"]),
+    ("\u{1F427}", &["penguin"]),
+    ("\u{1F614}", &["pensive"]),
+    ("\u{1F9D1}\u{200D}\u{1F91D}\u{200D}\u{1F9D1}", &["people_holding_hands"]),
+    ("\u{1FAC2}", &["people_hugging"]),
+    ("\u{1F3AD}", &["performing_arts"]),
+    ("\u{1F623}", &["persevere"]),
+    ("\u{1F9D1}\u{200D}\u{1F9B2}", &["person_bald"]),
+    ("\u{1F9D1}\u{200D}\u{1F9B1}", &["person_curly_hair"]),
+    ("\u{1F9D1}\u{200D}\u{1F37C}", &["person_feeding_baby"]),
+    ("\u{1F93A}", &["person_fencing"]),
+    ("\u{1F9D1}\u{200D}\u{1F9BD}", &["person_in_manual_wheelchair"]),
+    ("\u{1F9D1}\u{200D}\u{1F9BC}", &["person_in_motorized_wheelchair"]),
+    ("\u{1F935}", &["person_in_tuxedo"]),
+    ("\u{1F9D1}\u{200D}\u{1F9B0}", &["person_red_hair"]),
+    ("\u{1F9D1}\u{200D}\u{1F9B3}", &["person_white_hair"]),
+    ("\u{1FAC5}", &["person_with_crown"]),
+    ("\u{1F9D1}\u{200D}\u{1F9AF}", &["person_with_probing_cane"]),
+    ("\u{1F473}", &["person_with_turban"]),
+    ("\u{1F470}", &["person_with_veil"]),
+    ("\u{1F1F5}\u{1F1EA}", &["peru"]),
+    ("\u{1F9EB}", &["petri_dish"]),
+    ("\u{1F1F5}\u{1F1ED}", &["philippines"]),
+    ("\u{260E}\u{FE0F}", &["phone", "telephone"]),
+    ("\u{26CF}\u{FE0F}", &["pick"]),
+    ("\u{1F6FB}", &["pickup_truck"]),
+    ("\u{1F967}", &["pie"]),
+    ("\u{1F437}", &["pig"]),
+    ("\u{1F416}", &["pig2"]),
+    ("\u{1F43D}", &["pig_nose"]),
+    ("\u{1F48A}", &["pill"]),
+    ("\u{1F9D1}\u{200D}\u{2708}\u{FE0F}", &["pilot"]),
+    ("\u{1FA85}", &["pinata"]),
+    ("\u{1F90C}", &["pinched_fingers"]),
+    ("\u{1F90F}", &["pinching_hand"]),
+    ("\u{1F34D}", &["pineapple"]),
+    ("\u{1F3D3}", &["ping_pong"]),
+    ("\u{1FA77}", &["pink_heart"]),
+    ("\u{1F3F4}\u{200D}\u{2620}\u{FE0F}", &["pirate_flag"]),
+    ("\u{2653}", &["pisces"]),
+    ("\u{1F1F5}\u{1F1F3}", &["pitcairn_islands"]),
+    ("\u{1F355}", &["pizza"]),
+    ("\u{1FAA7}", &["placard"]),
+    ("\u{1F6D0}", &["place_of_worship"]),
+    ("\u{1F37D}\u{FE0F}", &["plate_with_cutlery"]),
+    ("\u{23EF}\u{FE0F}", &["play_or_pause_button"]),
+    ("\u{1F6DD}", &["playground_slide"]),
+    ("\u{1F97A}", &["pleading_face"]),
+    ("\u{1FAA0}", &["plunger"]),
+    ("\u{1F447}", &["point_down"]),
+    ("\u{1F448}", &["point_left"]),
+    ("\u{1F449}", &["point_right"]),
+    ("\u{261D}\u{FE0F}", &["point_up"]),
+    ("\u{1F446}", &["point_up_2"]),
+    ("\u{1F1F5}\u{1F1F1}", &["poland"]),
+    ("\u{1F43B}\u{200D}\u{2744}\u{FE0F}", &["polar_bear"]),
+    ("\u{1F693}", &["police_car"]),
+    ("\u{1F46E}", &["police_officer", "cop"]),
+    ("\u{1F46E}\u{200D}\u{2642}\u{FE0F}", &["policeman"]),
+    ("\u{1F46E}\u{200D}\u{2640}\u{FE0F}", &["policewoman"]),
+    ("\u{1F429}", &["poodle"]),
+    ("\u{1F37F}", &["popcorn"]),
+    ("\u{1F1F5}\u{1F1F9}", &["portugal"]),
+    ("\u{1F3E3}", &["post_office"]),
+    ("\u{1F4EF}", &["postal_horn"]),
+    ("\u{1F4EE}", &["postbox"]),
+    ("\u{1F6B0}", &["potable_water"]),
+    ("\u{1F954}", &["potato"]),
+    ("\u{1FAB4}", &["potted_plant"]),
+    ("\u{1F45D}", &["pouch"]),
+    ("\u{1F357}", &["poultry_leg"]),
+    ("\u{1F4B7}", &["pound"]),
+    ("\u{1FAD7}", &["pouring_liquid"]),
+    ("\u{1F63E}", &["pouting_cat"]),
+    ("\u{1F64E}", &["pouting_face"]),
+    ("\u{1F64E}\u{200D}\u{2642}\u{FE0F}", &["pouting_man"]),
+    ("\u{1F64E}\u{200D}\u{2640}\u{FE0F}", &["pouting_woman"]),
+    ("\u{1F64F}", &["pray"]),
+    ("\u{1F4FF}", &["prayer_beads"]),
+    ("\u{1FAC3}", &["pregnant_man"]),
+    ("\u{1FAC4}", &["pregnant_person"]),
+    ("\u{1F930}", &["pregnant_woman"]),
+    ("\u{1F968}", &["pretzel"]),
+    ("\u{23EE}\u{FE0F}", &["previous_track_button"]),
+    ("\u{1F934}", &["prince"]),
+    ("\u{1F478}", &["princess"]),
+    ("\u{1F5A8}\u{FE0F}", &["printer"]),
+    ("\u{1F9AF}", &["probing_cane"]),
+    ("\u{1F1F5}\u{1F1F7}", &["puerto_rico"]),
+    ("\u{1F7E3}", &["purple_circle"]),
+    ("\u{1F49C}", &["purple_heart"]),
+    ("\u{1F7EA}", &["purple_square"]),
+    ("\u{1F45B}", &["purse"]),
+    ("\u{1F4CC}", &["pushpin"]),
+    ("\u{1F6AE}", &["put_litter_in_its_place"]),
+    ("\u{1F1F6}\u{1F1E6}", &["qatar"]),
+    ("\u{2753}", &["question"]),
+    ("\u{1F430}", &["rabbit"]),
+    ("\u{1F407}", &["rabbit2"]),
+    ("\u{1F99D}", &["raccoon"]),
+    ("\u{1F40E}", &["racehorse"]),
+    ("\u{1F3CE}\u{FE0F}", &["racing_car"]),
+    ("\u{1F4FB}", &["radio"]),
+    ("\u{1F518}", &["radio_button"]),
+    ("\u{2622}\u{FE0F}", &["radioactive"]),
+    ("\u{1F621}", &["rage", "pout"]),
+    ("\u{1F683}", &["railway_car"]),
+    ("\u{1F6E4}\u{FE0F}", &["railway_track"]),
+    ("\u{1F308}", &["rainbow"]),
+    ("\u{1F3F3}\u{FE0F}\u{200D}\u{1F308}", &["rainbow_flag"]),
+    ("\u{1F91A}", &["raised_back_of_hand"]),
+    ("\u{1F928}", &["raised_eyebrow"]),
+    ("\u{270B}", &["raised_hand", "hand"]),
+    ("\u{1F590}\u{FE0F}", &["raised_hand_with_fingers_splayed"]),
+    ("\u{1F64C}", &["raised_hands"]),
+    ("\u{1F64B}", &["raising_hand"]),
+    ("\u{1F64B}\u{200D}\u{2642}\u{FE0F}", &["raising_hand_man"]),
+    ("\u{1F64B}\u{200D}\u{2640}\u{FE0F}", &["raising_hand_woman"]),
+    ("\u{1F40F}", &["ram"]),
+    ("\u{1F35C}", &["ramen"]),
+    ("\u{1F400}", &["rat"]),
+    ("\u{1FA92}", &["razor"]),
+    ("\u{1F9FE}", &["receipt"]),
+    ("\u{23FA}\u{FE0F}", &["record_button"]),
+    ("\u{267B}\u{FE0F}", &["recycle"]),
+    ("\u{1F534}", &["red_circle"]),
+    ("\u{1F9E7}", &["red_envelope"]),
+    ("\u{1F468}\u{200D}\u{1F9B0}", &["red_haired_man"]),
+    ("\u{1F469}\u{200D}\u{1F9B0}", &["red_haired_woman"]),
+    ("\u{1F7E5}", &["red_square"]),
+    ("\u{00AE}\u{FE0F}", &["registered"]),
+    ("\u{263A}\u{FE0F}", &["relaxed"]),
+    ("\u{1F60C}", &["relieved"]),
+    ("\u{1F397}\u{FE0F}", &["reminder_ribbon"]),
+    ("\u{1F501}", &["repeat"]),
+    ("\u{1F502}", &["repeat_one"]),
+    ("\u{26D1}\u{FE0F}", &["rescue_worker_helmet"]),
+    ("\u{1F6BB}", &["restroom"]),
+    ("\u{1F1F7}\u{1F1EA}", &["reunion"]),
+    ("\u{1F49E}", &["revolving_hearts"]),
+    ("\u{23EA}", &["rewind"]),
+    ("\u{1F98F}", &["rhinoceros"]),
+    ("\u{1F380}", &["ribbon"]),
+    ("\u{1F35A}", &["rice"]),
+    ("\u{1F359}", &["rice_ball"]),
+    ("\u{1F358}", &["rice_cracker"]),
+    ("\u{1F391}", &["rice_scene"]),
+    ("\u{1F5EF}\u{FE0F}", &["right_anger_bubble"]),
+    ("\u{1FAF1}", &["rightwards_hand"]),
+    ("\u{1FAF8}", &["rightwards_pushing_hand"]),
+    ("\u{1F48D}", &["ring"]),
+    ("\u{1F6DF}", &["ring_buoy"]),
+    ("\u{1FA90}", &["ringed_planet"]),
+    ("\u{1F916}", &["robot"]),
+    ("\u{1FAA8}", &["rock"]),
+    ("\u{1F680}", &["rocket"]),
+    ("\u{1F923}", &["rofl"]),
+    ("\u{1F644}", &["roll_eyes"]),
+    ("\u{1F9FB}", &["roll_of_paper"]),
+    ("\u{1F3A2}", &["roller_coaster"]),
+    ("\u{1F6FC}", &["roller_skate"]),
+    ("\u{1F1F7}\u{1F1F4}", &["romania"]),
+    ("\u{1F413}", &["rooster"]),
+    ("\u{1F339}", &["rose"]),
+    ("\u{1F3F5}\u{FE0F}", &["rosette"]),
+    ("\u{1F6A8}", &["rotating_light"]),
+    ("\u{1F4CD}", &["round_pushpin"]),
+    ("\u{1F6A3}", &["rowboat"]),
+    ("\u{1F6A3}\u{200D}\u{2642}\u{FE0F}", &["rowing_man"]),
+    ("\u{1F6A3}\u{200D}\u{2640}\u{FE0F}", &["rowing_woman"]),
+    ("\u{1F1F7}\u{1F1FA}", &["ru"]),
+    ("\u{1F3C9}", &["rugby_football"]),
+    ("\u{1F3C3}", &["runner", "running"]),
+    ("\u{1F3C3}\u{200D}\u{2642}\u{FE0F}", &["running_man"]),
+    ("\u{1F3BD}", &["running_shirt_with_sash"]),
+    ("\u{1F3C3}\u{200D}\u{2640}\u{FE0F}", &["running_woman"]),
+    ("\u{1F1F7}\u{1F1FC}", &["rwanda"]),
+    ("\u{1F202}\u{FE0F}", &["sa"]),
+    ("\u{1F9F7}", &["safety_pin"]),
+    ("\u{1F9BA}", &["safety_vest"]),
+    ("\u{2650}", &["sagittarius"]),
+    ("\u{1F376}", &["sake"]),
+    ("\u{1F9C2}", &["salt"]),
+    ("\u{1FAE1}", &["saluting_face"]),
+    ("\u{1F1FC}\u{1F1F8}", &["samoa"]),
+    ("\u{1F1F8}\u{1F1F2}", &["san_marino"]),
+    ("\u{1F461}", &["sandal"]),
+    ("\u{1F96A}", &["sandwich"]),
+    ("\u{1F385}", &["santa"]),
+    ("\u{1F1F8}\u{1F1F9}", &["sao_tome_principe"]),
+    ("\u{1F97B}", &["sari"]),
+    ("\u{1F4E1}", &["satellite"]),
+    ("\u{1F1F8}\u{1F1E6}", &["saudi_arabia"]),
+    ("\u{1F9D6}\u{200D}\u{2642}\u{FE0F}", &["sauna_man"]),
+    ("\u{1F9D6}", &["sauna_person"]),
+    ("\u{1F9D6}\u{200D}\u{2640}\u{FE0F}", &["sauna_woman"]),
+    ("\u{1F995}", &["sauropod"]),
+    ("\u{1F3B7}", &["saxophone"]),
+    ("\u{1F9E3}", &["scarf"]),
+    ("\u{1F3EB}", &["school"]),
+    ("\u{1F392}", &["school_satchel"]),
+    ("\u{1F9D1}\u{200D}\u{1F52C}", &["scientist"]),
+    ("\u{2702}\u{FE0F}", &["scissors"]),
+    ("\u{1F982}", &["scorpion"]),
+    ("\u{264F}", &["scorpius"]),
+    ("\u{1F3F4}\u{E0067}\u{E0062}\u{E0073}\u{E0063}\u{E0074}\u{E007F}", &["scotland"]),
+    ("\u{1F631}", &["scream"]),
+    ("\u{1F640}", &["scream_cat"]),
+    ("\u{1FA9B}", &["screwdriver"]),
+    ("\u{1F4DC}", &["scroll"]),
+    ("\u{1F9AD}", &["seal"]),
+    ("\u{1F4BA}", &["seat"]),
+    ("\u{3299}\u{FE0F}", &["secret"]),
+    ("\u{1F648}", &["see_no_evil"]),
+    ("\u{1F331}", &["seedling"]),
+    ("\u{1F933}", &["selfie"]),
+    ("\u{1F1F8}\u{1F1F3}", &["senegal"]),
+    ("\u{1F1F7}\u{1F1F8}", &["serbia"]),
+    ("\u{1F415}\u{200D}\u{1F9BA}", &["service_dog"]),
+    ("\u{0037}\u{FE0F}\u{20E3}", &["seven"]),
+    ("\u{1FAA1}", &["sewing_needle"]),
+    ("\u{1F1F8}\u{1F1E8}", &["seychelles"]),
+    ("\u{1FAE8}", &["shaking_face"]),
+    ("\u{1F958}", &["shallow_pan_of_food"]),
+    ("\u{2618}\u{FE0F}", &["shamrock"]),
+    ("\u{1F988}", &["shark"]),
+    ("\u{1F367}", &["shaved_ice"]),
+    ("\u{1F411}", &["sheep"]),
+    ("\u{1F41A}", &["shell"]),
+    ("\u{1F6E1}\u{FE0F}", &["shield"]),
+    ("\u{26E9}\u{FE0F}", &["shinto_shrine"]),
+    ("\u{1F6A2}", &["ship"]),
+    ("\u{1F455}", &["shirt", "tshirt"]),
+    ("\u{1F6CD}\u{FE0F}", &["shopping"]),
+    ("\u{1F6D2}", &["shopping_cart"]),
+    ("\u{1FA73}", &["shorts"]),
+    ("\u{1F6BF}", &["shower"]),
+    ("\u{1F990}", &["shrimp"]),
+    ("\u{1F937}", &["shrug"]),
+    ("\u{1F92B}", &["shushing_face"]),
+    ("\u{1F1F8}\u{1F1F1}", &["sierra_leone"]),
+    ("\u{1F4F6}", &["signal_strength"]),
+    ("\u{1F1F8}\u{1F1EC}", &["singapore"]),
+    ("\u{1F9D1}\u{200D}\u{1F3A4}", &["singer"]),
+    ("\u{1F1F8}\u{1F1FD}", &["sint_maarten"]),
+    ("\u{0036}\u{FE0F}\u{20E3}", &["six"]),
+    ("\u{1F52F}", &["six_pointed_star"]),
+    ("\u{1F6F9}", &["skateboard"]),
+    ("\u{1F3BF}", &["ski"]),
+    ("\u{26F7}\u{FE0F}", &["skier"]),
+    ("\u{1F480}", &["skull"]),
+    ("\u{2620}\u{FE0F}", &["skull_and_crossbones"]),
+    ("\u{1F9A8}", &["skunk"]),
+    ("\u{1F6F7}", &["sled"]),
+    ("\u{1F634}", &["sleeping"]),
+    ("\u{1F6CC}", &["sleeping_bed"]),
+    ("\u{1F62A}", &["sleepy"]),
+    ("\u{1F641}", &["slightly_frowning_face"]),
+    ("\u{1F642}", &["slightly_smiling_face"]),
+    ("\u{1F3B0}", &["slot_machine"]),
+    ("\u{1F9A5}", &["sloth"]),
+    ("\u{1F1F8}\u{1F1F0}", &["slovakia"]),
+    ("\u{1F1F8}\u{1F1EE}", &["slovenia"]),
+    ("\u{1F6E9}\u{FE0F}", &["small_airplane"]),
+    ("\u{1F539}", &["small_blue_diamond"]),
+    ("\u{1F538}", &["small_orange_diamond"]),
+    ("\u{1F53A}", &["small_red_triangle"]),
+    ("\u{1F53B}", &["small_red_triangle_down"]),
+    ("\u{1F604}", &["smile"]),
+    ("\u{1F638}", &["smile_cat"]),
+    ("\u{1F603}", &["smiley"]),
+    ("\u{1F63A}", &["smiley_cat"]),
+    ("\u{1F972}", &["smiling_face_with_tear"]),
+    ("\u{1F970}", &["smiling_face_with_three_hearts"]),
+    ("\u{1F608}", &["smiling_imp"]),
+    ("\u{1F60F}", &["smirk"]),
+    ("\u{1F63C}", &["smirk_cat"]),
+    ("\u{1F6AC}", &["smoking"]),
+    ("\u{1F40C}", &["snail"]),
+    ("\u{1F40D}", &["snake"]),
+    ("\u{1F927}", &["sneezing_face"]),
+    ("\u{1F3C2}", &["snowboarder"]),
+    ("\u{2744}\u{FE0F}", &["snowflake"]),
+    ("\u{26C4}", &["snowman"]),
+    ("\u{2603}\u{FE0F}", &["snowman_with_snow"]),
+    ("\u{1F9FC}", &["soap"]),
+    ("\u{1F62D}", &["sob"]),
+    ("\u{26BD}", &["soccer"]),
+    ("\u{1F9E6}", &["socks"]),
+    ("\u{1F94E}", &["softball"]),
+    ("\u{1F1F8}\u{1F1E7}", &["solomon_islands"]),
+    ("\u{1F1F8}\u{1F1F4}", &["somalia"]),
+    ("\u{1F51C}", &["soon"]),
+    ("\u{1F198}", &["sos"]),
+    ("\u{1F509}", &["sound"]),
+    ("\u{1F1FF}\u{1F1E6}", &["south_africa"]),
+    ("\u{1F1EC}\u{1F1F8}", &["south_georgia_south_sandwich_islands"]),
+    ("\u{1F1F8}\u{1F1F8}", &["south_sudan"]),
+    ("\u{1F47E}", &["space_invader"]),
+    ("\u{2660}\u{FE0F}", &["spades"]),
+    ("\u{1F35D}", &["spaghetti"]),
+    ("\u{2747}\u{FE0F}", &["sparkle"]),
+    ("\u{1F387}", &["sparkler"]),
+    ("\u{2728}", &["sparkles"]),
+    ("\u{1F496}", &["sparkling_heart"]),
+    ("\u{1F64A}", &["speak_no_evil"]),
+    ("\u{1F508}", &["speaker"]),
+    ("\u{1F5E3}\u{FE0F}", &["speaking_head"]),
+    ("\u{1F4AC}", &["speech_balloon"]),
+    ("\u{1F6A4}", &["speedboat"]),
+    ("\u{1F577}\u{FE0F}", &["spider"]),
+    ("\u{1F578}\u{FE0F}", &["spider_web"]),
+    ("\u{1F5D3}\u{FE0F}", &["spiral_calendar"]),
+    ("\u{1F5D2}\u{FE0F}", &["spiral_notepad"]),
+    ("\u{1F9FD}", &["sponge"]),
+    ("\u{1F944}", &["spoon"]),
+    ("\u{1F991}", &["squid"]),
+    ("\u{1F1F1}\u{1F1F0}", &["sri_lanka"]),
+    ("\u{1F1E7}\u{1F1F1}", &["st_barthelemy"]),
+    ("\u{1F1F8}\u{1F1ED}", &["st_helena"]),
+    ("\u{1F1F0}\u{1F1F3}", &["st_kitts_nevis"]),
+    ("\u{1F1F1}\u{1F1E8}", &["st_lucia"]),
+    ("\u{1F1F2}\u{1F1EB}", &["st_martin"]),
+    ("\u{1F1F5}\u{1F1F2}", &["st_pierre_miquelon"]),
+    ("\u{1F1FB}\u{1F1E8}", &["st_vincent_grenadines"]),
+    ("\u{1F3DF}\u{FE0F}", &["stadium"]),
+    ("\u{1F9CD}\u{200D}\u{2642}\u{FE0F}", &["standing_man"]),
+    ("\u{1F9CD}", &["standing_person"]),
+    ("\u{1F9CD}\u{200D}\u{2640}\u{FE0F}", &["standing_woman"]),
+    ("\u{2B50}", &["star"]),
+    ("\u{1F31F}", &["star2"]),
+    ("\u{262A}\u{FE0F}", &["star_and_crescent"]),
+    ("\u{2721}\u{FE0F}", &["star_of_david"]),
+    ("\u{1F929}", &["star_struck"]),
+    ("\u{1F320}", &["stars"]),
+    ("\u{1F689}", &["station"]),
+    ("\u{1F5FD}", &["statue_of_liberty"]),
+    ("\u{1F682}", &["steam_locomotive"]),
+    ("\u{1FA7A}", &["stethoscope"]),
+    ("\u{1F372}", &["stew"]),
+    ("\u{23F9}\u{FE0F}", &["stop_button"]),
+    ("\u{1F6D1}", &["stop_sign"]),
+    ("\u{23F1}\u{FE0F}", &["stopwatch"]),
+    ("\u{1F4CF}", &["straight_ruler"]),
+    ("\u{1F353}", &["strawberry"]),
+    ("\u{1F61B}", &["stuck_out_tongue"]),
+    ("\u{1F61D}", &["stuck_out_tongue_closed_eyes"]),
+    ("\u{1F61C}", &["stuck_out_tongue_winking_eye"]),
+    ("\u{1F9D1}\u{200D}\u{1F393}", &["student"]),
+    ("\u{1F399}\u{FE0F}", &["studio_microphone"]),
+    ("\u{1F959}", &["stuffed_flatbread"]),
+    ("\u{1F1F8}\u{1F1E9}", &["sudan"]),
+    ("\u{1F325}\u{FE0F}", &["sun_behind_large_cloud"]),
+    ("\u{1F326}\u{FE0F}", &["sun_behind_rain_cloud"]),
+    ("\u{1F324}\u{FE0F}", &["sun_behind_small_cloud"]),
+    ("\u{1F31E}", &["sun_with_face"]),
+    ("\u{1F33B}", &["sunflower"]),
+    ("\u{1F60E}", &["sunglasses"]),
+    ("\u{2600}\u{FE0F}", &["sunny"]),
+    ("\u{1F305}", &["sunrise"]),
+    ("\u{1F304}", &["sunrise_over_mountains"]),
+    ("\u{1F9B8}", &["superhero"]),
+    ("\u{1F9B8}\u{200D}\u{2642}\u{FE0F}", &["superhero_man"]),
+    ("\u{1F9B8}\u{200D}\u{2640}\u{FE0F}", &["superhero_woman"]),
+    ("\u{1F9B9}", &["supervillain"]),
+    ("\u{1F9B9}\u{200D}\u{2642}\u{FE0F}", &["supervillain_man"]),
+    ("\u{1F9B9}\u{200D}\u{2640}\u{FE0F}", &["supervillain_woman"]),
+    ("\u{1F3C4}", &["surfer"]),
+    ("\u{1F3C4}\u{200D}\u{2642}\u{FE0F}", &["surfing_man"]),
+    ("\u{1F3C4}\u{200D}\u{2640}\u{FE0F}", &["surfing_woman"]),
+    ("\u{1F1F8}\u{1F1F7}", &["suriname"]),
+    ("\u{1F363}", &["sushi"]),
+    ("\u{1F69F}", &["suspension_railway"]),
+    ("\u{1F1F8}\u{1F1EF}", &["svalbard_jan_mayen"]),
+    ("\u{1F9A2}", &["swan"]),
+    ("\u{1F1F8}\u{1F1FF}", &["swaziland"]),
+    ("\u{1F613}", &["sweat"]),
+    ("\u{1F4A6}", &["sweat_drops"]),
+    ("\u{1F605}", &["sweat_smile"]),
+    ("\u{1F1F8}\u{1F1EA}", &["sweden"]),
+    ("\u{1F360}", &["sweet_potato"]),
+    ("\u{1FA72}", &["swim_brief"]),
+    ("\u{1F3CA}", &["swimmer"]),
+    ("\u{1F3CA}\u{200D}\u{2642}\u{FE0F}", &["swimming_man"]),
+    ("\u{1F3CA}\u{200D}\u{2640}\u{FE0F}", &["swimming_woman"]),
+    ("\u{1F1E8}\u{1F1ED}", &["switzerland"]),
+    ("\u{1F523}", &["symbols"]),
+    ("\u{1F54D}", &["synagogue"]),
+    ("\u{1F1F8}\u{1F1FE}", &["syria"]),
+    ("\u{1F489}", &["syringe"]),
+    ("\u{1F996}", &["t-rex"]),
+    ("\u{1F32E}", &["taco"]),
+    ("\u{1F389}", &["tada"]),
+    ("\u{1F1F9}\u{1F1FC}", &["taiwan"]),
+    ("\u{1F1F9}\u{1F1EF}", &["tajikistan"]),
+    ("\u{1F961}", &["takeout_box"]),
+    ("\u{1FAD4}", &["tamale"]),
+    ("\u{1F38B}", &["tanabata_tree"]),
+    ("\u{1F34A}", &["tangerine", "mandarin", "orange"]),
+    ("\u{1F1F9}\u{1F1FF}", &["tanzania"]),
+    ("\u{2649}", &["taurus"]),
+    ("\u{1F695}", &["taxi"]),
+    ("\u{1F375}", &["tea"]),
+    ("\u{1F9D1}\u{200D}\u{1F3EB}", &["teacher"]),
+    ("\u{1FAD6}", &["teapot"]),
+    ("\u{1F9D1}\u{200D}\u{1F4BB}", &["technologist"]),
+    ("\u{1F9F8}", &["teddy_bear"]),
+    ("\u{1F4DE}", &["telephone_receiver"]),
+    ("\u{1F52D}", &["telescope"]),
+    ("\u{1F3BE}", &["tennis"]),
+    ("\u{26FA}", &["tent"]),
+    ("\u{1F9EA}", &["test_tube"]),
+    ("\u{1F1F9}\u{1F1ED}", &["thailand"]),
+    ("\u{1F321}\u{FE0F}", &["thermometer"]),
+    ("\u{1F914}", &["thinking"]),
+    ("\u{1FA74}", &["thong_sandal"]),
+    ("\u{1F4AD}", &["thought_balloon"]),
+    ("\u{1F9F5}", &["thread"]),
+    ("\u{0033}\u{FE0F}\u{20E3}", &["three"]),
+    ("\u{1F3AB}", &["ticket"]),
+    ("\u{1F39F}\u{FE0F}", &["tickets"]),
+    ("\u{1F42F}", &["tiger"]),
+    ("\u{1F405}", &["tiger2"]),
+    ("\u{23F2}\u{FE0F}", &["timer_clock"]),
+    ("\u{1F1F9}\u{1F1F1}", &["timor_leste"]),
+    ("\u{1F481}\u{200D}\u{2642}\u{FE0F}", &["tipping_hand_man", "sassy_man"]),
+    ("\u{1F481}", &["tipping_hand_person", "information_desk_person"]),
+    ("\u{1F481}\u{200D}\u{2640}\u{FE0F}", &["tipping_hand_woman", "sassy_woman"]),
+    ("\u{1F62B}", &["tired_face"]),
+    ("\u{2122}\u{FE0F}", &["tm"]),
+    ("\u{1F1F9}\u{1F1EC}", &["togo"]),
+    ("\u{1F6BD}", &["toilet"]),
+    ("\u{1F1F9}\u{1F1F0}", &["tokelau"]),
+    ("\u{1F5FC}", &["tokyo_tower"]),
+    ("\u{1F345}", &["tomato"]),
+    ("\u{1F1F9}\u{1F1F4}", &["tonga"]),
+    ("\u{1F445}", &["tongue"]),
+    ("\u{1F9F0}", &["toolbox"]),
+    ("\u{1F9B7}", &["tooth"]),
+    ("\u{1FAA5}", &["toothbrush"]),
+    ("\u{1F51D}", &["top"]),
+    ("\u{1F3A9}", &["tophat"]),
+    ("\u{1F32A}\u{FE0F}", &["tornado"]),
+    ("\u{1F1F9}\u{1F1F7}", &["tr"]),
+    ("\u{1F5B2}\u{FE0F}", &["trackball"]),
+    ("\u{1F69C}", &["tractor"]),
+    ("\u{1F6A5}", &["traffic_light"]),
+    ("\u{1F68B}", &["train"]),
+    ("\u{1F686}", &["train2"]),
+    ("\u{1F68A}", &["tram"]),
+    ("\u{1F3F3}\u{FE0F}\u{200D}\u{26A7}\u{FE0F}", &["transgender_flag"]),
+    ("\u{26A7}\u{FE0F}", &["transgender_symbol"]),
+    ("\u{1F6A9}", &["triangular_flag_on_post"]),
+    ("\u{1F4D0}", &["triangular_ruler"]),
+    ("\u{1F531}", &["trident"]),
+    ("\u{1F1F9}\u{1F1F9}", &["trinidad_tobago"]),
+    ("\u{1F1F9}\u{1F1E6}", &["tristan_da_cunha"]),
+    ("\u{1F624}", &["triumph"]),
+    ("\u{1F9CC}", &["troll"]),
+    ("\u{1F68E}", &["trolleybus"]),
+    ("\u{1F3C6}", &["trophy"]),
+    ("\u{1F379}", &["tropical_drink"]),
+    ("\u{1F420}", &["tropical_fish"]),
+    ("\u{1F69A}", &["truck"]),
+    ("\u{1F3BA}", &["trumpet"]),
+    ("\u{1F337}", &["tulip"]),
+    ("\u{1F943}", &["tumbler_glass"]),
+    ("\u{1F1F9}\u{1F1F3}", &["tunisia"]),
+    ("\u{1F983}", &["turkey"]),
+    ("\u{1F1F9}\u{1F1F2}", &["turkmenistan"]),
+    ("\u{1F1F9}\u{1F1E8}", &["turks_caicos_islands"]),
+    ("\u{1F422}", &["turtle"]),
+    ("\u{1F1F9}\u{1F1FB}", &["tuvalu"]),
+    ("\u{1F4FA}", &["tv"]),
+    ("\u{1F500}", &["twisted_rightwards_arrows"]),
+    ("\u{0032}\u{FE0F}\u{20E3}", &["two"]),
+    ("\u{1F495}", &["two_hearts"]),
+    ("\u{1F46C}", &["two_men_holding_hands"]),
+    ("\u{1F46D}", &["two_women_holding_hands"]),
+    ("\u{1F239}", &["u5272"]),
+    ("\u{1F234}", &["u5408"]),
+    ("\u{1F23A}", &["u55b6"]),
+    ("\u{1F22F}", &["u6307"]),
+    ("\u{1F237}\u{FE0F}", &["u6708"]),
+    ("\u{1F236}", &["u6709"]),
+    ("\u{1F235}", &["u6e80"]),
+    ("\u{1F21A}", &["u7121"]),
+    ("\u{1F238}", &["u7533"]),
+    ("\u{1F232}", &["u7981"]),
+    ("\u{1F233}", &["u7a7a"]),
+    ("\u{1F1FA}\u{1F1EC}", &["uganda"]),
+    ("\u{1F1FA}\u{1F1E6}", &["ukraine"]),
+    ("\u{2614}", &["umbrella"]),
+    ("\u{1F612}", &["unamused"]),
+    ("\u{1F51E}", &["underage"]),
+    ("\u{1F984}", &["unicorn"]),
+    ("\u{1F1E6}\u{1F1EA}", &["united_arab_emirates"]),
+    ("\u{1F1FA}\u{1F1F3}", &["united_nations"]),
+    ("\u{1F513}", &["unlock"]),
+    ("\u{1F199}", &["up"]),
+    ("\u{1F643}", &["upside_down_face"]),
+    ("\u{1F1FA}\u{1F1FE}", &["uruguay"]),
+    ("\u{1F1FA}\u{1F1F8}", &["us"]),
+    ("\u{1F1FA}\u{1F1F2}", &["us_outlying_islands"]),
+    ("\u{1F1FB}\u{1F1EE}", &["us_virgin_islands"]),
+    ("\u{1F1FA}\u{1F1FF}", &["uzbekistan"]),
+    ("\u{270C}\u{FE0F}", &["v"]),
+    ("\u{1F9DB}", &["vampire"]),
+    ("\u{1F9DB}\u{200D}\u{2642}\u{FE0F}", &["vampire_man"]),
+    ("\u{1F9DB}\u{200D}\u{2640}\u{FE0F}", &["vampire_woman"]),
+    ("\u{1F1FB}\u{1F1FA}", &["vanuatu"]),
+    ("\u{1F1FB}\u{1F1E6}", &["vatican_city"]),
+    ("\u{1F1FB}\u{1F1EA}", &["venezuela"]),
+    ("\u{1F6A6}", &["vertical_traffic_light"]),
+    ("\u{1F4FC}", &["vhs"]),
+    ("\u{1F4F3}", &["vibration_mode"]),
+    ("\u{1F4F9}", &["video_camera"]),
+    ("\u{1F3AE}", &["video_game"]),
+    ("\u{1F1FB}\u{1F1F3}", &["vietnam"]),
+    ("\u{1F3BB}", &["violin"]),
+    ("\u{264D}", &["virgo"]),
+    ("\u{1F30B}", &["volcano"]),
+    ("\u{1F3D0}", &["volleyball"]),
+    ("\u{1F92E}", &["vomiting_face"]),
+    ("\u{1F19A}", &["vs"]),
+    ("\u{1F596}", &["vulcan_salute"]),
+    ("\u{1F9C7}", &["waffle"]),
+    ("\u{1F3F4}\u{E0067}\u{E0062}\u{E0077}\u{E006C}\u{E0073}\u{E007F}", &["wales"]),
+    ("\u{1F6B6}", &["walking"]),
+    ("\u{1F6B6}\u{200D}\u{2642}\u{FE0F}", &["walking_man"]),
+    ("\u{1F6B6}\u{200D}\u{2640}\u{FE0F}", &["walking_woman"]),
+    ("\u{1F1FC}\u{1F1EB}", &["wallis_futuna"]),
+    ("\u{1F318}", &["waning_crescent_moon"]),
+    ("\u{1F316}", &["waning_gibbous_moon"]),
+    ("\u{26A0}\u{FE0F}", &["warning"]),
+    ("\u{1F5D1}\u{FE0F}", &["wastebasket"]),
+    ("\u{231A}", &["watch"]),
+    ("\u{1F403}", &["water_buffalo"]),
+    ("\u{1F93D}", &["water_polo"]),
+    ("\u{1F349}", &["watermelon"]),
+    ("\u{1F44B}", &["wave"]),
+    ("\u{3030}\u{FE0F}", &["wavy_dash"]),
+    ("\u{1F312}", &["waxing_crescent_moon"]),
+    ("\u{1F6BE}", &["wc"]),
+    ("\u{1F629}", &["weary"]),
+    ("\u{1F492}", &["wedding"]),
+    ("\u{1F3CB}\u{FE0F}", &["weight_lifting"]),
+    ("\u{1F3CB}\u{FE0F}\u{200D}\u{2642}\u{FE0F}", &["weight_lifting_man"]),
+    ("\u{1F3CB}\u{FE0F}\u{200D}\u{2640}\u{FE0F}", &["weight_lifting_woman"]),
+    ("\u{1F1EA}\u{1F1ED}", &["western_sahara"]),
+    ("\u{1F433}", &["whale"]),
+    ("\u{1F40B}", &["whale2"]),
+    ("\u{1F6DE}", &["wheel"]),
+    ("\u{2638}\u{FE0F}", &["wheel_of_dharma"]),
+    ("\u{267F}", &["wheelchair"]),
+    ("\u{2705}", &["white_check_mark"]),
+    ("\u{26AA}", &["white_circle"]),
+    ("\u{1F3F3}\u{FE0F}", &["white_flag"]),
+    ("\u{1F4AE}", &["white_flower"]),
+    ("\u{1F468}\u{200D}\u{1F9B3}", &["white_haired_man"]),
+    ("\u{1F469}\u{200D}\u{1F9B3}", &["white_haired_woman"]),
+    ("\u{1F90D}", &["white_heart"]),
+    ("\u{2B1C}", &["white_large_square"]),
+    ("\u{25FD}", &["white_medium_small_square"]),
+    ("\u{25FB}\u{FE0F}", &["white_medium_square"]),
+    ("\u{25AB}\u{FE0F}", &["white_small_square"]),
+    ("\u{1F533}", &["white_square_button"]),
+    ("\u{1F940}", &["wilted_flower"]),
+    ("\u{1F390}", &["wind_chime"]),
+    ("\u{1F32C}\u{FE0F}", &["wind_face"]),
+    ("\u{1FA9F}", &["window"]),
+    ("\u{1F377}", &["wine_glass"]),
+    ("\u{1FABD}", &["wing"]),
+    ("\u{1F609}", &["wink"]),
+    ("\u{1F6DC}", &["wireless"]),
+    ("\u{1F43A}", &["wolf"]),
+    ("\u{1F469}", &["woman"]),
+    ("\u{1F469}\u{200D}\u{1F3A8}", &["woman_artist"]),
+    ("\u{1F469}\u{200D}\u{1F680}", &["woman_astronaut"]),
+    ("\u{1F9D4}\u{200D}\u{2640}\u{FE0F}", &["woman_beard"]),
+    ("\u{1F938}\u{200D}\u{2640}\u{FE0F}", &["woman_cartwheeling"]),
+    ("\u{1F469}\u{200D}\u{1F373}", &["woman_cook"]),
+    ("\u{1F926}\u{200D}\u{2640}\u{FE0F}", &["woman_facepalming"]),
+    ("\u{1F469}\u{200D}\u{1F3ED}", &["woman_factory_worker"]),
+    ("\u{1F469}\u{200D}\u{1F33E}", &["woman_farmer"]),
+    ("\u{1F469}\u{200D}\u{1F37C}", &["woman_feeding_baby"]),
+    ("\u{1F469}\u{200D}\u{1F692}", &["woman_firefighter"]),
+    ("\u{1F469}\u{200D}\u{2695}\u{FE0F}", &["woman_health_worker"]),
+    ("\u{1F469}\u{200D}\u{1F9BD}", &["woman_in_manual_wheelchair"]),
+    ("\u{1F469}\u{200D}\u{1F9BC}", &["woman_in_motorized_wheelchair"]),
+    ("\u{1F935}\u{200D}\u{2640}\u{FE0F}", &["woman_in_tuxedo"]),
+    ("\u{1F469}\u{200D}\u{2696}\u{FE0F}", &["woman_judge"]),
+    ("\u{1F939}\u{200D}\u{2640}\u{FE0F}", &["woman_juggling"]),
+    ("\u{1F469}\u{200D}\u{1F527}", &["woman_mechanic"]),
+    ("\u{1F469}\u{200D}\u{1F4BC}", &["woman_office_worker"]),
+    ("\u{1F469}\u{200D}\u{2708}\u{FE0F}", &["woman_pilot"]),
+    ("\u{1F93E}\u{200D}\u{2640}\u{FE0F}", &["woman_playing_handball"]),
+    ("\u{1F93D}\u{200D}\u{2640}\u{FE0F}", &["woman_playing_water_polo"]),
+    ("\u{1F469}\u{200D}\u{1F52C}", &["woman_scientist"]),
+    ("\u{1F937}\u{200D}\u{2640}\u{FE0F}", &["woman_shrugging"]),
+    ("\u{1F469}\u{200D}\u{1F3A4}", &["woman_singer"]),
+    ("\u{1F469}\u{200D}\u{1F393}", &["woman_student"]),
+    ("\u{1F469}\u{200D}\u{1F3EB}", &["woman_teacher"]),
+    ("\u{1F469}\u{200D}\u{1F4BB}", &["woman_technologist"]),
+    ("\u{1F9D5}", &["woman_with_headscarf"]),
+    ("\u{1F469}\u{200D}\u{1F9AF}", &["woman_with_probing_cane"]),
+    ("\u{1F473}\u{200D}\u{2640}\u{FE0F}", &["woman_with_turban"]),
+    ("\u{1F470}\u{200D}\u{2640}\u{FE0F}", &["woman_with_veil", "bride_with_veil"]),
+    ("\u{1F45A}", &["womans_clothes"]),
+    ("\u{1F452}", &["womans_hat"]),
+    ("\u{1F93C}\u{200D}\u{2640}\u{FE0F}", &["women_wrestling"]),
+    ("\u{1F6BA}", &["womens"]),
+    ("\u{1FAB5}", &["wood"]),
+    ("\u{1F974}", &["woozy_face"]),
+    ("\u{1F5FA}\u{FE0F}", &["world_map"]),
+    ("\u{1FAB1}", &["worm"]),
+    ("\u{1F61F}", &["worried"]),
+    ("\u{1F527}", &["wrench"]),
+    ("\u{1F93C}", &["wrestling"]),
+    ("\u{270D}\u{FE0F}", &["writing_hand"]),
+    ("\u{274C}", &["x"]),
+    ("\u{1FA7B}", &["x_ray"]),
+    ("\u{1F9F6}", &["yarn"]),
+    ("\u{1F971}", &["yawning_face"]),
+    ("\u{1F7E1}", &["yellow_circle"]),
+    ("\u{1F49B}", &["yellow_heart"]),
+    ("\u{1F7E8}", &["yellow_square"]),
+    ("\u{1F1FE}\u{1F1EA}", &["yemen"]),
+    ("\u{1F4B4}", &["yen"]),
+    ("\u{262F}\u{FE0F}", &["yin_yang"]),
+    ("\u{1FA80}", &["yo_yo"]),
+    ("\u{1F60B}", &["yum"]),
+    ("\u{1F1FF}\u{1F1F2}", &["zambia"]),
+    ("\u{1F92A}", &["zany_face"]),
+    ("\u{26A1}", &["zap"]),
+    ("\u{1F993}", &["zebra"]),
+    ("\u{0030}\u{FE0F}\u{20E3}", &["zero"]),
+    ("\u{1F1FF}\u{1F1FC}", &["zimbabwe"]),
+    ("\u{1F910}", &["zipper_mouth_face"]),
+    ("\u{1F9DF}", &["zombie"]),
+    ("\u{1F9DF}\u{200D}\u{2642}\u{FE0F}", &["zombie_man"]),
+    ("\u{1F9DF}\u{200D}\u{2640}\u{FE0F}", &["zombie_woman"]),
+    ("\u{1F4A4}", &["zzz"]),
+]
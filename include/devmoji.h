@@ -0,0 +1,37 @@
+/* C API of devmoji, built with
+ *   cargo build --release --features ffi
+ * Strings are UTF-8 and NUL-terminated. Returned strings are owned by the
+ * caller and freed with devmoji_string_free. */
+#ifndef DEVMOJI_H
+#define DEVMOJI_H
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+typedef struct devmoji devmoji_t;
+
+/* Load the config at config_path, or the nearest one when it is NULL.
+ * NULL on failure, e.g. when that config is missing or invalid. */
+devmoji_t *devmoji_new(const char *config_path);
+void devmoji_free(devmoji_t *handle);
+
+/* Format a commit message like devmoji --edit does. NULL on failure. */
+char *devmoji_format_commit(const devmoji_t *handle, const char *text);
+
+/* Replace shortcodes with emoji. NULL on failure. */
+char *devmoji_emojify(const devmoji_t *handle, const char *text);
+
+/* Lint a commit message. 0 when it passes, 1 when it doesn't, -1 when handle
+ * or text is NULL, -2 when text isn't UTF-8 and -3 when linting failed.
+ * Unless messages is NULL, *messages is set to the errors, or the warnings of
+ * a message that passes, one per line, and to NULL when there are none. */
+int devmoji_lint(const devmoji_t *handle, const char *text, char **messages);
+
+void devmoji_string_free(char *s);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif
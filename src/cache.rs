@@ -0,0 +1,103 @@
+use std::path::{Path, PathBuf};
+
+use crate::config::dirs_home;
+use crate::fnv::Fnv;
+
+/// Where evaluated JS configs are cached: `node_modules/.cache/devmoji` of the
+/// config's project, else the per-user cache directory.
+pub fn find_cache_dir(config: &Path) -> Option<PathBuf> {
+    config
+        .parent()
+        .and_then(project_cache_dir)
+        .or_else(user_cache_dir)
+}
+
+/// `node_modules/.cache/devmoji` in `dir` or its nearest parent with node_modules
+fn project_cache_dir(dir: &Path) -> Option<PathBuf> {
+    dir.ancestors()
+        .map(|dir| dir.join("node_modules"))
+        .find(|dir| dir.is_dir())
+        .map(|dir| dir.join(".cache").join("devmoji"))
+}
+
+/// `$XDG_CACHE_HOME/devmoji`, or `~/.cache/devmoji`
+pub fn user_cache_dir() -> Option<PathBuf> {
+    std::env::var_os("XDG_CACHE_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| dirs_home().map(|home| home.join(".cache")))
+        .map(|dir| dir.join("devmoji"))
+}
+
+/// Cache key for a config: its absolute path and the hash of its contents.
+pub fn key(config: &Path, contents: &str) -> String {
+    let path = config.canonicalize().unwrap_or_else(|_| config.to_path_buf());
+    let mut hash = Fnv::new();
+    hash.write(path.to_string_lossy().as_bytes());
+    hash.write(&[0]);
+    hash.write(contents.as_bytes());
+    hash.hex()
+}
+
+pub fn get(dir: &Path, key: &str) -> Option<String> {
+    std::fs::read_to_string(dir.join(format!("{}.json", key))).ok()
+}
+
+pub fn put(dir: &Path, key: &str, json: &str) -> std::io::Result<()> {
+    std::fs::create_dir_all(dir)?;
+    // Write then rename, so concurrent runs never read a partial entry
+    let tmp = dir.join(format!("{}.json.{}", key, std::process::id()));
+    std::fs::write(&tmp, json)?;
+    std::fs::rename(&tmp, dir.join(format!("{}.json", key)))
+}
+
+/// Remove the cache of the current project and the per-user cache, returning
+/// the directories that were removed.
+pub fn clear() -> Result<Vec<String>, String> {
+    let cwd = std::env::current_dir().map_err(|e| e.to_string())?;
+    let mut dirs: Vec<PathBuf> = project_cache_dir(&cwd)
+        .into_iter()
+        .chain(user_cache_dir())
+        .collect();
+    dirs.dedup();
+
+    let mut removed = Vec::new();
+    for dir in dirs.into_iter().filter(|d| d.is_dir()) {
+        std::fs::remove_dir_all(&dir)
+            .map_err(|e| format!("Error removing {}: {}", dir.display(), e))?;
+        removed.push(format!("Removed {}", dir.display()));
+    }
+    if removed.is_empty() {
+        removed.push("Cache is empty".to_string());
+    }
+    Ok(removed)
+}
+
+/// Count the entries in a cache directory, failing on ones that aren't valid
+/// JSON or were left behind half-written.
+pub fn check(dir: &Path) -> Result<usize, String> {
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(0),
+        Err(e) => return Err(format!("could not read {}: {}", dir.display(), e)),
+    };
+    let mut count = 0;
+    let mut broken = Vec::new();
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let name = entry.file_name().to_string_lossy().into_owned();
+        let valid = name.ends_with(".json")
+            && std::fs::read_to_string(&path)
+                .is_ok_and(|json| serde_json::from_str::<serde_json::Value>(&json).is_ok());
+        if valid {
+            count += 1;
+        } else {
+            broken.push(name);
+        }
+    }
+    if broken.is_empty() {
+        Ok(count)
+    } else {
+        Err(format!("broken entries in {}: {}", dir.display(), broken.join(", ")))
+    }
+}
@@ -0,0 +1,98 @@
+//! The system clipboard, for `--clipboard`. Needs the `clipboard` feature.
+
+/// The text on the clipboard
+#[cfg(feature = "clipboard")]
+pub fn read() -> Result<String, String> {
+    arboard::Clipboard::new()
+        .and_then(|mut clipboard| clipboard.get_text())
+        .map_err(|e| format!("Can't read the clipboard: {}", e))
+}
+
+/// Set for the copy of devmoji that holds the clipboard on Linux, see `hold`
+pub const HOLDER_ENV: &str = "DEVMOJI_CLIPBOARD_HOLDER";
+
+/// Put `text` on the clipboard
+#[cfg(all(feature = "clipboard", any(not(unix), target_os = "macos")))]
+pub fn write(text: &str) -> Result<(), String> {
+    arboard::Clipboard::new()
+        .and_then(|mut clipboard| clipboard.set_text(text))
+        .map_err(|e| format!("Can't write the clipboard: {}", e))
+}
+
+/// Put `text` on the clipboard. On Linux the clipboard is served by the
+/// process that set it and is gone when that exits, so a copy of devmoji is
+/// started in the background to `hold` it until something else is copied.
+#[cfg(all(feature = "clipboard", unix, not(target_os = "macos")))]
+pub fn write(text: &str) -> Result<(), String> {
+    use std::io::Write;
+    use std::os::unix::process::CommandExt;
+    use std::process::{Command, Stdio};
+    use std::time::{Duration, Instant};
+
+    let error = |e: &dyn std::fmt::Display| format!("Can't write the clipboard: {}", e);
+    // Fail here rather than in the background, e.g. without a display
+    drop(arboard::Clipboard::new().map_err(|e| error(&e))?);
+    let exe = std::env::current_exe().map_err(|e| error(&e))?;
+    let mut holder = Command::new(exe)
+        .env(HOLDER_ENV, "1")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        // Not killed with the shell's foreground job on Ctrl-C
+        .process_group(0)
+        .spawn()
+        .map_err(|e| error(&e))?;
+    let mut stdin = holder.stdin.take().unwrap();
+    stdin.write_all(text.as_bytes()).map_err(|e| error(&e))?;
+    drop(stdin);
+
+    // The holder never says it's done, wait until the text can be pasted
+    let deadline = Instant::now() + Duration::from_secs(2);
+    while Instant::now() < deadline {
+        if let Ok(Some(status)) = holder.try_wait() {
+            return Err(error(&format!("the background process exited with {}", status)));
+        }
+        if read().is_ok_and(|current| current == text) {
+            return Ok(());
+        }
+        std::thread::sleep(Duration::from_millis(20));
+    }
+    Err(error(&"the background process didn't take it"))
+}
+
+/// Put the text on stdin on the clipboard and serve it until something else
+/// is copied. Run in the background by `write`.
+#[cfg(all(feature = "clipboard", unix, not(target_os = "macos")))]
+pub fn hold() -> Result<(), String> {
+    use arboard::SetExtLinux;
+    use std::io::Read;
+
+    let mut text = String::new();
+    std::io::stdin()
+        .read_to_string(&mut text)
+        .map_err(|e| e.to_string())?;
+    arboard::Clipboard::new()
+        .and_then(|mut clipboard| clipboard.set().wait().text(text))
+        .map_err(|e| e.to_string())
+}
+
+/// Only Linux and the BSDs need a process to hold the clipboard
+#[cfg(not(all(feature = "clipboard", unix, not(target_os = "macos"))))]
+pub fn hold() -> Result<(), String> {
+    Ok(())
+}
+
+#[cfg(not(feature = "clipboard"))]
+pub fn read() -> Result<String, String> {
+    Err(unsupported())
+}
+
+#[cfg(not(feature = "clipboard"))]
+pub fn write(_text: &str) -> Result<(), String> {
+    Err(unsupported())
+}
+
+#[cfg(not(feature = "clipboard"))]
+fn unsupported() -> String {
+    "devmoji was built without the clipboard feature".to_string()
+}
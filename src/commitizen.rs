@@ -0,0 +1,140 @@
+//! Commitizen configs, read as a config layer so projects using commitizen
+//! keep their types, scopes and emoji in one place. The types and scopes
+//! come from the `list` questions of `cz_customize`.
+
+use std::path::{Path, PathBuf};
+
+use serde_json::{json, Map, Value};
+
+use crate::github_emoji::{EMOJI_CODES, GITHUB_EMOJIS};
+use crate::trace;
+
+/// Commitizen config files, in the order commitizen looks for them
+const CONFIG_FILES: &[&str] = &["pyproject.toml", ".cz.toml", ".cz.json", "cz.json", "cz.toml"];
+
+/// Names of the questions asking for the type and the scope
+const TYPE_QUESTIONS: &[&str] = &["change_type", "type", "prefix"];
+const SCOPE_QUESTIONS: &[&str] = &["scope", "scopes"];
+
+pub fn is_commitizen_config(path: &Path) -> bool {
+    path.file_name()
+        .and_then(|name| name.to_str())
+        .is_some_and(|name| CONFIG_FILES.contains(&name))
+}
+
+/// The commitizen config in `dir`. A `pyproject.toml` only counts with a
+/// `[tool.commitizen]` table.
+pub fn config_in(dir: &Path) -> Option<PathBuf> {
+    CONFIG_FILES.iter().map(|name| dir.join(name)).find(|candidate| {
+        trace!("config", "looking for {}", candidate.display());
+        candidate.exists() && read_settings(candidate).is_ok()
+    })
+}
+
+/// A commitizen config as a devmoji config
+pub fn load(path: &Path) -> Result<Value, String> {
+    let settings = read_settings(path)?;
+    let questions = settings
+        .pointer("/customize/questions")
+        .and_then(Value::as_array)
+        .map_or(&[][..], Vec::as_slice);
+    let question = |names: &[&str]| {
+        questions.iter().find(|q| {
+            q.get("name")
+                .and_then(Value::as_str)
+                .is_some_and(|name| names.contains(&name))
+        })
+    };
+
+    let mut config = Map::new();
+    if let Some(choices) = question(TYPE_QUESTIONS).and_then(|q| q.get("choices")) {
+        let choices = choices.as_array().ok_or("the type choices aren't a list")?;
+        let devmoji: Vec<Value> = choices.iter().filter_map(devmoji_entry).collect();
+        let types: Vec<&Value> = devmoji.iter().map(|entry| &entry["code"]).collect();
+        config.insert("types".to_string(), json!(types));
+        config.insert("devmoji".to_string(), json!(devmoji));
+    }
+    if let Some(choices) = question(SCOPE_QUESTIONS).and_then(|q| q.get("choices")) {
+        let choices = choices.as_array().ok_or("the scope choices aren't a list")?;
+        let scopes: Vec<String> = choices
+            .iter()
+            .filter_map(|choice| choice_value(choice).map(|(value, _)| value))
+            .collect();
+        config.insert("scopes".to_string(), json!(scopes));
+    }
+    Ok(Value::Object(config))
+}
+
+/// The commitizen settings in a config file: `[tool.commitizen]` in TOML,
+/// `"commitizen"` in JSON
+fn read_settings(path: &Path) -> Result<Value, String> {
+    let contents = std::fs::read_to_string(path).map_err(|e| e.to_string())?;
+    let is_json = path.extension().is_some_and(|ext| ext == "json");
+    let (value, pointer) = if is_json {
+        let value: Value = serde_json::from_str(&contents).map_err(|e| e.to_string())?;
+        (value, "/commitizen")
+    } else {
+        let value: Value = toml::from_str(&contents).map_err(|e| e.to_string())?;
+        (value, "/tool/commitizen")
+    };
+    value
+        .pointer(pointer)
+        .filter(|settings| settings.is_object())
+        .cloned()
+        .ok_or_else(|| format!("no commitizen settings in {}", path.display()))
+}
+
+/// A type choice as a devmoji entry. Its emoji is an `emoji` key, or a
+/// leading emoji or shortcode in its name, e.g. `✨ feat: a new feature`.
+fn devmoji_entry(choice: &Value) -> Option<Value> {
+    let (code, name) = choice_value(choice)?;
+    let explicit = choice.get("emoji").and_then(Value::as_str).and_then(shortcode);
+    let (leading, description) = match name.split_once(char::is_whitespace) {
+        Some((first, rest)) => match shortcode(first) {
+            Some(code) => (Some(code), rest.trim_start()),
+            None => (None, name.as_str()),
+        },
+        None => (None, name.as_str()),
+    };
+    // Names usually repeat the type, as in `feat: a new feature`
+    let description = description
+        .strip_prefix(code.as_str())
+        .and_then(|rest| rest.strip_prefix(':'))
+        .unwrap_or(description)
+        .trim();
+
+    let mut entry = Map::new();
+    entry.insert("code".to_string(), json!(code));
+    if let Some(emoji) = explicit.or(leading) {
+        entry.insert("emoji".to_string(), json!(emoji));
+    }
+    if !description.is_empty() && description != code {
+        entry.insert("description".to_string(), json!(description));
+    }
+    Some(Value::Object(entry))
+}
+
+/// The value and name of a choice, which is a string or a `value`/`name` table
+fn choice_value(choice: &Value) -> Option<(String, String)> {
+    let (value, name) = match choice {
+        Value::String(value) => (value.as_str(), value.as_str()),
+        Value::Object(choice) => {
+            let value = choice.get("value").and_then(Value::as_str)?;
+            (value, choice.get("name").and_then(Value::as_str).unwrap_or(value))
+        }
+        _ => return None,
+    };
+    let value = value.trim();
+    (!value.is_empty()).then(|| (value.to_string(), name.trim().to_string()))
+}
+
+/// The shortcode of a `:shortcode:` or a known emoji
+fn shortcode(text: &str) -> Option<String> {
+    if let Some(code) = text.strip_prefix(':').and_then(|t| t.strip_suffix(':')) {
+        return GITHUB_EMOJIS.contains_key(code).then(|| code.to_string());
+    }
+    EMOJI_CODES
+        .get(text)
+        .or_else(|| EMOJI_CODES.get(text.replace('\u{fe0f}', "").as_str()))
+        .map(|code| code.to_string())
+}
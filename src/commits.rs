@@ -1,9 +1,15 @@
-use colored::Colorize;
+use std::collections::HashMap;
+
 use once_cell::sync::Lazy;
 use regex::Regex;
 
-use crate::config::Config;
+use crate::config::{
+    BreakingPolicy, Config, EmojiPlacement, EmojiPolicy, EmojiTrailer, GitmojiPolicy, Rule,
+    Severity, SpecialCommits,
+};
 use crate::devmoji::Devmoji;
+use crate::theme::Colorize;
+use crate::{debug, spec, tickets, tr, trace};
 
 static COMMIT_RE: Lazy<Regex> = Lazy::new(|| {
     Regex::new(r"(?mi)(?P<type>:?[a-z][a-z0-9-]*)(?:\((?P<scope>[a-z0-9-]+)\))?(?P<breaking>!?):\s*(?:(?P<other>(?::[a-z0-9_+-]+:\s*)+)\s*)?")
@@ -13,9 +19,95 @@ static COMMIT_RE: Lazy<Regex> = Lazy::new(|| {
 static BREAKING_CHANGE_RE: Lazy<Regex> =
     Lazy::new(|| Regex::new(r"(?m)^\s*BREAKING CHANGE").unwrap());
 
+/// Headers written gitmoji style, e.g. `:sparkles: add login page`
+static GITMOJI_HEADER_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^(?P<codes>(?::[a-z0-9_+-]+:[ \t]*)+)").unwrap());
+
+/// Autosquash prefixes git puts in front of the target commit's header
+static AUTOSQUASH_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^(?:(?:fixup|squash|amend)! *)+").unwrap());
+
+/// `--log-format` placeholders: `%{name}`, with optional text around the name
+/// that is only printed when the value isn't empty, e.g. `%{(scope)}`
+static PLACEHOLDER_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"%\{([^a-z{}]*)([a-z]+)([^a-z{}]*)\}").unwrap());
+
 static SHORTCODE_RE: Lazy<Regex> =
     Lazy::new(|| Regex::new(r":([a-zA-Z0-9_\-+]+):").unwrap());
 
+/// A header as loose as `fix` understands it, e.g. `Feat(api):add login.`
+static FIX_HEADER_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^(?P<type>[A-Za-z][A-Za-z0-9-]*)(?:\((?P<scope>[^()\r\n]+)\))?(?P<breaking>!?):(?P<space>[ \t]*)(?P<subject>.*)$")
+        .unwrap()
+});
+
+/// Shortcodes at the end of a line before a header, where `start` placement
+/// puts emoji
+static LEADING_CODES_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?::[a-z0-9_+-]+:[ \t]*)+$").unwrap());
+
+/// Shortcodes at the end of a line after a header, where `end` placement puts
+/// emoji
+static TRAILING_CODES_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?:[ \t]*:[a-z0-9_+-]+:)+[ \t]*$").unwrap());
+
+/// Headers git writes for merges, e.g. `Merge branch 'main' into login`
+static MERGE_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^Merge (?:(?:remote-tracking )?branch(?:es)?|pull request|tag|commit) ").unwrap()
+});
+
+/// Headers git writes for reverts, with the reverted header quoted
+static REVERT_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r#"^Revert "(?P<header>.*)"[ \t]*$"#).unwrap());
+
+/// Merge and revert headers in a log, after the graph, hash and refs
+static LOG_SPECIAL_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"(?m)^(?P<prefix>[*|/\\ ]*(?:[0-9a-f]{7,40} +)?(?:\([^)\n]*\) +)?)(?P<codes>(?::[a-z0-9_+-]+:[ \t]*)*)(?P<header>Merge (?:(?:remote-tracking )?branch(?:es)?|pull request|tag|commit) |Revert ")"#)
+        .unwrap()
+});
+
+/// Lines that look like a footer, written well or not, e.g. `Closes:#12`
+static LOOSE_FOOTER_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^(?:BREAKING[ -]CHANGE|[A-Za-z][A-Za-z0-9_ -]*?)[ \t]*(?::|[ \t]#)").unwrap()
+});
+
+/// The trailer `emojiTrailer` records the codes of the header emoji in, also
+/// indented as in `git log`
+static EMOJI_TRAILER_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?m)^[ \t]*Devmoji:[ \t]*(?P<codes>.*?)[ \t]*\r?$").unwrap());
+
+/// The line starting a commit in `git log`, after the graph
+static LOG_COMMIT_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?m)^[*|/\\ ]*commit [0-9a-f]{7,40}\b").unwrap());
+
+/// The parts of a conventional commit header
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Header {
+    pub commit_type: String,
+    pub scope: Option<String>,
+    pub breaking: bool,
+    pub subject: String,
+    /// The emoji formatting puts in front of the subject
+    pub emoji: String,
+}
+
+/// Something lint found, with the severity its rule is configured with
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Problem {
+    pub rule: Rule,
+    pub severity: Severity,
+    pub message: String,
+}
+
+impl std::fmt::Display for Problem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.severity {
+            Severity::Warn => write!(f, "{}", tr!("warning: {message}", message = self.message)),
+            _ => write!(f, "{}", self.message),
+        }
+    }
+}
+
 pub struct ConventionalCommits<'a> {
     devmoji: &'a Devmoji,
     config: &'a Config,
@@ -34,76 +126,584 @@ impl<'a> ConventionalCommits<'a> {
         self.format(text, false, color)
     }
 
+    /// Format the subject of a commit in `git log`, with the codes of the
+    /// `Devmoji:` trailers in its body
+    pub fn format_log_subject(&self, subject: &str, body: &str, color: bool) -> String {
+        let trailers: Vec<&str> = EMOJI_TRAILER_RE.find_iter(body).map(|m| m.as_str()).collect();
+        if trailers.is_empty() {
+            return self.format_log(subject, color);
+        }
+        // Formatted together with its trailers, which the log formatting reads
+        let text = format!("{}\n{}", subject, trailers.join("\n"));
+        let formatted = self.format_log(&text, color);
+        formatted.split('\n').next().unwrap_or_default().to_string()
+    }
+
+    /// Parse the header at the start of `line` with the configured grammar.
+    /// Autosquash prefixes are skipped and gitmoji-style headers converted.
+    pub fn parse_header(&self, line: &str) -> Option<Header> {
+        self.parse_header_with(line, false)
+    }
+
+    /// Parse the header of a whole commit message, which is also breaking
+    /// when its footers say so.
+    pub fn parse_message(&self, text: &str) -> Option<Header> {
+        let text = self.devmoji.devmojify(text);
+        let has_breaking = self.has_breaking_footer(&text);
+        self.parse_header_with(text.lines().next().unwrap_or(""), has_breaking)
+    }
+
+    fn has_breaking_footer(&self, text: &str) -> bool {
+        if self.config.spec.is_some() {
+            spec::has_breaking_footer(text)
+        } else {
+            BREAKING_CHANGE_RE.is_match(text)
+        }
+    }
+
+    /// Complete a whole message before formatting it: the breaking change
+    /// markers with `completeBreaking` and the trailer with `emojiTrailer`
+    pub fn complete_message(&self, text: &str) -> String {
+        self.add_emoji_trailer(&self.complete_breaking_change(text))
+    }
+
+    /// With `completeBreaking`, add what a whole message is missing to mark a
+    /// breaking change consistently: the `!` in the header for a `BREAKING
+    /// CHANGE` footer, or a footer described by the subject for a `!`.
+    pub fn complete_breaking_change(&self, text: &str) -> String {
+        if !self.config.complete_breaking {
+            return text.to_string();
+        }
+        self.add_breaking_bang(text)
+            .or_else(|| self.add_breaking_footer(text))
+            .unwrap_or_else(|| text.to_string())
+    }
+
+    /// With `emojiTrailer`, record the codes of the header emoji in a
+    /// `Devmoji:` trailer. An earlier one is replaced, keeping its codes.
+    pub fn add_emoji_trailer(&self, text: &str) -> String {
+        if self.config.emoji_trailer == EmojiTrailer::Off {
+            return text.to_string();
+        }
+        let Some(header) = self.parse_message(text) else {
+            return text.to_string();
+        };
+        let mut codes: Vec<String> = Vec::new();
+        let emoji = self.devmoji.devmojify(&header.emoji);
+        let recorded = EMOJI_TRAILER_RE
+            .captures_iter(text)
+            .flat_map(|caps| trailer_codes(caps.name("codes").unwrap().as_str()));
+        for code in SHORTCODE_RE.captures_iter(&emoji).map(|c| c[1].to_string()).chain(recorded) {
+            if !codes.contains(&code) {
+                codes.push(code);
+            }
+        }
+        if codes.is_empty() {
+            return text.to_string();
+        }
+
+        let message: String = text
+            .split_inclusive('\n')
+            .filter(|line| !EMOJI_TRAILER_RE.is_match(line))
+            .collect();
+        let message = message.trim_end();
+        let separator = if message.contains('\n') && spec::parse(message).footer_line.is_some() {
+            "\n"
+        } else {
+            "\n\n"
+        };
+        let ending = if text.ends_with('\n') { "\n" } else { "" };
+        debug!("format", "recording {} in the Devmoji trailer", codes.join(", "));
+        format!("{}{}Devmoji: {}{}", message, separator, codes.join(", "), ending)
+    }
+
+    /// The codes of the `Devmoji:` trailers in a log, as shortcodes by the
+    /// start of the header they belong to: the last header of a configured
+    /// type before the trailer, in the same commit
+    fn log_trailer_codes(&self, text: &str) -> HashMap<usize, String> {
+        let mut headers = HashMap::new();
+        for caps in EMOJI_TRAILER_RE.captures_iter(text) {
+            let trailer = caps.get(0).unwrap();
+            let start = LOG_COMMIT_RE
+                .find_iter(&text[..trailer.start()])
+                .last()
+                .map_or(0, |commit| commit.end());
+            let header = self
+                .header_re()
+                .captures_iter(&text[start..trailer.start()])
+                .filter(|h| self.config.types.iter().any(|t| t.eq_ignore_ascii_case(&h["type"])))
+                .last();
+            if let Some(header) = header {
+                let codes = headers.entry(start + header.get(0).unwrap().start());
+                let codes: &mut String = codes.or_default();
+                for code in trailer_codes(caps.name("codes").unwrap().as_str()) {
+                    codes.push_str(&format!(":{}: ", code));
+                }
+            }
+        }
+        headers
+    }
+
+    fn add_breaking_bang(&self, text: &str) -> Option<String> {
+        let (first_line, body) = text.split_once('\n')?;
+        if !self.has_breaking_footer(body) {
+            return None;
+        }
+        let line = self.devmoji.devmojify(first_line);
+        let start = match GITMOJI_HEADER_RE.find(&line) {
+            Some(codes) if self.has_leading_emoji(&line) => codes.end(),
+            _ => 0,
+        };
+        let caps = self.header_re().captures(&line[start..])?;
+        let bang = caps.name("breaking")?;
+        if caps.get(0).unwrap().start() != 0 || !bang.as_str().is_empty() {
+            return None;
+        }
+        let at = start + bang.start();
+        debug!("format", "added a ! to the header for the BREAKING CHANGE footer");
+        Some(format!("{}!{}\n{}", &line[..at], &line[at..], body))
+    }
+
+    /// Git's comment lines stay at the end
+    fn add_breaking_footer(&self, text: &str) -> Option<String> {
+        let header = self.parse_header(text.lines().next()?)?;
+        if !header.breaking || self.has_breaking_footer(text) {
+            return None;
+        }
+        let description = self.devmoji.strip(&self.devmoji.devmojify(&header.subject));
+        let description = match description.trim() {
+            "" => "describe the breaking change",
+            d => d,
+        };
+
+        let lines: Vec<&str> = text.lines().collect();
+        let end = lines
+            .iter()
+            .rposition(|l| !l.starts_with('#') && !l.trim().is_empty())
+            .map_or(1, |i| i + 1);
+        let message = lines[..end].join("\n");
+        let separator = if end > 1 && spec::parse(&message).footer_line.is_some() {
+            "\n"
+        } else {
+            "\n\n"
+        };
+
+        let mut completed = format!("{}{}BREAKING CHANGE: {}", message, separator, description);
+        for comment in &lines[end..] {
+            completed.push('\n');
+            completed.push_str(comment);
+        }
+        if text.ends_with('\n') {
+            completed.push('\n');
+        }
+        debug!("format", "added a BREAKING CHANGE footer for the ! in the header");
+        Some(completed)
+    }
+
+    fn parse_header_with(&self, line: &str, has_breaking: bool) -> Option<Header> {
+        let (_, line) = split_autosquash(line);
+        let mut line = self.devmoji.devmojify(line);
+        let mut leading = String::new();
+        if self.has_leading_emoji(&line) {
+            let end = GITMOJI_HEADER_RE.find(&line).unwrap().end();
+            leading = line[..end].to_string();
+            line.replace_range(..end, "");
+        } else if self.config.header.is_none() {
+            if let Some(converted) = self.convert_gitmoji_header(&line) {
+                line = converted;
+            }
+        }
+
+        let caps = self.header_re().captures(&line)?;
+        let m = caps.get(0).unwrap();
+        let commit_type = &caps["type"];
+        if m.start() != 0 || commit_type.starts_with(':') {
+            return None;
+        }
+        let subject = match caps.name("subject") {
+            Some(s) if s.end() != m.end() => s.as_str(),
+            Some(s) => &line[s.start()..],
+            None => &line[m.end()..],
+        };
+
+        let mut subject = subject.trim();
+        let mut trailing = "";
+        if self.config.emoji_placement == EmojiPlacement::End {
+            if let Some(c) = TRAILING_CODES_RE.find(subject) {
+                (subject, trailing) = (subject[..c.start()].trim_end(), c.as_str());
+            }
+        }
+        let scope = caps.name("scope").map(|s| s.as_str());
+        let breaking = caps.name("breaking").is_some_and(|b| b.as_str() == "!") || has_breaking;
+        let other = format!(
+            "{} {} {}",
+            leading,
+            caps.name("other").map_or("", |o| o.as_str()),
+            trailing
+        );
+        let tickets = tickets::referenced_emoji(&self.config.tickets, &line);
+        Some(Header {
+            commit_type: commit_type.to_string(),
+            scope: scope.map(str::to_string),
+            breaking,
+            subject: self.devmoji.emojify(subject),
+            emoji: self.format_emoji(commit_type, scope, &other, &tickets, breaking),
+        })
+    }
+
+    /// The emoji `format` adds for a type and scope.
+    pub fn type_emoji(&self, commit_type: &str, scope: Option<&str>) -> String {
+        self.format_emoji(commit_type, scope, "", &[], false)
+    }
+
+    /// Format a log line following a `--log-format` template. Lines without
+    /// a header only get their emoji converted.
+    pub fn format_log_template(&self, text: &str, template: &str, color: bool) -> String {
+        let text = self.devmoji.devmojify(text);
+        // Merges and reverts have no type to fill the template with, they get
+        // their emoji in front of the header like in `--log`
+        if self.config.special_commits != SpecialCommits::Off {
+            let prefix = LOG_SPECIAL_RE
+                .captures(&text)
+                .filter(|caps| caps.get(0).unwrap().start() == 0)
+                .map(|caps| caps.name("prefix").unwrap().end());
+            if let Some(prefix) = prefix {
+                if let Some(special) = self.format_special(&text[prefix..], color) {
+                    return self.devmoji.emojify(&format!("{}{}", &text[..prefix], special));
+                }
+            }
+        }
+        let Some(caps) = self.header_re().captures(&text) else {
+            return self.devmoji.emojify(&text);
+        };
+        let m = caps.get(0).unwrap();
+        let commit_type = &caps["type"];
+        if commit_type.starts_with(':') {
+            return self.devmoji.emojify(&text);
+        }
+
+        let scope = caps.name("scope").map(|s| s.as_str());
+        let breaking = caps.name("breaking").is_some_and(|b| b.as_str() == "!");
+        let other = caps.name("other").map_or("", |o| o.as_str());
+        let tickets = tickets::referenced_emoji(&self.config.tickets, &text);
+        let emoji = self.format_emoji(commit_type, scope, other, &tickets, breaking);
+        let subject = caps.name("subject").map_or(m.end(), |s| s.start());
+
+        let value = |name: &str| -> Option<String> {
+            let value = match name {
+                "hash" => text[..m.start()].trim().to_string(),
+                "emoji" => emoji.clone(),
+                "type" if color => self.config.type_style(commit_type).paint(commit_type),
+                "type" => commit_type.to_string(),
+                "scope" if color => scope.map(|s| s.dimmed().to_string()).unwrap_or_default(),
+                "scope" => scope.unwrap_or("").to_string(),
+                "breaking" => if breaking { "!" } else { "" }.to_string(),
+                "subject" => text[subject..].trim().to_string(),
+                _ => return None,
+            };
+            Some(value)
+        };
+
+        let line = PLACEHOLDER_RE.replace_all(template, |caps: &regex::Captures| {
+            match value(&caps[2]) {
+                Some(v) if v.is_empty() => String::new(),
+                Some(v) => format!("{}{}{}", &caps[1], v, &caps[3]),
+                None => caps[0].to_string(),
+            }
+        });
+        let line = self.devmoji.emojify(&line);
+        if color {
+            tickets::hyperlink(&self.config.tickets, &line)
+        } else {
+            line
+        }
+    }
+
     fn format(&self, text: &str, first_only: bool, color: bool) -> String {
+        // Keep `fixup!` & co. in front and format the header they refer to
+        if first_only {
+            let (prefix, rest) = split_autosquash(text);
+            if !prefix.is_empty() {
+                trace!("format", "keeping autosquash prefix {:?}", prefix.trim_end());
+                return format!("{}{}", prefix, self.format(rest, first_only, color));
+            }
+        }
+
         // First devmojify to normalize existing emoji to devmoji shortcodes
-        let text = self.devmoji.devmojify(text);
+        let mut text = self.devmoji.devmojify(text);
+        if self.config.special_commits != SpecialCommits::Off {
+            if first_only {
+                if let Some(special) = self.format_special(&text, color) {
+                    return self.devmoji.emojify(&special);
+                }
+            } else {
+                text = self.prefix_special_lines(&text);
+            }
+        }
+        if first_only && self.config.header.is_none() && !self.has_leading_emoji(&text) {
+            if let Some(converted) = self.convert_gitmoji_header(&text) {
+                debug!("format", "converted gitmoji header to {:?}", converted.lines().next().unwrap_or(""));
+                text = converted;
+            }
+        }
 
-        let has_breaking = BREAKING_CHANGE_RE.is_match(&text);
+        let has_breaking = self.has_breaking_footer(&text);
+        let trailer_codes = match first_only {
+            true => HashMap::new(),
+            false => self.log_trailer_codes(&text),
+        };
 
         let mut result = String::new();
         let mut last_end = 0;
         let mut found_first = false;
 
-        for caps in COMMIT_RE.captures_iter(&text) {
+        let placement = self.config.emoji_placement;
+        for caps in self.header_re().captures_iter(&text) {
             let m = caps.get(0).unwrap();
+            if m.start() < last_end {
+                continue;
+            }
+            let line_start = text[..m.start()].rfind('\n').map_or(0, |i| i + 1);
+            let line_end = text[m.end()..].find('\n').map_or(text.len(), |i| m.end() + i);
+            let single_line = !m.as_str().contains('\n');
 
-            if first_only && m.start() != 0 {
+            // Emoji already in front of the header (always picked up in a
+            // message), or at the end of its line
+            let lead = if first_only || placement == EmojiPlacement::Start {
+                let from = line_start.max(last_end);
+                LEADING_CODES_RE
+                    .find(&text[from..m.start()])
+                    .map_or(m.start(), |c| from + c.start())
+            } else {
+                m.start()
+            };
+            let trail = match placement {
+                EmojiPlacement::End if single_line => TRAILING_CODES_RE
+                    .find(&text[m.end()..line_end])
+                    .map_or(line_end, |c| m.end() + c.start()),
+                _ => line_end,
+            };
+
+            if !first_only
+                && self.config.special_commits == SpecialCommits::Emoji
+                && text[line_start..m.start()].contains("Revert \"")
+            {
+                trace!("format", "skipping {:?}, it is quoted by a revert", m.as_str());
                 continue;
             }
-            if first_only && found_first {
+
+            if first_only && (lead != 0 || found_first) {
+                trace!("format", "skipping {:?}, only the first line is a header", m.as_str());
                 continue;
             }
 
-            let commit_type = caps.name("type").unwrap().as_str();
+            let type_match = caps.name("type").unwrap();
+            let commit_type = type_match.as_str();
 
             // Skip if type starts with ':' (already a shortcode)
             if commit_type.starts_with(':') {
+                trace!("format", "skipping {:?}, the type is a shortcode", m.as_str());
                 continue;
             }
 
-            let scope = caps.name("scope").map(|m| m.as_str());
-            let breaking = caps.name("breaking").map(|m| m.as_str()) == Some("!");
-            let other = caps.name("other").map(|m| m.as_str()).unwrap_or("");
+            let scope_match = caps.name("scope");
+            let scope = scope_match.map(|m| m.as_str());
+            let breaking_match = caps.name("breaking");
+            let breaking = breaking_match.map(|m| m.as_str()) == Some("!");
+            let other_match = caps.name("other");
+            let subject_match = caps.name("subject");
+            let other = format!(
+                "{} {} {} {}",
+                &text[lead..m.start()],
+                other_match.map_or("", |m| m.as_str()),
+                &text[trail..line_end],
+                trailer_codes.get(&m.start()).map_or("", String::as_str)
+            );
 
-            let emojis =
-                self.format_emoji(commit_type, scope, other, breaking || has_breaking);
+            let tickets = tickets::referenced_emoji(
+                &self.config.tickets,
+                &ticket_text(&text, m.start(), first_only),
+            );
+            let mut emojis =
+                self.format_emoji(commit_type, scope, &other, &tickets, breaking || has_breaking);
+            if first_only && self.config.emoji_trailer == EmojiTrailer::Only {
+                // They are in the trailer instead
+                emojis.clear();
+            }
+            trace!(
+                "format",
+                "{} grammar matched {:?}: type={:?} scope={:?} breaking={} emoji={:?}",
+                self.grammar_name(),
+                m.as_str(),
+                commit_type,
+                scope,
+                breaking || has_breaking,
+                emojis
+            );
 
-            // Build replacement
-            let mut replacement = String::new();
+            // Emoji go where existing ones were, or in front of the subject
+            let insert_at = other_match.or(subject_match).map_or(m.end(), |g| g.start());
+            let resume_at = subject_match.map_or(m.end(), |g| g.start()).max(insert_at);
+
+            // Highlight type and scope, and add the `!` for breaking footers
+            let mut edits: Vec<(usize, usize, String)> = Vec::new();
             if color {
-                replacement.push_str(&commit_type.blue().to_string());
-                if let Some(s) = scope {
-                    replacement.push('(');
-                    replacement.push_str(&s.bold().to_string());
-                    replacement.push(')');
+                let style = self.config.type_style(commit_type);
+                edits.push((type_match.start(), type_match.end(), style.paint(commit_type)));
+                if let Some(s) = scope_match {
+                    edits.push((s.start(), s.end(), s.as_str().dimmed().to_string()));
                 }
-            } else {
-                replacement.push_str(commit_type);
-                if let Some(s) = scope {
-                    replacement.push('(');
-                    replacement.push_str(s);
-                    replacement.push(')');
+            }
+            if has_breaking && !breaking {
+                if let Some(b) = breaking_match {
+                    edits.push((b.start(), b.end(), "!".to_string()));
                 }
             }
-            if breaking || has_breaking {
-                replacement.push('!');
+            edits.sort_by_key(|(start, _, _)| *start);
+
+            // Build replacement
+            let mut replacement = String::new();
+            let mut pos = m.start();
+            for (start, end, edit) in edits {
+                if start < insert_at {
+                    replacement.push_str(&text[pos..start]);
+                    replacement.push_str(&edit);
+                    pos = end;
+                }
             }
-            replacement.push_str(": ");
-            replacement.push_str(&emojis);
-            if !emojis.is_empty() {
-                replacement.push(' ');
+            replacement.push_str(text[pos..insert_at].trim_end());
+            replacement.push(' ');
+            if placement == EmojiPlacement::Subject || (placement == EmojiPlacement::End && !single_line) {
+                replacement.push_str(&emojis);
+                if !emojis.is_empty() {
+                    replacement.push(' ');
+                }
             }
+            replacement.push_str(&text[resume_at..m.end()]);
 
-            result.push_str(&text[last_end..m.start()]);
+            result.push_str(&text[last_end..lead]);
+            if placement == EmojiPlacement::Start && !emojis.is_empty() {
+                result.push_str(&emojis);
+                result.push(' ');
+            }
             result.push_str(&replacement);
             last_end = m.end();
+            if placement == EmojiPlacement::End && single_line {
+                result.push_str(text[m.end()..trail].trim_end());
+                if !emojis.is_empty() {
+                    result.push(' ');
+                    result.push_str(&emojis);
+                }
+                last_end = line_end;
+            }
             found_first = true;
         }
 
         result.push_str(&text[last_end..]);
 
         // Now convert remaining shortcodes based on format
-        self.devmoji.emojify(&result)
+        let result = self.devmoji.emojify(&result);
+        if color && !first_only {
+            tickets::hyperlink(&self.config.tickets, &result)
+        } else {
+            result
+        }
+    }
+
+    /// Format a message with a merge or revert header: its emoji in front and,
+    /// with `full`, the header a revert quotes formatted. Expects devmojified
+    /// text, `None` when the header is neither.
+    fn format_special(&self, text: &str, color: bool) -> Option<String> {
+        let (line, rest) = text.split_at(text.find('\n').unwrap_or(text.len()));
+        let start = GITMOJI_HEADER_RE.find(line).map_or(0, |codes| codes.end());
+        let header = &line[start..];
+        let emoji = self.special_emoji(header)?;
+        debug!("format", "formatting special header {:?}", header);
+
+        // Emoji already in front are kept, except ours
+        let mut result = emoji.clone();
+        for c in SHORTCODE_RE.captures_iter(&line[..start]) {
+            if self.devmoji.get(&c[1]) != emoji {
+                result.push_str(&format!(" {}", &c[0]));
+            }
+        }
+        result.push(' ');
+        match REVERT_RE.captures(header) {
+            Some(caps) if self.config.special_commits == SpecialCommits::Full => {
+                let inner = caps.name("header").unwrap();
+                result.push_str(&header[..inner.start()]);
+                result.push_str(&self.format(inner.as_str(), true, color));
+                result.push_str(&header[inner.end()..]);
+            }
+            _ => result.push_str(header),
+        }
+        result.push_str(rest);
+        Some(result)
+    }
+
+    /// Put the merge or revert emoji in front of such headers in a log.
+    /// Expects devmojified text.
+    fn prefix_special_lines(&self, text: &str) -> String {
+        LOG_SPECIAL_RE
+            .replace_all(text, |caps: &regex::Captures| {
+                let header = caps.name("header").unwrap().as_str();
+                let emoji = self.special_emoji(header).unwrap_or_default();
+                let mut result = format!("{}{}", &caps["prefix"], emoji);
+                for c in SHORTCODE_RE.captures_iter(&caps["codes"]) {
+                    if self.devmoji.get(&c[1]) != emoji {
+                        result.push_str(&format!(" {}", &c[0]));
+                    }
+                }
+                result.push(' ');
+                result.push_str(header);
+                result
+            })
+            .to_string()
+    }
+
+    /// The emoji of a merge or revert header: the pack's `merge` or `revert`
+    /// devmoji when it has them, else the gitmoji
+    fn special_emoji(&self, header: &str) -> Option<String> {
+        let (code, gitmoji) = if MERGE_RE.is_match(header) {
+            ("merge", "twisted_rightwards_arrows")
+        } else if header.starts_with("Revert \"") {
+            ("revert", "rewind")
+        } else {
+            return None;
+        };
+        Some(
+            self.lookup_pack_code(code)
+                .unwrap_or_else(|| self.devmoji.get(gitmoji)),
+        )
+    }
+
+    /// The header grammar: the spec's when enforcing one, else from the
+    /// config or the conventional default
+    fn header_re(&self) -> &Regex {
+        if self.config.spec.is_some() {
+            return &spec::HEADER_RE;
+        }
+        self.config.header.as_ref().unwrap_or(&COMMIT_RE)
+    }
+
+    /// Whether `text` has emoji in front of a header, which is where `start`
+    /// placement puts them. Expects devmojified text.
+    fn has_leading_emoji(&self, text: &str) -> bool {
+        GITMOJI_HEADER_RE.find(text).is_some_and(|codes| {
+            self.header_re()
+                .find(&text[codes.end()..])
+                .is_some_and(|h| h.start() == 0)
+        })
+    }
+
+    fn grammar_name(&self) -> &'static str {
+        if self.config.spec.is_some() {
+            "spec"
+        } else if self.config.header.is_some() {
+            "custom"
+        } else {
+            "default"
+        }
     }
 
     fn format_emoji(
@@ -111,10 +711,21 @@ impl<'a> ConventionalCommits<'a> {
         commit_type: &str,
         scope: Option<&str>,
         other: &str,
+        tickets: &[&str],
         breaking: bool,
     ) -> String {
         let mut emojis: Vec<String> = Vec::new();
 
+        // The spec treats types and scopes case insensitively
+        let (commit_type, scope) = match self.config.spec {
+            Some(_) => (
+                &*commit_type.to_lowercase(),
+                scope.map(str::to_lowercase),
+            ),
+            None => (commit_type, scope.map(str::to_string)),
+        };
+        let scope = scope.as_deref();
+
         // Breaking change emoji
         if breaking {
             emojis.push(self.devmoji.get("boom"));
@@ -124,17 +735,24 @@ impl<'a> ConventionalCommits<'a> {
         let type_emoji = self.lookup_pack_code(commit_type);
 
         // Scope handling
+        let policy = self.config.emoji_policy;
         if let Some(scope) = scope {
             let compound = format!("{}-{}", commit_type, scope);
+            let scope_emoji = match policy {
+                EmojiPolicy::NoScope => None,
+                _ => self.lookup_pack_code(scope),
+            };
             if let Some(e) = self.lookup_pack_code(&compound) {
                 // Use compound emoji instead of type emoji
                 push_unique(&mut emojis, e);
+            } else if let (EmojiPolicy::MostSpecific, Some(e)) = (policy, &scope_emoji) {
+                push_unique(&mut emojis, e.clone());
             } else {
                 // Use type emoji + scope emoji
                 if let Some(e) = type_emoji {
                     push_unique(&mut emojis, e);
                 }
-                if let Some(e) = self.lookup_pack_code(scope) {
+                if let Some(e) = scope_emoji {
                     push_unique(&mut emojis, e);
                 }
             }
@@ -149,69 +767,576 @@ impl<'a> ConventionalCommits<'a> {
             push_unique(&mut emojis, emoji);
         }
 
+        for code in tickets {
+            push_unique(&mut emojis, self.devmoji.get(code));
+        }
+
+        // The limit drops emoji already in the header too, so formatting twice
+        // gives the same header
+        if let Some(limit) = self.config.emoji_limit {
+            emojis.truncate(limit);
+        }
         emojis.join(" ")
     }
 
-    fn lookup_pack_code(&self, code: &str) -> Option<String> {
-        for entry in self.devmoji.pack() {
-            if entry.code == code {
-                return Some(self.devmoji.get(&entry.emoji));
+    /// Rewrite a gitmoji-style header into a conventional one, using the first
+    /// devmoji code that maps to a type (or `type-scope`) as the intention.
+    /// Expects devmojified text, so `:sparkles:` has already become `:feat:`.
+    fn convert_gitmoji_header(&self, text: &str) -> Option<String> {
+        let caps = GITMOJI_HEADER_RE.captures(text)?;
+        let codes = caps.name("codes").unwrap();
+
+        let mut intention: Option<(&str, Option<&str>)> = None;
+        let mut breaking = false;
+        let mut other: Vec<&str> = Vec::new();
+        for c in SHORTCODE_RE.captures_iter(codes.as_str()) {
+            let code = c.get(1).unwrap().as_str();
+            if code == "breaking" {
+                breaking = true;
+            } else if intention.is_none() && self.type_for_code(code).is_some() {
+                intention = self.type_for_code(code);
+            } else {
+                other.push(code);
             }
         }
+        let (commit_type, scope) = intention?;
+
+        let mut header = commit_type.to_string();
+        if let Some(scope) = scope {
+            header.push_str(&format!("({})", scope));
+        }
+        if breaking {
+            header.push('!');
+        }
+        header.push_str(": ");
+        for code in other {
+            header.push_str(&format!(":{}: ", code));
+        }
+        header.push_str(&text[codes.end()..]);
+        Some(header)
+    }
+
+    fn type_for_code<'c>(&self, code: &'c str) -> Option<(&'c str, Option<&'c str>)> {
+        if self.config.types.iter().any(|t| t == code) {
+            return Some((code, None));
+        }
+        let (commit_type, scope) = code.split_once('-')?;
+        if self.config.types.iter().any(|t| t == commit_type) {
+            return Some((commit_type, Some(scope)));
+        }
         None
     }
 
-    pub fn lint(&self, text: &str) -> Result<(), Vec<String>> {
+    fn lookup_pack_code(&self, code: &str) -> Option<String> {
+        self.devmoji.pack_emoji(code).map(str::to_string)
+    }
+
+    /// Lint `text`: `Ok` with the warnings, or `Err` with every problem when
+    /// one is an error or there are more warnings than `maxWarnings`.
+    pub fn lint(&self, text: &str) -> Result<Vec<String>, Vec<String>> {
+        self.judge(&self.problems(text))
+    }
+
+    /// `lint` with the problems already found
+    pub fn judge(&self, problems: &[Problem]) -> Result<Vec<String>, Vec<String>> {
+        let mut messages: Vec<String> = problems.iter().map(ToString::to_string).collect();
+        if problems.iter().any(|p| p.severity == Severity::Error) {
+            return Err(messages);
+        }
+        match self.config.lint.max_warnings {
+            Some(max) if problems.len() > max => {
+                messages.push(tr!(
+                    "{count} warnings, more than the {max} allowed",
+                    count = problems.len(),
+                    max = max
+                ));
+                Err(messages)
+            }
+            _ => Ok(messages),
+        }
+    }
+
+    /// The problems lint finds in `text`, except those of rules that are off
+    pub fn problems(&self, text: &str) -> Vec<Problem> {
+        self.find_problems(text)
+            .into_iter()
+            .filter_map(|(rule, message)| {
+                let severity = self.config.lint.severity(rule);
+                (severity != Severity::Off).then_some(Problem {
+                    rule,
+                    severity,
+                    message,
+                })
+            })
+            .collect()
+    }
+
+    fn find_problems(&self, text: &str) -> Vec<(Rule, String)> {
         let first_line = text.lines().next().unwrap_or("");
 
-        // Skip linting for special commits
+        // Lint the header an autosquash commit refers to
+        let (_, first_line) = split_autosquash(first_line);
+
+        // Skip linting for special commits, also with their emoji in front
+        let devmojified = self.devmoji.devmojify(first_line);
+        let special = GITMOJI_HEADER_RE
+            .find(&devmojified)
+            .map_or(devmojified.as_str(), |codes| &devmojified[codes.end()..]);
         if first_line.starts_with("Merge branch")
-            || first_line.starts_with("fixup!")
-            || first_line.starts_with("squash!")
+            || MERGE_RE.is_match(special)
+            || special.starts_with("Revert")
             || first_line.starts_with("Revert")
             || first_line.starts_with("revert")
         {
-            return Ok(());
+            debug!("lint", "skipping merge or revert commit {:?}", first_line);
+            return Vec::new();
         }
 
-        let mut errors = Vec::new();
+        let mut problems = Vec::new();
 
-        if let Some(caps) = COMMIT_RE.captures(first_line) {
-            if caps.get(0).unwrap().start() != 0 {
-                errors.push(format!(
-                    "Expecting a commit message like: type(scope): description"
+        let body = text.split_once('\n').map_or("", |(_, body)| body);
+        if self.config.spec.is_some() {
+            let mut problems = self.lint_spec(first_line, body);
+            self.lint_body(text, false, &mut problems);
+            return problems;
+        }
+
+        // Emoji in front of the header, e.g. from `start` placement
+        let devmojified = self.devmoji.devmojify(first_line);
+        let first_line = match GITMOJI_HEADER_RE.find(&devmojified) {
+            Some(codes) if self.has_leading_emoji(&devmojified) => &devmojified[codes.end()..],
+            _ => first_line,
+        };
+
+        let converted = match self.config.header {
+            Some(_) => None,
+            None => self.convert_gitmoji_header(&self.devmoji.devmojify(first_line)),
+        };
+        let first_line = match (&converted, self.config.lint.gitmoji) {
+            (Some(_), GitmojiPolicy::Reject) => {
+                problems.push((
+                    Rule::Gitmoji,
+                    tr!("Gitmoji-style headers are not allowed, use: type(scope): description"),
                 ));
-                return Err(errors);
+                return problems;
+            }
+            (Some(header), GitmojiPolicy::Accept) => header.as_str(),
+            (None, _) => first_line,
+        };
+
+        if let Some(caps) = self.header_re().captures(first_line) {
+            if caps.get(0).unwrap().start() != 0 {
+                problems.push((Rule::Header, self.expecting_error()));
+                return problems;
             }
 
             let commit_type = caps.name("type").unwrap().as_str();
             if !self.config.types.iter().any(|t| t == commit_type) {
-                errors.push(format!(
-                    "Type should be one of: {}",
-                    self.config.types.join(", ")
-                ));
+                problems.push((Rule::Type, self.unknown_type_error(commit_type)));
             }
+            self.lint_scope(commit_type, caps.name("scope").map(|s| s.as_str()), &mut problems);
+            let bang = caps.name("breaking").is_some_and(|b| b.as_str() == "!");
+            self.lint_breaking(bang, body, &mut problems);
 
             // Check if there's a description after the match
             let m = caps.get(0).unwrap();
-            let rest = &first_line[m.end()..].trim();
+            let rest = match caps.name("subject") {
+                // Grammar continues after the subject, e.g. `{subject} ({ticket})`
+                Some(s) if s.end() != m.end() => s.as_str().trim(),
+                Some(s) => first_line[s.start()..].trim(),
+                None => first_line[m.end()..].trim(),
+            };
             if rest.is_empty() {
-                errors.push("Missing description".to_string());
+                problems.push((Rule::Description, tr!("Missing description")));
+            } else if rest.ends_with('.') && !rest.ends_with("...") {
+                problems.push((Rule::Period, tr!("Description should not end with a period")));
+            }
+
+            // The default grammar allows any whitespace after the colon
+            if let (None, Some(b)) = (&self.config.header, caps.name("breaking")) {
+                if !rest.is_empty() && !first_line[b.end() + 1..].starts_with(' ') {
+                    problems.push((Rule::ColonSpace, tr!("Missing space after the colon")));
+                }
             }
         } else {
-            errors.push("Expecting a commit message like: type(scope): description".to_string());
+            problems.push((Rule::Header, self.expecting_error()));
         }
+        self.lint_shortcodes(first_line, &mut problems);
+        self.lint_body(text, true, &mut problems);
+        problems
+    }
+
+    /// Fix what lint can fix in the header of `text`: a misspelled or
+    /// uppercase type, whitespace after the colon and a trailing period.
+    /// Returns the message and a description of each fix.
+    pub fn fix(&self, text: &str) -> (String, Vec<String>) {
+        let mut fixes = Vec::new();
+        let (header, body) = text.split_once('\n').map_or((text, None), |(h, b)| (h, Some(b)));
+        let (prefix, header) = split_autosquash(header);
+        // Custom grammars may put anything around the type
+        let caps = match self.config.header {
+            Some(_) => None,
+            None => FIX_HEADER_RE.captures(header),
+        };
+        let Some(caps) = caps else {
+            return (text.to_string(), fixes);
+        };
 
-        if errors.is_empty() {
-            Ok(())
+        let commit_type = &caps["type"];
+        let fixed_type = if self.config.types.iter().any(|t| t == commit_type) {
+            commit_type.to_string()
         } else {
-            Err(errors)
+            let lower = commit_type.to_lowercase();
+            let fixed = if self.config.types.contains(&lower) {
+                Some(lower)
+            } else {
+                closest(&lower, self.config.types.iter().map(String::as_str)).map(str::to_string)
+            };
+            match fixed {
+                Some(t) => {
+                    fixes.push(tr!("type {from} -> {to}", from = commit_type, to = t));
+                    t
+                }
+                None => commit_type.to_string(),
+            }
+        };
+
+        let mut subject = caps["subject"].trim_end();
+        if subject.ends_with('.') && !subject.ends_with("...") {
+            subject = subject.trim_end_matches('.').trim_end();
+            fixes.push(tr!("trailing period"));
+        }
+        if !subject.is_empty() && &caps["space"] != " " {
+            fixes.push(tr!("space after the colon"));
+        }
+
+        let mut fixed = format!("{}{}", prefix, fixed_type);
+        if let Some(scope) = caps.name("scope") {
+            fixed.push_str(&format!("({})", scope.as_str()));
         }
+        fixed.push_str(&caps["breaking"]);
+        fixed.push(':');
+        if !subject.is_empty() {
+            fixed.push(' ');
+            fixed.push_str(subject);
+        }
+        if let Some(body) = body {
+            fixed.push('\n');
+            fixed.push_str(body);
+        }
+        if fixes.is_empty() {
+            return (text.to_string(), fixes);
+        }
+        (fixed, fixes)
+    }
+
+    fn expecting_error(&self) -> String {
+        let template = self.config.header_template.as_deref();
+        tr!(
+            "Expecting a commit message like: {template}",
+            template = template.unwrap_or("type(scope): description")
+        )
+    }
+
+    fn unknown_type_error(&self, commit_type: &str) -> String {
+        let types = self.config.types.join(", ");
+        match closest(&commit_type.to_lowercase(), self.config.types.iter().map(String::as_str)) {
+            Some(t) => tr!(
+                "Type should be one of: {types} (did you mean '{suggestion}'?)",
+                types = types,
+                suggestion = t
+            ),
+            None => tr!("Type should be one of: {types}", types = types),
+        }
+    }
+
+    fn lint_scope(
+        &self,
+        commit_type: &str,
+        scope: Option<&str>,
+        problems: &mut Vec<(Rule, String)>,
+    ) {
+        let allowed = self.config.allowed_scopes(commit_type);
+        match scope {
+            None if self.config.lint.scope_required => {
+                let message = match allowed {
+                    Some(allowed) => {
+                        tr!("Missing scope, use one of: {scopes}", scopes = allowed.join(", "))
+                    }
+                    None => tr!("Missing scope"),
+                };
+                problems.push((Rule::ScopeRequired, message));
+            }
+            Some(scope) => {
+                let Some(allowed) = allowed.filter(|a| !a.iter().any(|s| s == scope)) else {
+                    return;
+                };
+                let scopes = allowed.join(", ");
+                let message = match closest(scope, allowed.iter().map(String::as_str)) {
+                    Some(s) => tr!(
+                        "Scope should be one of: {scopes} (did you mean '{suggestion}'?)",
+                        scopes = scopes,
+                        suggestion = s
+                    ),
+                    None => tr!("Scope should be one of: {scopes}", scopes = scopes),
+                };
+                problems.push((Rule::Scope, message));
+            }
+            None => {}
+        }
+    }
+
+    /// With `"breaking": "consistent"`, a `!` in the header and a breaking
+    /// change footer need each other.
+    fn lint_breaking(&self, bang: bool, body: &str, problems: &mut Vec<(Rule, String)>) {
+        if self.config.lint.breaking != BreakingPolicy::Consistent {
+            return;
+        }
+        let message = match (bang, self.has_breaking_footer(body)) {
+            (true, false) => tr!("! in the header without a BREAKING CHANGE footer"),
+            (false, true) => tr!("BREAKING CHANGE footer without a ! in the header"),
+            _ => return,
+        };
+        problems.push((Rule::Breaking, message));
+    }
+
+    /// The body and footer rules. `layout` is whether to check the blank line
+    /// after the header and the footer format, which the spec checks itself.
+    fn lint_body(&self, text: &str, layout: bool, problems: &mut Vec<(Rule, String)>) {
+        let lines: Vec<&str> = text.lines().collect();
+        if let Some(max) = self.config.lint.body_max_line_length {
+            for (i, line) in lines.iter().enumerate().skip(1) {
+                // A long URL or path can't be wrapped
+                if line.chars().count() > max && line.trim().contains(char::is_whitespace) {
+                    let message = tr!(
+                        "Line {line} is longer than {max} characters",
+                        line = i + 1,
+                        max = max
+                    );
+                    problems.push((Rule::BodyMaxLineLength, message));
+                }
+            }
+        }
+        if layout && lines.get(1).is_some_and(|l| !l.trim().is_empty()) {
+            problems.push((
+                Rule::BodyLeadingBlank,
+                tr!("The body must begin one blank line after the description"),
+            ));
+        }
+        for line in footer_lines(text) {
+            if layout && !spec::FOOTER_RE.is_match(line) {
+                problems.push((
+                    Rule::FooterFormat,
+                    tr!("Footer '{footer}' should look like: Token: value", footer = line),
+                ));
+            }
+            let demojified = self.devmoji.demojify(line);
+            if SHORTCODE_RE.captures_iter(&demojified).any(|c| self.devmoji.is_known(&c[1])) {
+                problems.push((
+                    Rule::FooterEmoji,
+                    tr!("Footers should not have emoji: {footer}", footer = line),
+                ));
+            }
+        }
+    }
+
+    /// Unknown shortcodes that look like typos of known ones. Others may be
+    /// meant literally and pass.
+    fn lint_shortcodes(&self, header: &str, problems: &mut Vec<(Rule, String)>) {
+        for caps in SHORTCODE_RE.captures_iter(header) {
+            let code = &caps[1];
+            if self.devmoji.is_known(code) {
+                continue;
+            }
+            if let Some(known) = closest(code, self.devmoji.codes()) {
+                let message = tr!(
+                    "Unknown shortcode :{code}:, did you mean :{suggestion}:?",
+                    code = code,
+                    suggestion = known
+                );
+                problems.push((Rule::Shortcode, message));
+            }
+        }
+    }
+
+    /// Lint against the spec grammar, plus the configured types
+    fn lint_spec(&self, header: &str, body: &str) -> Vec<(Rule, String)> {
+        let message = if body.is_empty() {
+            header.to_string()
+        } else {
+            format!("{}\n{}", header, body)
+        };
+        let mut problems: Vec<(Rule, String)> = spec::lint(&message)
+            .iter()
+            .map(|violation| (Rule::Spec, violation.to_string()))
+            .collect();
+
+        if let Some(caps) = spec::HEADER_RE.captures(header) {
+            let commit_type = caps["type"].to_lowercase();
+            if caps.get(0).unwrap().start() == 0
+                && !self.config.types.iter().any(|t| t.eq_ignore_ascii_case(&commit_type))
+            {
+                problems.push((Rule::Type, self.unknown_type_error(&commit_type)));
+            }
+            if caps.get(0).unwrap().start() == 0 {
+                let scope = caps.name("scope").map(|s| s.as_str());
+                self.lint_scope(&commit_type, scope, &mut problems);
+                self.lint_breaking(&caps["breaking"] == "!", body, &mut problems);
+            }
+        }
+        self.lint_shortcodes(header, &mut problems);
+        problems
     }
 }
 
+/// The lines of the last paragraph when it is made of footers, also ones not
+/// written `Token: value`, e.g. `Reviewed by: Jane`
+fn footer_lines(text: &str) -> Vec<&str> {
+    let paragraphs: Vec<&str> = text.trim_end().split("\n\n").collect();
+    match paragraphs.last() {
+        Some(last) if paragraphs.len() > 1 && last.lines().all(|l| LOOSE_FOOTER_RE.is_match(l)) => {
+            last.lines().collect()
+        }
+        _ => Vec::new(),
+    }
+}
+
+/// The parts of a message ticket keys are picked up from: the header and the
+/// footer paragraph of a commit message, or the current line of a log.
+fn ticket_text(text: &str, at: usize, whole_message: bool) -> String {
+    if whole_message {
+        let header = text.lines().next().unwrap_or("");
+        let paragraphs: Vec<&str> = text.trim_end().split("\n\n").collect();
+        match paragraphs.last() {
+            Some(footer) if paragraphs.len() > 1 => format!("{}\n{}", header, footer),
+            _ => header.to_string(),
+        }
+    } else {
+        let start = text[..at].rfind('\n').map_or(0, |i| i + 1);
+        let end = text[at..].find('\n').map_or(text.len(), |i| at + i);
+        text[start..end].to_string()
+    }
+}
+
+/// Compile a header template like `[{ticket}] {type}({scope}){breaking}: {subject}`
+/// into a header regex. `{type}` is required, `({scope})` becomes optional,
+/// `{breaking}` matches an optional `!` and existing emoji are picked up in front
+/// of `{subject}`. Any other `{name}` matches a single word and is kept as is.
+pub fn compile_header_template(template: &str) -> Result<Regex, String> {
+    static PLACEHOLDER_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"\{([a-z_]+)\}").unwrap());
+
+    if !template.contains("{type}") {
+        return Err("header template needs a {type} placeholder".to_string());
+    }
+
+    let mut pattern = String::from("(?mi)");
+    let mut last = 0;
+    let placeholders: Vec<_> = PLACEHOLDER_RE.captures_iter(template).collect();
+    for (i, caps) in placeholders.iter().enumerate() {
+        let m = caps.get(0).unwrap();
+        let mut literal = &template[last..m.start()];
+        let mut next = m.end();
+        let fragment = match &caps[1] {
+            "type" => r"(?P<type>[a-z][a-z0-9-]*)".to_string(),
+            "scope" if literal.ends_with('(') && template[m.end()..].starts_with(')') => {
+                literal = &literal[..literal.len() - 1];
+                next += 1;
+                r"(?:\((?P<scope>[a-z0-9-]+)\))?".to_string()
+            }
+            "scope" => r"(?P<scope>[a-z0-9-]+)".to_string(),
+            "breaking" => r"(?P<breaking>!?)".to_string(),
+            "subject" => {
+                let subject = if i + 1 == placeholders.len() && template[m.end()..].is_empty() {
+                    "(?P<subject>)"
+                } else {
+                    "(?P<subject>.*?)"
+                };
+                format!(r"\s*(?:(?P<other>(?::[a-z0-9_+-]+:\s*)+)\s*)?{}", subject)
+            }
+            name => format!(r"(?P<{}>[^\s\[\](){{}}:]+)", name),
+        };
+        pattern.push_str(&template_literal(literal));
+        pattern.push_str(&fragment);
+        last = next;
+    }
+    pattern.push_str(&template_literal(&template[last..]));
+
+    Regex::new(&pattern).map_err(|e| e.to_string())
+}
+
+/// Escape literal template text, letting spaces match any amount of whitespace
+fn template_literal(text: &str) -> String {
+    text.split(' ')
+        .map(regex::escape)
+        .collect::<Vec<_>>()
+        .join(r"\s*")
+}
+
+/// Split off leading `fixup! `, `squash! ` and `amend! ` prefixes.
+pub fn split_autosquash(text: &str) -> (&str, &str) {
+    match AUTOSQUASH_RE.find(text) {
+        Some(m) => text.split_at(m.end()),
+        None => ("", text),
+    }
+}
+
+/// The codes in the value of a `Devmoji:` trailer, e.g. `feat, breaking`
+fn trailer_codes(value: &str) -> impl Iterator<Item = String> + '_ {
+    value
+        .split([',', ' '])
+        .map(|code| code.trim().trim_matches(':'))
+        .filter(|code| !code.is_empty())
+        .map(str::to_string)
+}
+
 fn push_unique(vec: &mut Vec<String>, item: String) {
     if !item.is_empty() && !vec.contains(&item) {
         vec.push(item);
     }
 }
+
+/// The candidate closest to `word`, if it is a likely typo: one edit away,
+/// or two for longer words, and no other candidate as close.
+pub fn closest<'c>(word: &str, candidates: impl IntoIterator<Item = &'c str>) -> Option<&'c str> {
+    let max = if word.chars().count() >= 6 { 2 } else { 1 };
+    let mut best: Option<(usize, &str)> = None;
+    let mut tie = false;
+    for candidate in candidates {
+        let d = edit_distance(word, candidate);
+        match best {
+            Some((b, _)) if d > b => {}
+            Some((b, c)) if d == b => tie |= c != candidate,
+            _ => {
+                best = Some((d, candidate));
+                tie = false;
+            }
+        }
+    }
+    match best {
+        Some((d, candidate)) if d <= max && !tie => Some(candidate),
+        _ => None,
+    }
+}
+
+/// Edit distance counting a swap of two neighbouring characters as one edit
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut d = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for (i, row) in d.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, cell) in d[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            d[i][j] = (d[i - 1][j] + 1).min(d[i][j - 1] + 1).min(d[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                d[i][j] = d[i][j].min(d[i - 2][j - 2] + 1);
+            }
+        }
+    }
+    d[a.len()][b.len()]
+}
@@ -1,29 +1,260 @@
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 
 use once_cell::sync::Lazy;
-use serde::Deserialize;
+use regex::Regex;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::commits::compile_header_template;
+use crate::error::Error;
+#[cfg(feature = "js")]
+use crate::cache;
+use crate::{commitizen, debug, i18n, info, js, trace, warn};
+use crate::spec::Spec;
+use crate::theme::{self, Style};
+use crate::tickets::Ticket;
 
 #[derive(Debug, Clone)]
 pub struct DevmojiEntry {
     pub code: String,
+    /// A GitHub shortcode, or with `literal` the text written verbatim
     pub emoji: String,
     pub description: String,
+    /// Whether `emoji` is written as is, e.g. a kaomoji or a Slack emoji name
+    pub literal: bool,
 }
 
-#[derive(Debug, Deserialize, Default)]
+#[derive(Debug, Deserialize, JsonSchema, Default)]
+#[serde(rename_all = "camelCase")]
 pub struct ConfigFile {
     #[serde(default)]
     pub types: Vec<String>,
     #[serde(default)]
     pub devmoji: Vec<ConfigDevmojiEntry>,
+    #[serde(default)]
+    pub lint: LintConfig,
+    #[serde(default)]
+    pub tickets: Vec<ConfigTicket>,
+    /// Enforce a Conventional Commits specification version exactly
+    pub spec: Option<Spec>,
+    /// Header grammar as a template, e.g. `[{ticket}] {type}: {subject}`
+    pub header_template: Option<String>,
+    /// Header grammar as a regex with a `type` and optional `scope`,
+    /// `breaking`, `other` and `subject` named groups
+    pub header_pattern: Option<String>,
+    /// Colors of types in colored output, e.g. `{"feat": "green bold"}`
+    #[serde(default)]
+    pub colors: HashMap<String, String>,
+    /// Where emoji go in a header
+    #[serde(default)]
+    pub emoji_placement: EmojiPlacement,
+    /// Valid scopes, for all types or per type
+    pub scopes: Option<Scopes>,
+    /// Add the `!` or `BREAKING CHANGE` footer a breaking change is missing
+    #[serde(default)]
+    pub complete_breaking: bool,
+    /// Start from no devmojis instead of the built-in ones
+    #[serde(default)]
+    pub replace_defaults: bool,
+    /// Which of the type, scope and compound emoji a header gets
+    #[serde(default)]
+    pub emoji_policy: EmojiPolicy,
+    /// The most emoji a header gets, in the order breaking, type and scope,
+    /// existing emoji, tickets
+    pub emoji_limit: Option<usize>,
+    /// How merge and revert headers are formatted
+    #[serde(default)]
+    pub special_commits: SpecialCommits,
+    /// Whether messages record their devmoji codes in a `Devmoji:` trailer
+    #[serde(default)]
+    pub emoji_trailer: EmojiTrailer,
+}
+
+/// Valid scopes: a list for all types, or lists per type where `*` holds the
+/// scopes of types without their own list
+#[derive(Debug, Deserialize, JsonSchema, Clone)]
+#[serde(untagged)]
+pub enum Scopes {
+    All(Vec<String>),
+    PerType(HashMap<String, Vec<String>>),
+}
+
+#[derive(Debug, Deserialize, JsonSchema, Clone)]
+pub struct ConfigTicket {
+    pub pattern: String,
+    pub url: Option<String>,
+    pub emoji: Option<String>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema, Default, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LintConfig {
+    /// Whether gitmoji-style headers like `:sparkles: add login` pass lint
+    #[serde(default)]
+    pub gitmoji: GitmojiPolicy,
+    /// Whether headers need a scope
+    #[serde(default)]
+    pub scope_required: bool,
+    /// Whether a `!` in the header and a breaking change footer need each other
+    #[serde(default)]
+    pub breaking: BreakingPolicy,
+    /// Severity of each rule, rules not listed have their default one
+    #[serde(default)]
+    pub rules: HashMap<Rule, Severity>,
+    /// How many warnings pass, any number when unset
+    pub max_warnings: Option<usize>,
+    /// The longest body and footer lines may be, any length when unset
+    pub body_max_line_length: Option<usize>,
+}
+
+impl LintConfig {
+    pub fn severity(&self, rule: Rule) -> Severity {
+        self.rules.get(&rule).copied().unwrap_or(rule.default_severity())
+    }
+}
+
+/// A lint rule, named in `"lint": {"rules": {"period": "warn"}}`
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "kebab-case")]
+pub enum Rule {
+    /// The header doesn't match the grammar
+    Header,
+    /// Gitmoji-style header with `"gitmoji": "reject"`
+    Gitmoji,
+    Type,
+    /// The scope isn't one of the configured scopes
+    Scope,
+    /// No scope with `scopeRequired`
+    ScopeRequired,
+    /// Missing description
+    Description,
+    /// Description ending with a period
+    Period,
+    /// No single space after the colon
+    ColonSpace,
+    /// `!` and breaking change footer disagree with `"breaking": "consistent"`
+    Breaking,
+    /// Misspelled shortcode
+    Shortcode,
+    /// Violation of the `spec` grammar
+    Spec,
+    /// Body or footer line longer than `bodyMaxLineLength`
+    BodyMaxLineLength,
+    /// No blank line between the header and the body
+    BodyLeadingBlank,
+    /// Footer not written `Token: value` or `Token #value`
+    FooterFormat,
+    /// Emoji in a footer
+    FooterEmoji,
+}
+
+impl Rule {
+    /// Errors, except the body and footer rules most repositories don't
+    /// follow, which are off until they are given a severity
+    pub fn default_severity(self) -> Severity {
+        match self {
+            Rule::BodyLeadingBlank | Rule::FooterFormat | Rule::FooterEmoji => Severity::Off,
+            _ => Severity::Error,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, JsonSchema, Default, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    #[default]
+    Error,
+    /// Printed, but only fails with more than `maxWarnings`
+    Warn,
+    Off,
+}
+
+#[derive(Debug, Deserialize, JsonSchema, Default, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum BreakingPolicy {
+    /// Either one marks a breaking change
+    #[default]
+    Any,
+    /// Both are needed
+    Consistent,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Deserialize, JsonSchema, Default, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum GitmojiPolicy {
+    #[default]
+    Accept,
+    Reject,
+}
+
+/// Which emoji a header with a scope gets. A `type-scope` compound devmoji
+/// always replaces the type emoji.
+#[derive(Debug, Deserialize, JsonSchema, Default, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum EmojiPolicy {
+    /// The type or compound emoji, and the scope emoji
+    #[default]
+    All,
+    /// The type or compound emoji, never the scope emoji
+    NoScope,
+    /// Only the most specific one: compound, else scope, else type
+    MostSpecific,
+}
+
+/// How merge and revert headers, which aren't conventional, are formatted
+#[derive(Debug, Deserialize, JsonSchema, Default, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum SpecialCommits {
+    /// Their emoji in front, and the header a revert quotes formatted
+    #[default]
+    Full,
+    /// Only their emoji in front
+    Emoji,
+    /// Left as they are
+    Off,
+}
+
+/// Where formatting puts the emoji of a header
+#[derive(Debug, Deserialize, JsonSchema, Default, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum EmojiPlacement {
+    /// After the colon, in front of the subject: `feat: ✨ add login`
+    #[default]
+    Subject,
+    /// In front of the type, gitmoji style: `✨ feat: add login`
+    Start,
+    /// At the end of the subject: `feat: add login ✨`
+    End,
+}
+
+/// Whether a commit message gets a `Devmoji: feat, breaking` trailer with the
+/// codes of its header emoji, which `--log` reads back
+#[derive(Debug, Deserialize, JsonSchema, Default, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum EmojiTrailer {
+    /// Emoji only in the header
+    #[default]
+    Off,
+    /// Emoji in the header and the trailer
+    Also,
+    /// Emoji only in the trailer, the header is left plain
+    Only,
+}
+
+#[derive(Debug, Deserialize, JsonSchema, Clone)]
 pub struct ConfigDevmojiEntry {
     pub code: String,
     pub emoji: Option<String>,
     pub gitmoji: Option<String>,
     pub description: Option<String>,
+    /// Use `emoji` verbatim instead of looking it up as a shortcode
+    #[serde(default)]
+    pub literal: bool,
+    /// Translated descriptions by language code, e.g. `{"cs": "nová funkce"}`
+    #[serde(default)]
+    pub descriptions: HashMap<String, String>,
 }
 
 pub static DEFAULT_TYPES: Lazy<Vec<&'static str>> = Lazy::new(|| {
@@ -33,45 +264,106 @@ pub static DEFAULT_TYPES: Lazy<Vec<&'static str>> = Lazy::new(|| {
 });
 
 pub static DEFAULT_DEVMOJIS: Lazy<Vec<DevmojiEntry>> = Lazy::new(|| {
-    vec![
-        DevmojiEntry { code: "feat".into(), emoji: "sparkles".into(), description: "a new feature".into() },
-        DevmojiEntry { code: "fix".into(), emoji: "bug".into(), description: "a bug fix".into() },
-        DevmojiEntry { code: "docs".into(), emoji: "books".into(), description: "documentation only changes".into() },
-        DevmojiEntry { code: "style".into(), emoji: "art".into(), description: "changes that do not affect the meaning of the code".into() },
-        DevmojiEntry { code: "refactor".into(), emoji: "recycle".into(), description: "a code change that neither fixes a bug nor adds a feature".into() },
-        DevmojiEntry { code: "perf".into(), emoji: "zap".into(), description: "a code change that improves performance".into() },
-        DevmojiEntry { code: "test".into(), emoji: "rotating_light".into(), description: "adding missing or correcting existing tests".into() },
-        DevmojiEntry { code: "chore".into(), emoji: "wrench".into(), description: "changes to the build process or auxiliary tools".into() },
-        DevmojiEntry { code: "chore-release".into(), emoji: "rocket".into(), description: "code deployment or publishing to external repositories".into() },
-        DevmojiEntry { code: "chore-deps".into(), emoji: "link".into(), description: "add or delete dependencies".into() },
-        DevmojiEntry { code: "build".into(), emoji: "package".into(), description: "changes related to build processes".into() },
-        DevmojiEntry { code: "ci".into(), emoji: "construction_worker".into(), description: "updates to the continuous integration system".into() },
-        DevmojiEntry { code: "release".into(), emoji: "rocket".into(), description: "code deployment or publishing to external repositories".into() },
-        DevmojiEntry { code: "security".into(), emoji: "lock".into(), description: "fixing security issues".into() },
-        DevmojiEntry { code: "i18n".into(), emoji: "globe_with_meridians".into(), description: "internationalization and localization".into() },
-        DevmojiEntry { code: "breaking".into(), emoji: "boom".into(), description: "introducing breaking changes".into() },
-        DevmojiEntry { code: "config".into(), emoji: "gear".into(), description: "changing configuration files".into() },
-        DevmojiEntry { code: "add".into(), emoji: "heavy_plus_sign".into(), description: "add something".into() },
-        DevmojiEntry { code: "remove".into(), emoji: "heavy_minus_sign".into(), description: "remove something".into() },
-    ]
+    const DEVMOJIS: &[(&str, &str, &str)] = include!("../data/devmojis.rs");
+    DEVMOJIS
+        .iter()
+        .map(|&(code, emoji, description)| DevmojiEntry {
+            code: code.into(),
+            emoji: emoji.into(),
+            description: description.into(),
+            literal: false,
+        })
+        .collect()
 });
 
 pub struct Config {
     pub types: Vec<String>,
     pub devmojis: Vec<DevmojiEntry>,
+    pub lint: LintConfig,
+    /// Custom header grammar, `None` for the conventional commit default
+    pub header: Option<Regex>,
+    pub header_template: Option<String>,
+    pub tickets: Vec<Ticket>,
+    pub spec: Option<Spec>,
+    /// Styles of types in colored output, by lowercase type
+    pub colors: HashMap<String, Style>,
+    pub emoji_placement: EmojiPlacement,
+    pub scopes: Option<Scopes>,
+    pub complete_breaking: bool,
+    pub emoji_policy: EmojiPolicy,
+    pub emoji_limit: Option<usize>,
+    pub special_commits: SpecialCommits,
+    pub emoji_trailer: EmojiTrailer,
 }
 
 impl Config {
-    pub fn load(config_path: Option<&str>) -> Self {
-        let file_config = config_path
-            .map(|p| PathBuf::from(p))
-            .or_else(|| find_config_file())
-            .and_then(|p| load_config_file(&p));
+    /// Load the global config with the project config from `config_path` or
+    /// the nearest config file merged on top, see `config_files`. JS and TS
+    /// configs are evaluated with Node, cached unless `use_cache` is false.
+    /// Other configs are left out when invalid, the project config is an error.
+    pub fn load(config_path: Option<&str>, use_cache: bool) -> Result<Self, Error> {
+        // A config passed explicitly has to exist, the nearest one may not
+        if let Some(path) = config_path {
+            if !Path::new(path).exists() {
+                return Err(Error::Config(format!("Could not find the config {}", path)));
+            }
+        }
+        let paths = config_files(config_path);
+        if paths.is_empty() {
+            info!("config", "no config file found, using defaults");
+        }
+        let project = project_config(config_path);
+        let file_config = load_config_files(&paths, project.as_deref(), use_cache)?;
 
         let mut types: Vec<String> = DEFAULT_TYPES.iter().map(|s| s.to_string()).collect();
-        let mut devmojis = DEFAULT_DEVMOJIS.clone();
+        let mut devmojis: Vec<DevmojiEntry> = DEFAULT_DEVMOJIS
+            .iter()
+            .map(|d| DevmojiEntry {
+                description: i18n::translate(&d.description).to_string(),
+                ..d.clone()
+            })
+            .collect();
+        let mut lint = LintConfig::default();
+        let mut header = None;
+        let mut header_template = None;
+        let mut tickets = Vec::new();
+        let mut spec = None;
+        let mut colors = theme::default_type_styles();
+        let mut emoji_placement = EmojiPlacement::default();
+        let mut scopes = None;
+        let mut complete_breaking = false;
+        let mut emoji_policy = EmojiPolicy::default();
+        let mut emoji_limit = None;
+        let mut special_commits = SpecialCommits::default();
+        let mut emoji_trailer = EmojiTrailer::default();
 
         if let Some(cfg) = file_config {
+            header = load_header_grammar(&cfg);
+            lint = cfg.lint;
+            header_template = cfg.header_template;
+            spec = cfg.spec;
+            emoji_placement = cfg.emoji_placement;
+            scopes = cfg.scopes;
+            complete_breaking = cfg.complete_breaking;
+            emoji_policy = cfg.emoji_policy;
+            emoji_limit = cfg.emoji_limit;
+            special_commits = cfg.special_commits;
+            emoji_trailer = cfg.emoji_trailer;
+            for (commit_type, spec) in &cfg.colors {
+                match Style::parse(spec) {
+                    Ok(style) => {
+                        colors.insert(commit_type.to_lowercase(), style);
+                    }
+                    Err(e) => warn!("config", "ignoring invalid color: {}", e),
+                }
+            }
+            for ticket in &cfg.tickets {
+                match Ticket::compile(ticket) {
+                    Ok(t) => tickets.push(t),
+                    Err(e) => warn!("config", "ignoring invalid ticket pattern: {}", e),
+                }
+            }
+
             // Merge types
             for t in &cfg.types {
                 if !types.contains(t) {
@@ -79,6 +371,11 @@ impl Config {
                 }
             }
 
+            if cfg.replace_defaults {
+                debug!("config", "replacing the built-in devmojis");
+                devmojis.clear();
+            }
+
             // Merge devmoji entries
             for entry in &cfg.devmoji {
                 let emoji = resolve_config_emoji(entry);
@@ -87,6 +384,7 @@ impl Config {
                 if let Some(existing) = devmojis.iter_mut().find(|d| d.code == entry.code) {
                     if let Some(e) = &emoji {
                         existing.emoji = e.clone();
+                        existing.literal = entry.literal && entry.emoji.is_some();
                     }
                     if let Some(d) = &description {
                         existing.description = d.clone();
@@ -96,12 +394,90 @@ impl Config {
                         code: entry.code.clone(),
                         emoji: emoji.unwrap_or_default(),
                         description: description.unwrap_or_default(),
+                        literal: entry.literal && entry.emoji.is_some(),
                     });
                 }
             }
         }
 
-        Config { types, devmojis }
+        Ok(Config {
+            types,
+            devmojis,
+            lint,
+            header,
+            header_template,
+            tickets,
+            spec,
+            colors,
+            emoji_placement,
+            scopes,
+            complete_breaking,
+            emoji_policy,
+            emoji_limit,
+            special_commits,
+            emoji_trailer,
+        })
+    }
+
+    /// The scopes allowed for a type, `None` when any scope is
+    pub fn allowed_scopes(&self, commit_type: &str) -> Option<&[String]> {
+        match self.scopes.as_ref()? {
+            Scopes::All(scopes) => Some(scopes),
+            Scopes::PerType(scopes) => scopes
+                .get(commit_type)
+                .or_else(|| scopes.get("*"))
+                .map(Vec::as_slice),
+        }
+    }
+
+    /// Replace the types with `types`, except the ones starting with `+`,
+    /// which are added to the configured types
+    pub fn override_types(&mut self, types: &[String]) {
+        if types.iter().any(|t| !t.starts_with('+')) {
+            self.types.clear();
+        }
+        for t in types {
+            let t = t.strip_prefix('+').unwrap_or(t).trim();
+            if !t.is_empty() && !self.types.iter().any(|existing| existing == t) {
+                self.types.push(t.to_string());
+            }
+        }
+        debug!("config", "types for this run: {}", self.types.join(", "));
+    }
+
+    /// Style of a type in colored output
+    pub fn type_style(&self, commit_type: &str) -> Style {
+        self.colors
+            .get(&commit_type.to_lowercase())
+            .cloned()
+            .unwrap_or_else(theme::default_style)
+    }
+}
+
+fn load_header_grammar(cfg: &ConfigFile) -> Option<Regex> {
+    let compiled = if let Some(pattern) = &cfg.header_pattern {
+        Regex::new(pattern).map_err(|e| e.to_string()).and_then(|re| {
+            if re.capture_names().any(|n| n == Some("type")) {
+                Ok(re)
+            } else {
+                Err("header pattern needs a named `type` group".to_string())
+            }
+        })
+    } else if let Some(template) = &cfg.header_template {
+        compile_header_template(template)
+    } else {
+        return None;
+    };
+
+    match compiled {
+        Ok(re) => {
+            debug!("config", "custom header grammar: {}", re.as_str());
+            Some(re)
+        }
+        Err(e) => {
+            warn!("config", "ignoring invalid header grammar: {}", e);
+            None
+        }
     }
 }
 
@@ -110,78 +486,325 @@ fn resolve_config_emoji(entry: &ConfigDevmojiEntry) -> Option<String> {
         return Some(emoji.clone());
     }
     if let Some(gitmoji_code) = &entry.gitmoji {
-        use crate::gitmoji::GITMOJI_MAP;
-        use crate::gitmoji::GITMOJIS;
-        if let Some(&idx) = GITMOJI_MAP.get(gitmoji_code.as_str()) {
-            return Some(GITMOJIS[idx].code.to_string());
+        if let Some(gitmoji) = crate::gitmoji::find(gitmoji_code) {
+            return Some(gitmoji.code.to_string());
         }
     }
     None
 }
 
 fn resolve_config_description(entry: &ConfigDevmojiEntry) -> Option<String> {
+    if let Some(desc) = entry.descriptions.get(i18n::current().code()) {
+        return Some(desc.clone());
+    }
     if let Some(desc) = &entry.description {
         return Some(desc.clone());
     }
     if let Some(gitmoji_code) = &entry.gitmoji {
-        use crate::gitmoji::GITMOJI_MAP;
-        use crate::gitmoji::GITMOJIS;
-        if let Some(&idx) = GITMOJI_MAP.get(gitmoji_code.as_str()) {
-            return Some(GITMOJIS[idx].description.to_string());
+        if let Some(gitmoji) = crate::gitmoji::find(gitmoji_code) {
+            return Some(gitmoji.description.to_string());
         }
     }
     None
 }
 
-fn find_config_file() -> Option<PathBuf> {
-    let cwd = std::env::current_dir().ok()?;
+/// The config files to merge, lowest precedence first: the global config in
+/// `~/.config/devmoji` or the home directory, the nearest commitizen config,
+/// then `config_path` or the nearest config in the current directory and its
+/// parents.
+pub fn config_files(config_path: Option<&str>) -> Vec<PathBuf> {
+    let home = dirs_home();
+    let global = user_config_dir()
+        .and_then(|dir| config_in(&dir))
+        .or_else(|| home.as_deref().and_then(config_in));
+    let project = project_config(config_path);
+    let commitizen = nearest(commitizen::config_in);
+
+    let mut paths: Vec<PathBuf> = Vec::new();
+    for path in global.into_iter().chain(commitizen).chain(project) {
+        if !paths.iter().any(|p| same_file(p, &path)) {
+            paths.push(path);
+        }
+    }
+    paths
+}
 
-    // Check current directory
-    let candidate = cwd.join("devmoji.config.json");
-    if candidate.exists() {
-        return Some(candidate);
+/// `config_path`, or the nearest config in the current directory and its
+/// parents below the home directory
+fn project_config(config_path: Option<&str>) -> Option<PathBuf> {
+    match config_path {
+        Some(path) => Some(PathBuf::from(path)),
+        None => nearest(config_in),
     }
+}
 
-    // Walk up looking for package.json or .git
-    let mut dir = cwd.as_path();
-    loop {
-        let candidate = dir.join("devmoji.config.json");
-        if candidate.exists() {
-            return Some(candidate);
-        }
+/// The first file `find` finds in the current directory and its parents,
+/// leaving out the home directory, which holds the global config
+fn nearest(find: fn(&Path) -> Option<PathBuf>) -> Option<PathBuf> {
+    let home = dirs_home();
+    std::env::current_dir().ok().and_then(|cwd| {
+        cwd.ancestors()
+            .filter(|dir| Some(*dir) != home.as_deref())
+            .find_map(find)
+    })
+}
+
+fn same_file(a: &Path, b: &Path) -> bool {
+    a.canonicalize().ok().is_some_and(|a| b.canonicalize().ok() == Some(a))
+}
+
+/// The config file in `dir`, JSON first, then JS and TS.
+fn config_in(dir: &Path) -> Option<PathBuf> {
+    let json = std::iter::once("json");
+    json.chain(js::CONFIG_EXTENSIONS.iter().copied())
+        .map(|ext| dir.join(format!("devmoji.config.{}", ext)))
+        .find(|candidate| {
+            trace!("config", "looking for {}", candidate.display());
+            candidate.exists()
+        })
+}
+
+/// `$XDG_CONFIG_HOME/devmoji`, or `~/.config/devmoji`
+pub fn user_config_dir() -> Option<PathBuf> {
+    std::env::var_os("XDG_CONFIG_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| dirs_home().map(|home| home.join(".config")))
+        .map(|dir| dir.join("devmoji"))
+}
+
+pub(crate) fn dirs_home() -> Option<PathBuf> {
+    std::env::var("HOME")
+        .ok()
+        .map(PathBuf::from)
+}
+
+/// JSON Schema of a config file, for editors to complete and validate
+/// configs pointing at it with `"$schema"`
+pub fn schema() -> Value {
+    let mut generator = schemars::generate::SchemaSettings::default().into_generator();
+    let rule = generator.subschema_for::<Rule>().to_value();
+    let mut schema = generator.into_root_schema_for::<ConfigFile>().to_value();
+    schema["title"] = "devmoji config".into();
+    // Maps are only known to have string keys, the rules can be named
+    schema["$defs"]["LintConfig"]["properties"]["rules"]["propertyNames"] = rule;
+    if let Some(properties) = schema["properties"].as_object_mut() {
+        properties.insert(
+            "$schema".to_string(),
+            serde_json::json!({ "type": "string" }),
+        );
+        // Merged in by `load_config_value` before the rest is deserialized
+        properties.insert(
+            "extends".to_string(),
+            serde_json::json!({
+                "description": "Configs merged underneath this one, by path or npm package",
+                "anyOf": [
+                    { "type": "string" },
+                    { "type": "array", "items": { "type": "string" } }
+                ]
+            }),
+        );
+    }
+    schema
+}
 
-        // Check if this dir has package.json or .git
-        if dir.join("package.json").exists() || dir.join(".git").exists() {
-            let candidate = dir.join("devmoji.config.json");
-            if candidate.exists() {
-                return Some(candidate);
+/// Load a config file without its `extends` and tell what is wrong with it,
+/// instead of ignoring it like `load` does.
+pub fn check_file(path: &Path) -> Result<(), String> {
+    let value = if commitizen::is_commitizen_config(path) {
+        commitizen::load(path)?
+    } else if js::is_js_config(path) {
+        serde_json::from_str(&js::evaluate(path)?).map_err(|e| e.to_string())?
+    } else {
+        let contents = std::fs::read_to_string(path).map_err(|e| e.to_string())?;
+        serde_json::from_str(&contents).map_err(|e| e.to_string())?
+    };
+    serde_json::from_value::<ConfigFile>(value).map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+/// Merge config files, later ones on top. Invalid files are left out, except
+/// the `project` config, which is an error.
+fn load_config_files(
+    paths: &[PathBuf],
+    project: Option<&Path>,
+    use_cache: bool,
+) -> Result<Option<ConfigFile>, Error> {
+    let mut merged = None;
+    for path in paths {
+        let is_project = project.is_some_and(|project| same_file(project, path));
+        let checked = load_config_value(path, use_cache, &mut Vec::new()).and_then(|value| {
+            match serde_json::from_value::<ConfigFile>(value.clone()) {
+                Ok(_) => Ok(value),
+                Err(e) => Err(e.to_string()),
+            }
+        });
+        let value = match checked {
+            Ok(value) => value,
+            Err(e) if is_project => {
+                return Err(Error::Config(format!("Invalid config {}: {}", path.display(), e)));
             }
+            Err(e) => {
+                info!("config", "ignoring {}: {}", path.display(), e);
+                continue;
+            }
+        };
+        info!("config", "using {}", path.display());
+        let merged = merged.get_or_insert_with(|| Value::Object(Default::default()));
+        deep_merge(merged, value);
+    }
+    Ok(merged.and_then(|merged| serde_json::from_value(merged).ok()))
+}
+
+/// Read a config as JSON, with the configs it `extends` merged underneath.
+/// `chain` holds the configs currently being extended, to detect cycles.
+fn load_config_value(
+    path: &Path,
+    use_cache: bool,
+    chain: &mut Vec<PathBuf>,
+) -> Result<Value, String> {
+    let parsed = if commitizen::is_commitizen_config(path) {
+        commitizen::load(path)
+    } else {
+        let contents = if js::is_js_config(path) {
+            load_js_config(path, use_cache)
+        } else {
+            std::fs::read_to_string(path).map_err(|e| e.to_string())
+        };
+        contents.and_then(|c| serde_json::from_str(&c).map_err(|e| e.to_string()))
+    };
+    let mut value: Value = parsed?;
+
+    let bases = match value.as_object_mut().and_then(|o| o.remove("extends")) {
+        None => Vec::new(),
+        Some(Value::String(base)) => vec![base],
+        Some(Value::Array(bases)) => bases
+            .into_iter()
+            .filter_map(|b| match b {
+                Value::String(base) => Some(base),
+                other => {
+                    warn!("config", "ignoring invalid extends: expected a path, got {}", other);
+                    None
+                }
+            })
+            .collect(),
+        Some(other) => {
+            warn!("config", "ignoring invalid extends: expected a path or a list, got {}", other);
+            Vec::new()
         }
+    };
 
-        match dir.parent() {
-            Some(parent) => dir = parent,
-            None => break,
+    chain.push(path.canonicalize().unwrap_or_else(|_| path.to_path_buf()));
+    let mut merged = Value::Object(Default::default());
+    for base in bases {
+        let base_path = match resolve_extends(path, &base) {
+            Ok(p) => p,
+            Err(e) => {
+                warn!("config", "ignoring invalid extends: {}", e);
+                continue;
+            }
+        };
+        let canonical = base_path.canonicalize().unwrap_or_else(|_| base_path.clone());
+        if let Some(start) = chain.iter().position(|p| *p == canonical) {
+            let cycle: Vec<String> = chain[start..]
+                .iter()
+                .chain(std::iter::once(&canonical))
+                .map(|p| p.display().to_string())
+                .collect();
+            warn!("config", "ignoring invalid extends: cycle {}", cycle.join(" -> "));
+            continue;
+        }
+        info!("config", "{} extends {}", path.display(), base_path.display());
+        match load_config_value(&base_path, use_cache, chain) {
+            Ok(base_value) => deep_merge(&mut merged, base_value),
+            Err(e) => info!("config", "could not load {}: {}", base_path.display(), e),
         }
     }
+    chain.pop();
 
-    // Check home directory
-    if let Some(home) = dirs_home() {
-        let candidate = home.join("devmoji.config.json");
-        if candidate.exists() {
-            return Some(candidate);
-        }
+    deep_merge(&mut merged, value);
+    Ok(merged)
+}
+
+/// Resolve an `extends` entry: a path relative to the extending config, an
+/// absolute path, or an npm package (or a file in one) from node_modules.
+fn resolve_extends(from: &Path, base: &str) -> Result<PathBuf, String> {
+    let dir = from.parent().unwrap_or(Path::new("."));
+    let target = if base.starts_with('.') || Path::new(base).is_absolute() {
+        dir.join(base)
+    } else {
+        dir.ancestors()
+            .map(|d| d.join("node_modules").join(base))
+            .find(|p| p.exists())
+            .ok_or_else(|| format!("could not find package {} in node_modules", base))?
+    };
+
+    if target.is_dir() {
+        package_main(&target)
+            .or_else(|| config_in(&target))
+            .ok_or_else(|| format!("no devmoji config in {}", target.display()))
+    } else if target.exists() {
+        Ok(target)
+    } else {
+        Err(format!("could not find {}", target.display()))
     }
+}
 
-    None
+/// The `main` file of the package in `dir`, if it has one.
+fn package_main(dir: &Path) -> Option<PathBuf> {
+    let manifest = std::fs::read_to_string(dir.join("package.json")).ok()?;
+    let manifest: Value = serde_json::from_str(&manifest).ok()?;
+    let main = dir.join(manifest.get("main")?.as_str()?);
+    main.is_file().then_some(main)
 }
 
-fn dirs_home() -> Option<PathBuf> {
-    std::env::var("HOME")
-        .ok()
-        .map(PathBuf::from)
+/// Merge `over` into `base`: objects key by key, lists are concatenated
+/// (later devmoji entries override earlier ones), anything else is replaced.
+fn deep_merge(base: &mut Value, over: Value) {
+    match (base, over) {
+        (Value::Object(base), Value::Object(over)) => {
+            for (key, value) in over {
+                match base.get_mut(&key) {
+                    Some(existing) => deep_merge(existing, value),
+                    None => {
+                        base.insert(key, value);
+                    }
+                }
+            }
+        }
+        (Value::Array(base), Value::Array(over)) => base.extend(over),
+        (base, over) => *base = over,
+    }
+}
+
+/// Evaluate a JS or TS config to JSON, reusing the cached result while the
+/// file is unchanged.
+#[cfg(feature = "js")]
+fn load_js_config(path: &Path, use_cache: bool) -> Result<String, String> {
+    let source = std::fs::read_to_string(path).map_err(|e| e.to_string())?;
+    let cached = use_cache
+        .then(|| cache::find_cache_dir(path))
+        .flatten()
+        .map(|dir| (dir, cache::key(path, &source)));
+
+    if let Some((dir, key)) = &cached {
+        if let Some(json) = cache::get(dir, key) {
+            info!("cache", "hit for {} in {}", path.display(), dir.display());
+            return Ok(json);
+        }
+        info!("cache", "miss for {} in {}", path.display(), dir.display());
+    }
+
+    let json = js::evaluate(path)?;
+    if let Some((dir, key)) = &cached {
+        if let Err(e) = cache::put(dir, key, &json) {
+            debug!("cache", "could not write to {}: {}", dir.display(), e);
+        }
+    }
+    Ok(json)
 }
 
-fn load_config_file(path: &Path) -> Option<ConfigFile> {
-    let contents = std::fs::read_to_string(path).ok()?;
-    serde_json::from_str(&contents).ok()
+#[cfg(not(feature = "js"))]
+fn load_js_config(path: &Path, _use_cache: bool) -> Result<String, String> {
+    js::evaluate(path)
 }
@@ -1,11 +1,16 @@
+use std::borrow::Cow;
 use std::collections::HashMap;
 
+use aho_corasick::{AhoCorasick, AhoCorasickKind, Input, MatchKind};
 use once_cell::sync::Lazy;
 use regex::Regex;
 
 use crate::config::{Config, DevmojiEntry};
-use crate::github_emoji::GITHUB_EMOJIS;
-use crate::gitmoji::GITMOJI_MAP;
+use crate::github_emoji::{EMOJI_CODES, GITHUB_EMOJIS};
+use crate::gitmoji::{self, GITMOJIS};
+
+/// Scanner pattern of the `:` that may start a shortcode
+const COLON: usize = 0;
 
 static SHORTCODE_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r":([a-zA-Z0-9_\-+]+):").unwrap());
 static SHORTCODE_SPACE_RE: Lazy<Regex> =
@@ -16,38 +21,98 @@ pub struct Devmoji {
     pack: Vec<DevmojiEntry>,
     /// Maps shortcode (without colons) -> unicode emoji
     pack_map: HashMap<String, String>,
-    /// Reverse map: unicode emoji -> shortcode
-    emoji_to_code: HashMap<String, String>,
+    /// Maps devmoji code -> text of a `literal` entry, written verbatim
+    literals: HashMap<String, String>,
+    /// Maps devmoji code -> the emoji it is written as, the first entry wins
+    pack_emoji: HashMap<String, String>,
+    /// Maps unicode emoji -> the first devmoji code written as it
+    emoji_to_devmoji: HashMap<String, String>,
+    /// Maps an entry's emoji shortcode -> the first devmoji code using it
+    shortcode_to_devmoji: HashMap<String, String>,
+    /// Every known emoji sequence, plus `:` where shortcodes may start
+    scanner: AhoCorasick,
+    /// Per scanner pattern: the shortcode an emoji sequence stands for, empty
+    /// for a stray variation selector
+    codes: Vec<Cow<'static, str>>,
 }
 
 impl Devmoji {
     pub fn new(config: &Config) -> Self {
         let pack = config.devmojis.clone();
         let mut pack_map = HashMap::new();
+        let mut literals = HashMap::new();
+        let mut emoji_to_devmoji = HashMap::new();
+        let mut shortcode_to_devmoji = HashMap::new();
         for entry in &pack {
             pack_map.insert(entry.code.clone(), entry.emoji.clone());
+            if entry.literal && !entry.emoji.is_empty() {
+                literals.insert(entry.code.clone(), entry.emoji.clone());
+            }
+            let emoji = GITHUB_EMOJIS.get(entry.emoji.as_str()).copied().unwrap_or(entry.emoji.as_str());
+            emoji_to_devmoji
+                .entry(emoji.to_string())
+                .or_insert_with(|| entry.code.clone());
+            shortcode_to_devmoji
+                .entry(entry.emoji.clone())
+                .or_insert_with(|| entry.code.clone());
         }
 
-        // Build reverse map from unicode emoji to shortcode (github emojis)
-        let mut emoji_to_code: HashMap<String, String> = HashMap::new();
-        for (&code, &emoji) in GITHUB_EMOJIS.iter() {
-            emoji_to_code
-                .entry(emoji.to_string())
-                .or_insert_with(|| code.to_string());
-            // Also store without variation selector
-            let stripped = emoji.replace('\u{fe0f}', "");
-            if stripped != emoji {
-                emoji_to_code
-                    .entry(stripped)
-                    .or_insert_with(|| code.to_string());
+        // Every emoji with its canonical shortcode: the generated map, then
+        // gitmojis it doesn't have, e.g. ones from `update-gitmojis`
+        let mut patterns: Vec<Cow<'static, str>> = vec![":".into(), "\u{fe0f}".into()];
+        let mut codes: Vec<Cow<'static, str>> = vec!["".into(), "".into()];
+        for (&emoji, &code) in EMOJI_CODES.entries() {
+            patterns.push(emoji.into());
+            codes.push(code.into());
+        }
+        let known = patterns.len();
+        let gitmojis = GITMOJIS.iter().filter(|g| !GITHUB_EMOJIS.contains_key(g.code));
+        for gitmoji in gitmojis {
+            // Also without variation selector
+            for emoji in [gitmoji.emoji.to_string(), gitmoji.emoji.replace('\u{fe0f}', "")] {
+                if !EMOJI_CODES.contains_key(emoji.as_str())
+                    && !patterns[known..].iter().any(|p| *p == emoji)
+                {
+                    patterns.push(emoji.into());
+                    codes.push(gitmoji.code.into());
+                }
             }
         }
+        // Literals demojify to their devmoji code, unless they are a real emoji
+        for (code, literal) in &literals {
+            if !EMOJI_CODES.contains_key(literal.as_str())
+                && !patterns[known..].iter().any(|p| p == literal)
+            {
+                patterns.push(literal.clone().into());
+                codes.push(code.clone().into());
+            }
+        }
+
+        // Leftmost-longest keeps ZWJ sequences, flags and keycaps together.
+        // The noncontiguous NFA is the cheapest to build, which dominates startup.
+        let scanner = AhoCorasick::builder()
+            .match_kind(MatchKind::LeftmostLongest)
+            .kind(Some(AhoCorasickKind::NoncontiguousNFA))
+            .build(patterns.iter().map(|p| p.as_bytes()))
+            .expect("emoji patterns are valid");
 
-        Devmoji {
+        let mut devmoji = Devmoji {
             pack,
             pack_map,
-            emoji_to_code,
+            literals,
+            pack_emoji: HashMap::new(),
+            emoji_to_devmoji,
+            shortcode_to_devmoji,
+            scanner,
+            codes,
+        };
+        for entry in &devmoji.pack {
+            if !devmoji.pack_emoji.contains_key(&entry.code) {
+                let emoji = devmoji.entry_emoji(entry);
+                devmoji.pack_emoji.insert(entry.code.clone(), emoji);
+            }
         }
+        devmoji
     }
 
     pub fn pack(&self) -> &[DevmojiEntry] {
@@ -57,65 +122,118 @@ impl Devmoji {
     /// Resolve a code to its unicode emoji.
     /// First checks pack (devmoji aliases), then github emojis.
     pub fn get(&self, code: &str) -> String {
+        // Not found - return wrapped
+        self.try_get(code).unwrap_or_else(|| format!(":{}:", code))
+    }
+
+    /// Resolve a code to its unicode emoji, if it is known.
+    pub fn try_get(&self, code: &str) -> Option<String> {
+        if let Some(literal) = self.literals.get(code) {
+            return Some(literal.clone());
+        }
         // Check if it's a devmoji pack code -> resolve to its emoji shortcode, then recurse
         if let Some(emoji_code) = self.pack_map.get(code) {
             if emoji_code != code {
-                return self.get(emoji_code);
+                return self.try_get(emoji_code);
             }
         }
 
-        // Check github emoji registry
-        if let Some(&emoji) = GITHUB_EMOJIS.get(code) {
-            return emoji.to_string();
+        // Check github emoji registry, then gitmojis it does not have
+        GITHUB_EMOJIS
+            .get(code)
+            .copied()
+            .or_else(|| gitmoji::index(code).map(|i| GITMOJIS[i].emoji))
+            .map(|emoji| emoji.to_string())
+    }
+
+    /// The emoji a pack entry is written as
+    pub fn entry_emoji(&self, entry: &DevmojiEntry) -> String {
+        match self.literals.get(&entry.code) {
+            Some(literal) => literal.clone(),
+            None => self.get(&entry.emoji),
         }
+    }
 
-        // Not found - return wrapped
-        format!(":{}:", code)
+    /// The emoji the devmoji `code` is written as, `None` when the pack doesn't
+    /// have it
+    pub fn pack_emoji(&self, code: &str) -> Option<&str> {
+        self.pack_emoji.get(code).map(String::as_str)
     }
 
-    /// Convert unicode emoji to shortcodes
+    /// Whether `code` is a devmoji written as literal text rather than an emoji
+    pub fn is_literal(&self, code: &str) -> bool {
+        self.literals.contains_key(code)
+    }
+
+    /// Whether `code` is a devmoji, GitHub or gitmoji shortcode
+    pub fn is_known(&self, code: &str) -> bool {
+        self.pack_map.contains_key(code) || self.try_get(code).is_some()
+    }
+
+    /// Every known shortcode: devmoji codes, then GitHub's and gitmoji's
+    pub fn codes(&self) -> impl Iterator<Item = &str> {
+        self.pack
+            .iter()
+            .map(|e| e.code.as_str())
+            .chain(GITHUB_EMOJIS.keys().copied())
+            .chain(GITMOJIS.iter().map(|g| g.code))
+    }
+
+    /// Convert unicode emoji to shortcodes.
     pub fn demojify(&self, text: &str) -> String {
-        let mut result = String::with_capacity(text.len());
+        self.scan(text, |code| format!(":{}:", code), |_| None)
+    }
 
-        for ch in text.chars() {
-            let s = ch.to_string();
-            // Try with variation selector
-            let with_vs = format!("{}\u{fe0f}", ch);
+    /// Convert shortcodes to unicode emoji
+    pub fn emojify(&self, text: &str) -> String {
+        // Emoji are normalized too, e.g. to their variation selector form
+        self.scan(text, |code| self.get(code), |code| self.try_get(code))
+    }
 
-            if let Some(code) = self
-                .emoji_to_code
-                .get(&s)
-                .or_else(|| self.emoji_to_code.get(&with_vs))
-            {
-                // Skip variation selectors themselves
-                if ch == '\u{fe0f}' {
+    /// Single pass over `text`, replacing emoji sequences with `emoji(code)`
+    /// and shortcodes with `shortcode(code)` when it returns a replacement.
+    fn scan(
+        &self,
+        text: &str,
+        emoji: impl Fn(&str) -> String,
+        shortcode: impl Fn(&str) -> Option<String>,
+    ) -> String {
+        let mut result = String::with_capacity(text.len());
+        let mut last = 0;
+        let mut pos = 0;
+        while let Some(m) = self.scanner.find(Input::new(text).span(pos..text.len())) {
+            pos = m.end();
+            let code = &self.codes[m.pattern().as_usize()];
+            if m.pattern().as_usize() == COLON {
+                let rest = &text[m.end()..];
+                let len = rest
+                    .find(|c: char| !(c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '+')))
+                    .unwrap_or(rest.len());
+                if len == 0 || !rest[len..].starts_with(':') {
                     continue;
                 }
-                result.push_str(&format!(":{}:", code));
-            } else if ch == '\u{fe0f}' {
-                // Skip standalone variation selectors
+                // Unknown shortcodes are skipped whole, so their closing
+                // colon doesn't start another one
+                pos = m.end() + len + 1;
+                if let Some(replacement) = shortcode(&rest[..len]) {
+                    result.push_str(&text[last..m.start()]);
+                    result.push_str(&replacement);
+                    last = pos;
+                }
                 continue;
-            } else {
-                result.push(ch);
             }
-        }
 
+            result.push_str(&text[last..m.start()]);
+            // Stray variation selectors have no code and are dropped
+            if !code.is_empty() {
+                result.push_str(&emoji(code));
+            }
+            last = m.end();
+        }
+        result.push_str(&text[last..]);
         result
     }
 
-    /// Convert shortcodes to unicode emoji
-    pub fn emojify(&self, text: &str) -> String {
-        // First normalize to shortcodes
-        let text = self.demojify(text);
-        // Then resolve shortcodes to unicode
-        SHORTCODE_RE
-            .replace_all(&text, |caps: &regex::Captures| {
-                let code = &caps[1];
-                self.get(code)
-            })
-            .to_string()
-    }
-
     /// Strip all emoji from text
     pub fn strip(&self, text: &str) -> String {
         let text = self.demojify(text);
@@ -128,33 +246,17 @@ impl Devmoji {
         SHORTCODE_RE
             .replace_all(&text, |caps: &regex::Captures| {
                 let code = &caps[1];
-                // Look up the github emoji for this code
-                if let Some(&emoji) = GITHUB_EMOJIS.get(code) {
-                    // Check if any devmoji pack entry maps to this emoji
-                    for entry in &self.pack {
-                        let resolved = self.resolve_pack_emoji(&entry.emoji);
-                        if resolved == emoji {
-                            return format!(":{}:", entry.code);
-                        }
-                    }
-                }
-                // Also check gitmoji
-                if GITMOJI_MAP.contains_key(code) {
-                    for entry in &self.pack {
-                        if entry.emoji == code {
-                            return format!(":{}:", entry.code);
-                        }
-                    }
-                }
-                format!(":{}:", code)
+                // The devmoji written as the github emoji for this code
+                let devmoji = GITHUB_EMOJIS
+                    .get(code)
+                    .and_then(|&emoji| self.emoji_to_devmoji.get(emoji))
+                    // Also check gitmoji
+                    .or_else(|| match gitmoji::index(code) {
+                        Some(_) => self.shortcode_to_devmoji.get(code),
+                        None => None,
+                    });
+                format!(":{}:", devmoji.map_or(code, String::as_str))
             })
             .to_string()
     }
-
-    fn resolve_pack_emoji(&self, emoji_code: &str) -> String {
-        if let Some(&emoji) = GITHUB_EMOJIS.get(emoji_code) {
-            return emoji.to_string();
-        }
-        emoji_code.to_string()
-    }
 }
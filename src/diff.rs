@@ -0,0 +1,144 @@
+use crate::theme::Colorize;
+
+/// Lines of context shown around each change
+const CONTEXT: usize = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Line<'a> {
+    Same(&'a str),
+    Removed(&'a str),
+    Added(&'a str),
+}
+
+/// Line based diff between `old` and `new` (Myers' algorithm).
+pub fn diff_lines<'a>(old: &'a str, new: &'a str) -> Vec<Line<'a>> {
+    let a: Vec<&str> = old.lines().collect();
+    let b: Vec<&str> = new.lines().collect();
+
+    // Common prefix and suffix are cheap to peel off and keep the search small
+    let prefix = a.iter().zip(&b).take_while(|(x, y)| x == y).count();
+    let suffix = a[prefix..]
+        .iter()
+        .rev()
+        .zip(b[prefix..].iter().rev())
+        .take_while(|(x, y)| x == y)
+        .count();
+
+    let mut result: Vec<Line> = a[..prefix].iter().map(|l| Line::Same(l)).collect();
+    result.extend(myers(&a[prefix..a.len() - suffix], &b[prefix..b.len() - suffix]));
+    result.extend(a[a.len() - suffix..].iter().map(|l| Line::Same(l)));
+    result
+}
+
+fn myers<'a>(a: &[&'a str], b: &[&'a str]) -> Vec<Line<'a>> {
+    let (n, m) = (a.len() as isize, b.len() as isize);
+    let max = (n + m) as usize;
+    let offset = max as isize + 1;
+    let mut v = vec![0isize; 2 * max + 3];
+    let mut trace: Vec<Vec<isize>> = Vec::new();
+
+    'search: for d in 0..=max as isize {
+        trace.push(v.clone());
+        let mut k = -d;
+        while k <= d {
+            let idx = (k + offset) as usize;
+            let mut x = if k == -d || (k != d && v[idx - 1] < v[idx + 1]) {
+                v[idx + 1]
+            } else {
+                v[idx - 1] + 1
+            };
+            let mut y = x - k;
+            while x < n && y < m && a[x as usize] == b[y as usize] {
+                x += 1;
+                y += 1;
+            }
+            v[idx] = x;
+            if x >= n && y >= m {
+                break 'search;
+            }
+            k += 2;
+        }
+    }
+
+    // Walk the trace backwards to recover the edit script
+    let mut lines = Vec::new();
+    let (mut x, mut y) = (n, m);
+    for (d, v) in trace.iter().enumerate().rev() {
+        let d = d as isize;
+        let k = x - y;
+        let prev_k = if k == -d || (k != d && v[(k - 1 + offset) as usize] < v[(k + 1 + offset) as usize]) {
+            k + 1
+        } else {
+            k - 1
+        };
+        let prev_x = v[(prev_k + offset) as usize];
+        let prev_y = prev_x - prev_k;
+        while x > prev_x && y > prev_y {
+            x -= 1;
+            y -= 1;
+            lines.push(Line::Same(a[x as usize]));
+        }
+        if d > 0 {
+            if x == prev_x {
+                lines.push(Line::Added(b[prev_y as usize]));
+            } else {
+                lines.push(Line::Removed(a[prev_x as usize]));
+            }
+        }
+        x = prev_x;
+        y = prev_y;
+    }
+    lines.reverse();
+    lines
+}
+
+/// Render a diff as unified diff hunks, with colors when enabled.
+pub fn render(lines: &[Line], color: bool) -> String {
+    let changed: Vec<usize> = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, l)| !matches!(l, Line::Same(_)))
+        .map(|(i, _)| i)
+        .collect();
+
+    let mut out = String::new();
+    let mut i = 0;
+    while i < changed.len() {
+        // Grow the hunk while the next change is within reach of its context
+        let start = changed[i].saturating_sub(CONTEXT);
+        let mut end = changed[i];
+        while i < changed.len() && changed[i] <= end + 2 * CONTEXT {
+            end = changed[i];
+            i += 1;
+        }
+        let end = (end + CONTEXT + 1).min(lines.len());
+
+        let old_start = 1 + lines[..start].iter().filter(|l| !matches!(l, Line::Added(_))).count();
+        let new_start = 1 + lines[..start].iter().filter(|l| !matches!(l, Line::Removed(_))).count();
+        let hunk = &lines[start..end];
+        let old_len = hunk.iter().filter(|l| !matches!(l, Line::Added(_))).count();
+        let new_len = hunk.iter().filter(|l| !matches!(l, Line::Removed(_))).count();
+
+        let header = format!("@@ -{},{} +{},{} @@", old_start, old_len, new_start, new_len);
+        out.push_str(&paint(header, color, |s| s.cyan().to_string()));
+        out.push('\n');
+        for line in hunk {
+            let rendered = match line {
+                Line::Same(l) => format!(" {}", l),
+                Line::Removed(l) => paint(format!("-{}", l), color, |s| s.red().to_string()),
+                Line::Added(l) => paint(format!("+{}", l), color, |s| s.green().to_string()),
+            };
+            out.push_str(&rendered);
+            out.push('\n');
+        }
+    }
+    out
+}
+
+fn paint(text: String, color: bool, f: impl Fn(&str) -> String) -> String {
+    if color {
+        f(&text)
+    } else {
+        text
+    }
+}
@@ -0,0 +1,241 @@
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use crate::theme::Colorize;
+#[cfg(feature = "js")]
+use crate::{cache, js};
+use crate::{config, git, hook, output};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Ok,
+    /// Works, but not everywhere or not as well as it could
+    Warn,
+    Fail,
+}
+
+/// The outcome of one check, with how to fix it when it isn't ok
+#[derive(Debug)]
+pub struct Check {
+    pub name: &'static str,
+    pub status: Status,
+    pub detail: String,
+    pub fix: Option<String>,
+}
+
+impl Check {
+    fn ok(name: &'static str, detail: impl Into<String>) -> Self {
+        Check {
+            name,
+            status: Status::Ok,
+            detail: detail.into(),
+            fix: None,
+        }
+    }
+
+    fn warn(name: &'static str, detail: impl Into<String>, fix: impl Into<String>) -> Self {
+        Check {
+            name,
+            status: Status::Warn,
+            detail: detail.into(),
+            fix: Some(fix.into()),
+        }
+    }
+
+    fn fail(name: &'static str, detail: impl Into<String>, fix: impl Into<String>) -> Self {
+        Check {
+            name,
+            status: Status::Fail,
+            detail: detail.into(),
+            fix: Some(fix.into()),
+        }
+    }
+}
+
+/// Check the environment devmoji runs in, with the config files
+/// `config_path` selects. `color` is whether output is colored, from the tty
+/// or `--color`.
+pub fn run(config_path: Option<&str>, color: bool) -> Vec<Check> {
+    let configs = config::config_files(config_path);
+    let mut checks = vec![check_git()];
+    if configs.is_empty() {
+        checks.push(Check::ok("config", "none found, using defaults"));
+    }
+    checks.extend(configs.iter().map(|c| check_config(c)));
+    // The JS config that is evaluated, if any
+    #[cfg(feature = "js")]
+    let config = configs.iter().rev().find(|c| js::is_js_config(c)).map(PathBuf::as_path);
+    #[cfg(feature = "js")]
+    checks.extend(check_runtime(config));
+    checks.push(check_devmoji_on_path());
+    checks.extend(check_hooks());
+    #[cfg(feature = "js")]
+    checks.push(check_cache(config));
+    checks.push(check_emoji());
+    checks.push(check_color(color));
+    checks
+}
+
+pub fn render(checks: &[Check]) -> String {
+    let width = checks.iter().map(|c| c.name.len()).max().unwrap_or(0);
+    let mut out = String::new();
+    for check in checks {
+        let mark = match check.status {
+            Status::Ok => "\u{2714}".green(),
+            Status::Warn => "!".yellow(),
+            Status::Fail => "\u{2716}".red(),
+        };
+        out.push_str(&format!("{} {:width$}  {}\n", mark, check.name, check.detail, width = width));
+        if let Some(fix) = &check.fix {
+            let indent = " ".repeat(width + 4);
+            out.push_str(&format!("{}{}\n", indent, fix.dimmed()));
+        }
+    }
+    out
+}
+
+fn check_git() -> Check {
+    if let Err(e) = version(Path::new("git")) {
+        return Check::fail("git", e, "Install git and make sure it is on the PATH");
+    }
+    match git::find_git_dir() {
+        Some(dir) => Check::ok("git", dir.display().to_string()),
+        None => Check::warn(
+            "git",
+            "not in a git repository",
+            "Run devmoji inside a work tree to use --edit, hooks and stats",
+        ),
+    }
+}
+
+fn check_config(path: &Path) -> Check {
+    match config::check_file(path) {
+        Ok(()) => Check::ok("config", path.display().to_string()),
+        Err(e) => Check::fail(
+            "config",
+            format!("{} is invalid: {}", path.display(), e),
+            "Fix the config, devmoji fails on a broken project config and ignores other ones",
+        ),
+    }
+}
+
+/// Node or tsx, needed to evaluate JS and TS configs
+#[cfg(feature = "js")]
+fn check_runtime(config: Option<&Path>) -> Option<Check> {
+    let config = config?;
+    let program = js::runtime(config);
+    Some(match version(&program) {
+        Ok(version) => Check::ok("runtime", format!("{} {}", program.display(), version)),
+        Err(e) if cfg!(feature = "js-engine") => Check::warn(
+            "runtime",
+            e,
+            "Configs using Node APIs or types need Node (and tsx for TypeScript)",
+        ),
+        Err(e) => Check::fail(
+            "runtime",
+            e,
+            match program.ends_with("tsx") {
+                true => "Install tsx (npm i -D tsx) or use a JSON config",
+                false => "Install Node.js or use a JSON config",
+            },
+        ),
+    })
+}
+
+/// The hook and editor wrapper run `devmoji` from the PATH
+fn check_devmoji_on_path() -> Check {
+    let found = std::env::var_os("PATH")
+        .map(|path| std::env::split_paths(&path).collect::<Vec<PathBuf>>())
+        .unwrap_or_default()
+        .into_iter()
+        .map(|dir| dir.join(if cfg!(windows) { "devmoji.exe" } else { "devmoji" }))
+        .find(|bin| bin.is_file());
+    match found {
+        Some(bin) => Check::ok("path", bin.display().to_string()),
+        None => Check::fail(
+            "path",
+            "devmoji is not on the PATH",
+            "Install it with cargo install devmoji, the git hooks run it from the PATH",
+        ),
+    }
+}
+
+fn check_hooks() -> Option<Check> {
+    git::find_git_dir()?;
+    let status = match hook::status() {
+        Ok(status) => status,
+        Err(e) => return Some(Check::fail("hooks", e, "Check core.hooksPath")),
+    };
+    let hook = status.hook.display();
+    Some(if status.foreign {
+        Check::warn(
+            "hooks",
+            format!("{} runs something else", hook),
+            "Call devmoji --edit \"$1\" from it, or replace it with devmoji hook install --force",
+        )
+    } else if !status.installed {
+        Check::warn("hooks", "not installed", "Run devmoji hook install")
+    } else if !status.executable {
+        Check::fail("hooks", format!("{} is not executable", hook), format!("Run chmod +x {}", hook))
+    } else if !status.editor {
+        Check::warn(
+            "hooks",
+            format!("{}, but core.editor is not the rebase wrapper", hook),
+            "Run devmoji hook install to format messages edited during rebases too",
+        )
+    } else {
+        Check::ok("hooks", format!("{} and the rebase editor wrapper", hook))
+    })
+}
+
+#[cfg(feature = "js")]
+fn check_cache(config: Option<&Path>) -> Check {
+    let dir = match config {
+        Some(config) => cache::find_cache_dir(config),
+        None => cache::user_cache_dir(),
+    };
+    let Some(dir) = dir else {
+        return Check::warn("cache", "no cache directory", "Set HOME or XDG_CACHE_HOME");
+    };
+    match cache::check(&dir) {
+        Ok(count) => Check::ok("cache", format!("{} entries in {}", count, dir.display())),
+        Err(e) => Check::fail("cache", e, "Run devmoji cache clear"),
+    }
+}
+
+fn check_emoji() -> Check {
+    if output::terminal_supports_unicode() {
+        Check::ok("emoji", "the terminal shows emoji")
+    } else {
+        Check::warn(
+            "emoji",
+            "the terminal likely can't show emoji, they are written as ascii",
+            "Use a UTF-8 locale (e.g. LANG=en_US.UTF-8) or pass --format unicode",
+        )
+    }
+}
+
+fn check_color(color: bool) -> Check {
+    if !cfg!(feature = "color") {
+        return Check::ok("color", "off, devmoji was built without the color feature");
+    }
+    if !color {
+        return Check::ok("color", "off (--color true turns it on)");
+    }
+    match std::env::var("TERM").as_deref() {
+        Ok("dumb") => Check::warn("color", "on, but TERM=dumb", "Pass --no-color"),
+        _ => Check::ok("color", "on"),
+    }
+}
+
+/// The `--version` of a program, `Err` when it can't be run
+fn version(program: &Path) -> Result<String, String> {
+    let output = Command::new(program)
+        .arg("--version")
+        .output()
+        .map_err(|e| format!("could not run {}: {}", program.display(), e))?;
+    if !output.status.success() {
+        return Err(format!("{} --version failed ({})", program.display(), output.status));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
@@ -0,0 +1,52 @@
+use std::fmt;
+
+/// Why devmoji failed. Each kind has its own exit code, see `exit_code`, so
+/// hooks and CI can tell them apart.
+#[derive(Debug)]
+pub enum Error {
+    /// The message doesn't pass `--lint`, one line per problem
+    Lint(Vec<String>),
+    /// A check failed and was reported already, e.g. the diff of `--check`
+    Failed,
+    /// Nothing matched, e.g. a search
+    NotFound(String),
+    Usage(String),
+    Config(String),
+    Io(String),
+    Git(String),
+    Cancelled,
+    /// Exit with the status of a program devmoji ran, e.g. the editor
+    Exit(i32),
+}
+
+impl Error {
+    /// 1 for lint errors and failed checks, 2 for wrong usage, 3 for config
+    /// errors, 4 for I/O errors, 5 for git errors and 130 when cancelled
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Error::Lint(_) | Error::Failed | Error::NotFound(_) => 1,
+            Error::Usage(_) => 2,
+            Error::Config(_) => 3,
+            Error::Io(_) => 4,
+            Error::Git(_) => 5,
+            Error::Cancelled => 130,
+            Error::Exit(code) => *code,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Lint(problems) => write!(f, "{}", problems.join("\n")),
+            Error::NotFound(e)
+            | Error::Usage(e)
+            | Error::Config(e)
+            | Error::Io(e)
+            | Error::Git(e) => write!(f, "{}", e),
+            Error::Failed | Error::Cancelled | Error::Exit(_) => Ok(()),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
@@ -0,0 +1,162 @@
+//! C ABI for embedding devmoji, e.g. in editor plugins. Build the shared
+//! library with `cargo build --release --features ffi` and see
+//! `include/devmoji.h` for the declarations.
+
+use std::ffi::{c_char, c_int, CStr, CString};
+use std::panic::{self, AssertUnwindSafe};
+use std::ptr;
+
+use crate::commits::ConventionalCommits;
+use crate::config::Config;
+use crate::devmoji::Devmoji;
+use crate::output::{Output, Target};
+
+/// A loaded config, passed to every other function
+pub struct Handle {
+    config: Config,
+    devmoji: Devmoji,
+}
+
+impl Handle {
+    fn commits(&self) -> ConventionalCommits<'_> {
+        ConventionalCommits::new(&self.devmoji, &self.config)
+    }
+}
+
+/// Load the config at `config_path`, or the nearest one when it is NULL.
+/// Returns NULL on failure, e.g. when that config is missing or invalid.
+/// Free it with `devmoji_free`.
+///
+/// # Safety
+///
+/// `config_path` is NULL or a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn devmoji_new(config_path: *const c_char) -> *mut Handle {
+    let config_path = match config_path.is_null() {
+        true => None,
+        false => match CStr::from_ptr(config_path).to_str() {
+            Ok(path) => Some(path.to_string()),
+            Err(_) => return ptr::null_mut(),
+        },
+    };
+    panic::catch_unwind(|| {
+        let Ok(config) = Config::load(config_path.as_deref(), true) else {
+            return ptr::null_mut();
+        };
+        let devmoji = Devmoji::new(&config);
+        Box::into_raw(Box::new(Handle { config, devmoji }))
+    })
+    .unwrap_or(ptr::null_mut())
+}
+
+/// # Safety
+///
+/// `handle` is NULL or was returned by `devmoji_new` and not freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn devmoji_free(handle: *mut Handle) {
+    if !handle.is_null() {
+        drop(Box::from_raw(handle));
+    }
+}
+
+/// Format a commit message like `devmoji --edit` does. Returns NULL on
+/// failure, free the result with `devmoji_string_free`.
+///
+/// # Safety
+///
+/// `handle` comes from `devmoji_new` and `text` is a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn devmoji_format_commit(
+    handle: *const Handle,
+    text: *const c_char,
+) -> *mut c_char {
+    with_text(handle, text, |handle, text| {
+        let formatted = handle.commits().format_commit(text, false);
+        Some(Output::new(None, Target::File).render(&handle.devmoji, &formatted))
+    })
+}
+
+/// Replace shortcodes with emoji. Returns NULL on failure, free the result
+/// with `devmoji_string_free`.
+///
+/// # Safety
+///
+/// `handle` comes from `devmoji_new` and `text` is a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn devmoji_emojify(
+    handle: *const Handle,
+    text: *const c_char,
+) -> *mut c_char {
+    with_text(handle, text, |handle, text| {
+        Some(handle.devmoji.emojify(text))
+    })
+}
+
+/// Lint a commit message. Returns 0 when it passes and 1 when it doesn't, or
+/// -1 when `handle` or `text` is NULL, -2 when `text` isn't UTF-8 and -3 when
+/// linting failed. Unless `messages` is NULL, it is set to the errors, or the
+/// warnings of a message that passes, one per line, and to NULL when there
+/// are none. Free it with `devmoji_string_free`.
+///
+/// # Safety
+///
+/// `handle` comes from `devmoji_new`, `text` is a NUL-terminated string and
+/// `messages` is NULL or points to writable memory for a pointer.
+#[no_mangle]
+pub unsafe extern "C" fn devmoji_lint(
+    handle: *const Handle,
+    text: *const c_char,
+    messages: *mut *mut c_char,
+) -> c_int {
+    if !messages.is_null() {
+        *messages = ptr::null_mut();
+    }
+    if handle.is_null() || text.is_null() {
+        return -1;
+    }
+    let Ok(text) = CStr::from_ptr(text).to_str() else {
+        return -2;
+    };
+    let handle = &*handle;
+    let Ok(result) = panic::catch_unwind(AssertUnwindSafe(|| handle.commits().lint(text))) else {
+        return -3;
+    };
+    let (status, lines) = match result {
+        Ok(warnings) => (0, warnings),
+        Err(errors) => (1, errors),
+    };
+    if !messages.is_null() && !lines.is_empty() {
+        *messages = CString::new(lines.join("\n")).map_or(ptr::null_mut(), CString::into_raw);
+    }
+    status
+}
+
+/// # Safety
+///
+/// `s` is NULL or was returned by a devmoji function and not freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn devmoji_string_free(s: *mut c_char) {
+    if !s.is_null() {
+        drop(CString::from_raw(s));
+    }
+}
+
+/// Run `f` with the handle and text, NULL when either is invalid or it panics
+unsafe fn with_text(
+    handle: *const Handle,
+    text: *const c_char,
+    f: impl FnOnce(&Handle, &str) -> Option<String>,
+) -> *mut c_char {
+    if handle.is_null() || text.is_null() {
+        return ptr::null_mut();
+    }
+    let Ok(text) = CStr::from_ptr(text).to_str() else {
+        return ptr::null_mut();
+    };
+    let handle = &*handle;
+    panic::catch_unwind(AssertUnwindSafe(|| f(handle, text)))
+        .ok()
+        .flatten()
+        .and_then(|result| CString::new(result).ok())
+        .map_or(ptr::null_mut(), CString::into_raw)
+}
@@ -0,0 +1,108 @@
+use std::path::PathBuf;
+
+use crate::git;
+
+/// Name of the filter driver in `.gitattributes` and the git config
+const FILTER_NAME: &str = "devmoji";
+const CLEAN: &str = "devmoji --clean";
+const CLEAN_STRIP: &str = "devmoji --clean --format strip";
+const SMUDGE: &str = "devmoji --smudge";
+
+fn gitattributes() -> Result<PathBuf, String> {
+    let root = git::toplevel().ok_or_else(|| "Not inside a git work tree".to_string())?;
+    Ok(root.join(".gitattributes"))
+}
+
+/// Configure the filter driver and route `patterns` through it. Files are
+/// stored with shortcodes, or without emoji when `strip` is set, and checked
+/// out with emoji.
+pub fn install(patterns: &[String], strip: bool) -> Result<Vec<String>, String> {
+    let mut done = Vec::new();
+    let clean = if strip { CLEAN_STRIP } else { CLEAN };
+    for (key, command) in [("clean", clean), ("smudge", SMUDGE)] {
+        let key = format!("filter.{}.{}", FILTER_NAME, key);
+        if git::config_get(&key).as_deref() != Some(command) {
+            git::config_set(&key, command).map_err(|e| e.to_string())?;
+            done.push(format!("Set {} to \"{}\"", key, command));
+        }
+    }
+
+    let path = gitattributes()?;
+    let mut attributes = std::fs::read_to_string(&path).unwrap_or_default();
+    let attribute = format!("filter={}", FILTER_NAME);
+    let mut added = Vec::new();
+    for pattern in patterns {
+        let exists = attributes.lines().any(|line| {
+            let mut words = line.split_whitespace();
+            words.next() == Some(pattern.as_str()) && words.any(|w| w == attribute)
+        });
+        if !exists {
+            if !attributes.is_empty() && !attributes.ends_with('\n') {
+                attributes.push('\n');
+            }
+            attributes.push_str(&format!("{} {}\n", pattern, attribute));
+            added.push(pattern.as_str());
+        }
+    }
+    if !added.is_empty() {
+        std::fs::write(&path, &attributes)
+            .map_err(|e| format!("Error writing {}: {}", path.display(), e))?;
+        done.push(format!("Added {} to {}", added.join(", "), path.display()));
+    }
+
+    if done.is_empty() {
+        done.push("Filter is already installed".to_string());
+    }
+    Ok(done)
+}
+
+/// Remove the filter driver config and its attribute from `.gitattributes`.
+pub fn uninstall() -> Result<Vec<String>, String> {
+    let mut done = Vec::new();
+    for key in ["clean", "smudge"] {
+        let key = format!("filter.{}.{}", FILTER_NAME, key);
+        if git::config_get(&key).is_some() {
+            git::config_unset(&key).map_err(|e| e.to_string())?;
+            done.push(format!("Removed {}", key));
+        }
+    }
+
+    let path = gitattributes()?;
+    if let Ok(attributes) = std::fs::read_to_string(&path) {
+        let attribute = format!("filter={}", FILTER_NAME);
+        let mut changed = false;
+        let kept: Vec<String> = attributes
+            .lines()
+            .filter_map(|line| match without_attribute(line, &attribute) {
+                Some(line) => {
+                    changed = true;
+                    line
+                }
+                None => Some(line.to_string()),
+            })
+            .collect();
+        if changed {
+            let mut kept = kept.join("\n");
+            if !kept.is_empty() {
+                kept.push('\n');
+            }
+            std::fs::write(&path, kept)
+                .map_err(|e| format!("Error writing {}: {}", path.display(), e))?;
+            done.push(format!("Removed the devmoji filter from {}", path.display()));
+        }
+    }
+    Ok(done)
+}
+
+/// `line` without `attribute`, or `None` when it doesn't have it. Other
+/// attributes of the pattern are kept, the line is dropped when only the
+/// pattern is left.
+fn without_attribute(line: &str, attribute: &str) -> Option<Option<String>> {
+    let mut words = line.split_whitespace();
+    let pattern = words.next()?;
+    let others: Vec<&str> = words.clone().filter(|w| *w != attribute).collect();
+    if others.len() == words.count() {
+        return None;
+    }
+    Some((!others.is_empty()).then(|| format!("{} {}", pattern, others.join(" "))))
+}
@@ -0,0 +1,28 @@
+//! 64-bit FNV-1a, for hashes that must not change between builds or Rust
+//! versions, unlike `DefaultHasher`: cache keys and report fingerprints
+
+pub struct Fnv(u64);
+
+impl Default for Fnv {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Fnv {
+    pub fn new() -> Self {
+        Fnv(0xcbf29ce484222325)
+    }
+
+    pub fn write(&mut self, bytes: &[u8]) {
+        for &b in bytes {
+            self.0 ^= b as u64;
+            self.0 = self.0.wrapping_mul(0x100000001b3);
+        }
+    }
+
+    /// The hash as 16 hex digits
+    pub fn hex(&self) -> String {
+        format!("{:016x}", self.0)
+    }
+}
@@ -0,0 +1,345 @@
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::{info, trace};
+
+/// Commit message files git may hand to a hook or editor, relative to the git dir.
+/// Ordered so that `COMMIT_EDITMSG` wins when modification times tie.
+const MESSAGE_FILES: &[&str] = &[
+    "rebase-merge/message",
+    "SQUASH_MSG",
+    "MERGE_MSG",
+    "COMMIT_EDITMSG",
+];
+
+/// Find the git dir for the current directory, following `.git` files
+/// used by worktrees and submodules.
+pub fn find_git_dir() -> Option<PathBuf> {
+    let mut dir = std::env::current_dir().ok()?;
+    loop {
+        let git = dir.join(".git");
+        if git.is_dir() {
+            return Some(git);
+        }
+        if git.is_file() {
+            return read_gitdir_file(&git);
+        }
+        if !dir.pop() {
+            return None;
+        }
+    }
+}
+
+fn read_gitdir_file(path: &Path) -> Option<PathBuf> {
+    let contents = std::fs::read_to_string(path).ok()?;
+    let target = contents.trim().strip_prefix("gitdir:")?.trim();
+    let target = PathBuf::from(target);
+    if target.is_absolute() {
+        Some(target)
+    } else {
+        Some(path.parent()?.join(target))
+    }
+}
+
+/// Resolve the message file for `--edit`.
+///
+/// An explicit path is used as is. A bare name like `MERGE_MSG` that does not
+/// exist relative to the current directory is looked up in the git dir. Without
+/// an argument, the most recently written message file in the git dir wins,
+/// which is the one git just prepared for the running hook or editor.
+pub fn resolve_message_file(file: Option<&str>) -> Result<PathBuf, String> {
+    if let Some(file) = file {
+        let path = PathBuf::from(file);
+        if path.exists() || path.is_absolute() {
+            return Ok(path);
+        }
+        if let Some(git_dir) = find_git_dir() {
+            let candidate = git_dir.join(file);
+            if candidate.exists() {
+                return Ok(candidate);
+            }
+        }
+        return Ok(path);
+    }
+
+    let git_dir = find_git_dir().ok_or_else(|| "Could not find .git directory".to_string())?;
+    let path = latest_message_file(&git_dir).unwrap_or_else(|| git_dir.join("COMMIT_EDITMSG"));
+    info!("edit", "most recent message file is {}", path.display());
+    Ok(path)
+}
+
+fn latest_message_file(git_dir: &Path) -> Option<PathBuf> {
+    MESSAGE_FILES
+        .iter()
+        .map(|name| git_dir.join(name))
+        .filter_map(|path| {
+            let modified = std::fs::metadata(&path).and_then(|m| m.modified()).ok()?;
+            Some((modified, path))
+        })
+        .max_by_key(|(modified, _)| *modified)
+        .map(|(_, path)| path)
+}
+
+/// Whether the path is an interactive rebase todo list rather than a message.
+pub fn is_rebase_todo(path: &Path) -> bool {
+    path.file_name().and_then(|n| n.to_str()) == Some("git-rebase-todo")
+}
+
+/// Apply `f` to the subject of every command line in a rebase todo list,
+/// leaving comments, blank lines and commands without a subject untouched.
+/// This is only cosmetic: git takes the messages from the commits, not from
+/// the todo list.
+pub fn map_rebase_todo(text: &str, f: impl Fn(&str) -> String) -> String {
+    let mut result = String::with_capacity(text.len());
+    for line in text.split_inclusive('\n') {
+        let (content, newline) = match line.strip_suffix('\n') {
+            Some(c) => (c, "\n"),
+            None => (line, ""),
+        };
+        match todo_subject_start(content) {
+            Some(start) => {
+                result.push_str(&content[..start]);
+                result.push_str(&f(&content[start..]));
+            }
+            None => {
+                trace!("edit", "leaving todo line {:?} as is", content);
+                result.push_str(content)
+            }
+        }
+        result.push_str(newline);
+    }
+    result
+}
+
+/// Where the subject starts in a todo line like `pick <hash> <subject>` or
+/// `fixup -C <hash> <subject>`
+fn todo_subject_start(line: &str) -> Option<usize> {
+    let (cmd, mut rest) = line.split_once(' ')?;
+    if !is_todo_command(cmd) {
+        return None;
+    }
+    if matches!(cmd, "f" | "fixup") {
+        if let Some(after) = rest.strip_prefix("-C ").or_else(|| rest.strip_prefix("-c ")) {
+            rest = after;
+        }
+    }
+    let (_hash, subject) = rest.split_once(' ')?;
+    Some(line.len() - subject.len())
+}
+
+fn is_todo_command(cmd: &str) -> bool {
+    matches!(
+        cmd,
+        "p" | "pick" | "r" | "reword" | "e" | "edit" | "s" | "squash" | "f" | "fixup"
+    )
+}
+
+/// Whether a rebase is currently stopped in the repository.
+pub fn rebase_in_progress(git_dir: &Path) -> bool {
+    git_dir.join("rebase-merge").is_dir() || git_dir.join("rebase-apply").is_dir()
+}
+
+/// Commit time and message of every commit in `range` (default `HEAD`),
+/// newest first.
+pub fn log_times(range: Option<&str>) -> Result<Vec<(i64, String)>, String> {
+    let mut cmd = Command::new("git");
+    cmd.args(["log", "-z", "--format=%ct%n%B"]);
+    if let Some(range) = range {
+        cmd.arg(range);
+    }
+    info!("git", "running git log {}", range.unwrap_or(""));
+    let output = cmd.output().map_err(|e| format!("Error running git: {}", e))?;
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .split('\0')
+        .filter_map(|commit| {
+            let (time, message) = commit.split_once('\n')?;
+            Some((time.parse().ok()?, message.to_string()))
+        })
+        .collect())
+}
+
+/// Hash and message of every commit in `range`, newest first.
+pub fn log_messages(range: &str) -> Result<Vec<(String, String)>, String> {
+    info!("git", "running git log {}", range);
+    let output = Command::new("git")
+        .args(["log", "-z", "--format=%H%n%B", range, "--"])
+        .output()
+        .map_err(|e| format!("Error running git: {}", e))?;
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .split('\0')
+        .filter_map(|commit| {
+            let (hash, message) = commit.split_once('\n')?;
+            Some((hash.to_string(), message.to_string()))
+        })
+        .collect())
+}
+
+/// The top level directory of the current work tree.
+pub fn toplevel() -> Option<PathBuf> {
+    let output = Command::new("git")
+        .args(["rev-parse", "--show-toplevel"])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    Some(PathBuf::from(String::from_utf8_lossy(&output.stdout).trim_end()))
+}
+
+/// Read a git config value.
+pub fn config_get(key: &str) -> Option<String> {
+    read_config(&["--get", key])
+}
+
+/// Get a git config value from one config file, `scope` being `local`,
+/// `global` or `system`.
+pub fn config_get_from(scope: &str, key: &str) -> Option<String> {
+    read_config(&[&format!("--{}", scope), "--get", key])
+}
+
+fn read_config(args: &[&str]) -> Option<String> {
+    let output = Command::new("git").arg("config").args(args).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let value = String::from_utf8_lossy(&output.stdout).trim_end().to_string();
+    Some(value)
+}
+
+/// Set a git config value in the local repository config.
+pub fn config_set(key: &str, value: &str) -> io::Result<()> {
+    run_git(&["config", key, value])
+}
+
+/// Remove a git config value from the local repository config.
+pub fn config_unset(key: &str) -> io::Result<()> {
+    run_git(&["config", "--unset", key])
+}
+
+fn run_git(args: &[&str]) -> io::Result<()> {
+    info!("git", "running git {}", args.join(" "));
+    let status = Command::new("git").args(args).status()?;
+    if status.success() {
+        Ok(())
+    } else {
+        Err(io::Error::other(format!("git {} failed", args.join(" "))))
+    }
+}
+
+/// Marker lines git writes in front of each message when combining commits
+/// for `squash` and `fixup` during an interactive rebase.
+static SQUASH_MARKER_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^# This is the (?:\d+(?:st|nd|rd|th) )?commit message(?: #\d+)?:\s*$").unwrap()
+});
+
+/// Apply `f` to every message in a squash combination, starting at its first
+/// line that is neither blank nor a comment. Messages without markers are
+/// treated as a single message.
+pub fn map_squash_messages(text: &str, f: impl Fn(&str) -> String) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut block = String::new();
+
+    let flush = |block: &mut String, result: &mut String| {
+        let mut rest = block.as_str();
+        while let Some(line) = rest.split_inclusive('\n').next() {
+            if !line.trim().is_empty() && !line.starts_with('#') {
+                break;
+            }
+            result.push_str(line);
+            rest = &rest[line.len()..];
+        }
+        if !rest.is_empty() {
+            result.push_str(&f(rest));
+        }
+        block.clear();
+    };
+
+    for line in text.split_inclusive('\n') {
+        if SQUASH_MARKER_RE.is_match(line.trim_end_matches(['\r', '\n'])) {
+            flush(&mut block, &mut result);
+            result.push_str(line);
+        } else {
+            block.push_str(line);
+        }
+    }
+    flush(&mut block, &mut result);
+    result
+}
+
+/// The text git starts comment lines of commit messages with, from
+/// `core.commentString` or `core.commentChar`. `auto` picks one that isn't
+/// used in the message, which is `#` for the messages git prepares.
+pub fn comment_prefix() -> String {
+    config_get("core.commentString")
+        .or_else(|| config_get("core.commentChar"))
+        .filter(|c| !c.is_empty() && c != "auto")
+        .unwrap_or_else(|| "#".to_string())
+}
+
+/// Apply `f` to the message in a commit message file, leaving the parts git
+/// strips as they are: comment lines, and everything from the scissors line
+/// `git commit --verbose` writes in front of the diff. Comment lines are put
+/// back where they were, the ones after the message after what `f` returns.
+pub fn map_message(text: &str, comment: &str, f: impl FnOnce(&str) -> String) -> String {
+    let (head, tail) = split_scissors(text, comment);
+
+    let lines: Vec<&str> = head.split_inclusive('\n').collect();
+    let is_comment = |line: &str| line.starts_with(comment);
+    let message: String = lines.iter().filter(|l| !is_comment(l)).copied().collect();
+    if message.len() == head.len() {
+        return f(head) + tail;
+    }
+    trace!("edit", "leaving comment lines and the scissors section as is");
+
+    let formatted = f(&message);
+    let mut formatted_lines = formatted.split_inclusive('\n');
+    let last_line = lines.iter().rposition(|l| !is_comment(l)).map_or(0, |i| i + 1);
+    let mut result = String::with_capacity(text.len());
+    let push = |result: &mut String, line: &str| {
+        if !result.is_empty() && !result.ends_with('\n') {
+            result.push('\n');
+        }
+        result.push_str(line);
+    };
+    for line in &lines[..last_line] {
+        match is_comment(line) {
+            true => push(&mut result, line),
+            false => push(&mut result, formatted_lines.next().unwrap_or("")),
+        }
+    }
+    for line in formatted_lines.chain(lines[last_line..].iter().copied()) {
+        push(&mut result, line);
+    }
+    if !tail.is_empty() {
+        push(&mut result, tail);
+    }
+    result
+}
+
+/// Split a commit message file at the scissors line, which starts the second
+/// part. It is empty without one.
+pub fn split_scissors<'t>(text: &'t str, comment: &str) -> (&'t str, &'t str) {
+    let scissors = format!("{} {}", comment, SCISSORS);
+    text.split_inclusive('\n')
+        .scan(0, |pos, line| {
+            let start = *pos;
+            *pos += line.len();
+            Some((start, line))
+        })
+        .find(|(_, line)| line.trim_end_matches(['\r', '\n']) == scissors)
+        .map_or((text, ""), |(start, _)| text.split_at(start))
+}
+
+const SCISSORS: &str = "------------------------ >8 ------------------------";
@@ -1,1921 +1,3 @@
-use std::collections::HashMap;
-
-use once_cell::sync::Lazy;
-
-pub static GITHUB_EMOJIS: Lazy<HashMap<&'static str, &'static str>> = Lazy::new(|| {
-    let mut m = HashMap::with_capacity(1913);
-    m.insert("+1", "\u{1F44D}");
-    m.insert("-1", "\u{1F44E}");
-    m.insert("100", "\u{1F4AF}");
-    m.insert("1234", "\u{1F522}");
-    m.insert("1st_place_medal", "\u{1F947}");
-    m.insert("2nd_place_medal", "\u{1F948}");
-    m.insert("3rd_place_medal", "\u{1F949}");
-    m.insert("8ball", "\u{1F3B1}");
-    m.insert("a", "\u{1F170}\u{FE0F}");
-    m.insert("ab", "\u{1F18E}");
-    m.insert("abacus", "\u{1F9EE}");
-    m.insert("abc", "\u{1F524}");
-    m.insert("abcd", "\u{1F521}");
-    m.insert("accept", "\u{1F251}");
-    m.insert("accordion", "\u{1FA97}");
-    m.insert("adhesive_bandage", "\u{1FA79}");
-    m.insert("adult", "\u{1F9D1}");
-    m.insert("aerial_tramway", "\u{1F6A1}");
-    m.insert("afghanistan", "\u{1F1E6}\u{1F1EB}");
-    m.insert("airplane", "\u{2708}\u{FE0F}");
-    m.insert("aland_islands", "\u{1F1E6}\u{1F1FD}");
-    m.insert("alarm_clock", "\u{23F0}");
-    m.insert("albania", "\u{1F1E6}\u{1F1F1}");
-    m.insert("alembic", "\u{2697}\u{FE0F}");
-    m.insert("algeria", "\u{1F1E9}\u{1F1FF}");
-    m.insert("alien", "\u{1F47D}");
-    m.insert("ambulance", "\u{1F691}");
-    m.insert("american_samoa", "\u{1F1E6}\u{1F1F8}");
-    m.insert("amphora", "\u{1F3FA}");
-    m.insert("anatomical_heart", "\u{1FAC0}");
-    m.insert("anchor", "\u{2693}");
-    m.insert("andorra", "\u{1F1E6}\u{1F1E9}");
-    m.insert("angel", "\u{1F47C}");
-    m.insert("anger", "\u{1F4A2}");
-    m.insert("angola", "\u{1F1E6}\u{1F1F4}");
-    m.insert("angry", "\u{1F620}");
-    m.insert("anguilla", "\u{1F1E6}\u{1F1EE}");
-    m.insert("anguished", "\u{1F627}");
-    m.insert("ant", "\u{1F41C}");
-    m.insert("antarctica", "\u{1F1E6}\u{1F1F6}");
-    m.insert("antigua_barbuda", "\u{1F1E6}\u{1F1EC}");
-    m.insert("apple", "\u{1F34E}");
-    m.insert("aquarius", "\u{2652}");
-    m.insert("argentina", "\u{1F1E6}\u{1F1F7}");
-    m.insert("aries", "\u{2648}");
-    m.insert("armenia", "\u{1F1E6}\u{1F1F2}");
-    m.insert("arrow_backward", "\u{25C0}\u{FE0F}");
-    m.insert("arrow_double_down", "\u{23EC}");
-    m.insert("arrow_double_up", "\u{23EB}");
-    m.insert("arrow_down", "\u{2B07}\u{FE0F}");
-    m.insert("arrow_down_small", "\u{1F53D}");
-    m.insert("arrow_forward", "\u{25B6}\u{FE0F}");
-    m.insert("arrow_heading_down", "\u{2935}\u{FE0F}");
-    m.insert("arrow_heading_up", "\u{2934}\u{FE0F}");
-    m.insert("arrow_left", "\u{2B05}\u{FE0F}");
-    m.insert("arrow_lower_left", "\u{2199}\u{FE0F}");
-    m.insert("arrow_lower_right", "\u{2198}\u{FE0F}");
-    m.insert("arrow_right", "\u{27A1}\u{FE0F}");
-    m.insert("arrow_right_hook", "\u{21AA}\u{FE0F}");
-    m.insert("arrow_up", "\u{2B06}\u{FE0F}");
-    m.insert("arrow_up_down", "\u{2195}\u{FE0F}");
-    m.insert("arrow_up_small", "\u{1F53C}");
-    m.insert("arrow_upper_left", "\u{2196}\u{FE0F}");
-    m.insert("arrow_upper_right", "\u{2197}\u{FE0F}");
-    m.insert("arrows_clockwise", "\u{1F503}");
-    m.insert("arrows_counterclockwise", "\u{1F504}");
-    m.insert("art", "\u{1F3A8}");
-    m.insert("articulated_lorry", "\u{1F69B}");
-    m.insert("artificial_satellite", "\u{1F6F0}\u{FE0F}");
-    m.insert("artist", "\u{1F9D1}\u{1F3A8}");
-    m.insert("aruba", "\u{1F1E6}\u{1F1FC}");
-    m.insert("ascension_island", "\u{1F1E6}\u{1F1E8}");
-    m.insert("asterisk", "\u{002A}\u{FE0F}\u{20E3}");
-    m.insert("astonished", "\u{1F632}");
-    m.insert("astronaut", "\u{1F9D1}\u{1F680}");
-    m.insert("athletic_shoe", "\u{1F45F}");
-    m.insert("atm", "\u{1F3E7}");
-    m.insert("atom_symbol", "\u{269B}\u{FE0F}");
-    m.insert("australia", "\u{1F1E6}\u{1F1FA}");
-    m.insert("austria", "\u{1F1E6}\u{1F1F9}");
-    m.insert("auto_rickshaw", "\u{1F6FA}");
-    m.insert("avocado", "\u{1F951}");
-    m.insert("axe", "\u{1FA93}");
-    m.insert("azerbaijan", "\u{1F1E6}\u{1F1FF}");
-    m.insert("b", "\u{1F171}\u{FE0F}");
-    m.insert("baby", "\u{1F476}");
-    m.insert("baby_bottle", "\u{1F37C}");
-    m.insert("baby_chick", "\u{1F424}");
-    m.insert("baby_symbol", "\u{1F6BC}");
-    m.insert("back", "\u{1F519}");
-    m.insert("bacon", "\u{1F953}");
-    m.insert("badger", "\u{1F9A1}");
-    m.insert("badminton", "\u{1F3F8}");
-    m.insert("bagel", "\u{1F96F}");
-    m.insert("baggage_claim", "\u{1F6C4}");
-    m.insert("baguette_bread", "\u{1F956}");
-    m.insert("bahamas", "\u{1F1E7}\u{1F1F8}");
-    m.insert("bahrain", "\u{1F1E7}\u{1F1ED}");
-    m.insert("balance_scale", "\u{2696}\u{FE0F}");
-    m.insert("bald_man", "\u{1F468}\u{1F9B2}");
-    m.insert("bald_woman", "\u{1F469}\u{1F9B2}");
-    m.insert("ballet_shoes", "\u{1FA70}");
-    m.insert("balloon", "\u{1F388}");
-    m.insert("ballot_box", "\u{1F5F3}\u{FE0F}");
-    m.insert("ballot_box_with_check", "\u{2611}\u{FE0F}");
-    m.insert("bamboo", "\u{1F38D}");
-    m.insert("banana", "\u{1F34C}");
-    m.insert("bangbang", "\u{203C}\u{FE0F}");
-    m.insert("bangladesh", "\u{1F1E7}\u{1F1E9}");
-    m.insert("banjo", "\u{1FA95}");
-    m.insert("bank", "\u{1F3E6}");
-    m.insert("bar_chart", "\u{1F4CA}");
-    m.insert("barbados", "\u{1F1E7}\u{1F1E7}");
-    m.insert("barber", "\u{1F488}");
-    m.insert("baseball", "\u{26BE}");
-    m.insert("basket", "\u{1F9FA}");
-    m.insert("basketball", "\u{1F3C0}");
-    m.insert("basketball_man", "\u{26F9}\u{FE0F}\u{2642}\u{FE0F}");
-    m.insert("basketball_woman", "\u{26F9}\u{FE0F}\u{2640}\u{FE0F}");
-    m.insert("bat", "\u{1F987}");
-    m.insert("bath", "\u{1F6C0}");
-    m.insert("bathtub", "\u{1F6C1}");
-    m.insert("battery", "\u{1F50B}");
-    m.insert("beach_umbrella", "\u{1F3D6}\u{FE0F}");
-    m.insert("beans", "\u{1FAD8}");
-    m.insert("bear", "\u{1F43B}");
-    m.insert("bearded_person", "\u{1F9D4}");
-    m.insert("beaver", "\u{1F9AB}");
-    m.insert("bed", "\u{1F6CF}\u{FE0F}");
-    m.insert("bee", "\u{1F41D}");
-    m.insert("beer", "\u{1F37A}");
-    m.insert("beers", "\u{1F37B}");
-    m.insert("beetle", "\u{1FAB2}");
-    m.insert("beginner", "\u{1F530}");
-    m.insert("belarus", "\u{1F1E7}\u{1F1FE}");
-    m.insert("belgium", "\u{1F1E7}\u{1F1EA}");
-    m.insert("belize", "\u{1F1E7}\u{1F1FF}");
-    m.insert("bell", "\u{1F514}");
-    m.insert("bell_pepper", "\u{1FAD1}");
-    m.insert("bellhop_bell", "\u{1F6CE}\u{FE0F}");
-    m.insert("benin", "\u{1F1E7}\u{1F1EF}");
-    m.insert("bento", "\u{1F371}");
-    m.insert("bermuda", "\u{1F1E7}\u{1F1F2}");
-    m.insert("beverage_box", "\u{1F9C3}");
-    m.insert("bhutan", "\u{1F1E7}\u{1F1F9}");
-    m.insert("bicyclist", "\u{1F6B4}");
-    m.insert("bike", "\u{1F6B2}");
-    m.insert("biking_man", "\u{1F6B4}\u{2642}\u{FE0F}");
-    m.insert("biking_woman", "\u{1F6B4}\u{2640}\u{FE0F}");
-    m.insert("bikini", "\u{1F459}");
-    m.insert("billed_cap", "\u{1F9E2}");
-    m.insert("biohazard", "\u{2623}\u{FE0F}");
-    m.insert("bird", "\u{1F426}");
-    m.insert("birthday", "\u{1F382}");
-    m.insert("bison", "\u{1F9AC}");
-    m.insert("biting_lip", "\u{1FAE6}");
-    m.insert("black_bird", "\u{1F426}\u{2B1B}");
-    m.insert("black_cat", "\u{1F408}\u{2B1B}");
-    m.insert("black_circle", "\u{26AB}");
-    m.insert("black_flag", "\u{1F3F4}");
-    m.insert("black_heart", "\u{1F5A4}");
-    m.insert("black_joker", "\u{1F0CF}");
-    m.insert("black_large_square", "\u{2B1B}");
-    m.insert("black_medium_small_square", "\u{25FE}");
-    m.insert("black_medium_square", "\u{25FC}\u{FE0F}");
-    m.insert("black_nib", "\u{2712}\u{FE0F}");
-    m.insert("black_small_square", "\u{25AA}\u{FE0F}");
-    m.insert("black_square_button", "\u{1F532}");
-    m.insert("blond_haired_man", "\u{1F471}\u{2642}\u{FE0F}");
-    m.insert("blond_haired_person", "\u{1F471}");
-    m.insert("blond_haired_woman", "\u{1F471}\u{2640}\u{FE0F}");
-    m.insert("blonde_woman", "\u{1F471}\u{2640}\u{FE0F}");
-    m.insert("blossom", "\u{1F33C}");
-    m.insert("blowfish", "\u{1F421}");
-    m.insert("blue_book", "\u{1F4D8}");
-    m.insert("blue_car", "\u{1F699}");
-    m.insert("blue_heart", "\u{1F499}");
-    m.insert("blue_square", "\u{1F7E6}");
-    m.insert("blueberries", "\u{1FAD0}");
-    m.insert("blush", "\u{1F60A}");
-    m.insert("boar", "\u{1F417}");
-    m.insert("boat", "\u{26F5}");
-    m.insert("bolivia", "\u{1F1E7}\u{1F1F4}");
-    m.insert("bomb", "\u{1F4A3}");
-    m.insert("bone", "\u{1F9B4}");
-    m.insert("book", "\u{1F4D6}");
-    m.insert("bookmark", "\u{1F516}");
-    m.insert("bookmark_tabs", "\u{1F4D1}");
-    m.insert("books", "\u{1F4DA}");
-    m.insert("boom", "\u{1F4A5}");
-    m.insert("boomerang", "\u{1FA83}");
-    m.insert("boot", "\u{1F462}");
-    m.insert("bosnia_herzegovina", "\u{1F1E7}\u{1F1E6}");
-    m.insert("botswana", "\u{1F1E7}\u{1F1FC}");
-    m.insert("bouncing_ball_man", "\u{26F9}\u{FE0F}\u{2642}\u{FE0F}");
-    m.insert("bouncing_ball_person", "\u{26F9}\u{FE0F}");
-    m.insert("bouncing_ball_woman", "\u{26F9}\u{FE0F}\u{2640}\u{FE0F}");
-    m.insert("bouquet", "\u{1F490}");
-    m.insert("bouvet_island", "\u{1F1E7}\u{1F1FB}");
-    m.insert("bow", "\u{1F647}");
-    m.insert("bow_and_arrow", "\u{1F3F9}");
-    m.insert("bowing_man", "\u{1F647}\u{2642}\u{FE0F}");
-    m.insert("bowing_woman", "\u{1F647}\u{2640}\u{FE0F}");
-    m.insert("bowl_with_spoon", "\u{1F963}");
-    m.insert("bowling", "\u{1F3B3}");
-    m.insert("boxing_glove", "\u{1F94A}");
-    m.insert("boy", "\u{1F466}");
-    m.insert("brain", "\u{1F9E0}");
-    m.insert("brazil", "\u{1F1E7}\u{1F1F7}");
-    m.insert("bread", "\u{1F35E}");
-    m.insert("breast_feeding", "\u{1F931}");
-    m.insert("bricks", "\u{1F9F1}");
-    m.insert("bride_with_veil", "\u{1F470}\u{2640}\u{FE0F}");
-    m.insert("bridge_at_night", "\u{1F309}");
-    m.insert("briefcase", "\u{1F4BC}");
-    m.insert("british_indian_ocean_territory", "\u{1F1EE}\u{1F1F4}");
-    m.insert("british_virgin_islands", "\u{1F1FB}\u{1F1EC}");
-    m.insert("broccoli", "\u{1F966}");
-    m.insert("broken_heart", "\u{1F494}");
-    m.insert("broom", "\u{1F9F9}");
-    m.insert("brown_circle", "\u{1F7E4}");
-    m.insert("brown_heart", "\u{1F90E}");
-    m.insert("brown_square", "\u{1F7EB}");
-    m.insert("brunei", "\u{1F1E7}\u{1F1F3}");
-    m.insert("bubble_tea", "\u{1F9CB}");
-    m.insert("bubbles", "\u{1FAE7}");
-    m.insert("bucket", "\u{1FAA3}");
-    m.insert("bug", "\u{1F41B}");
-    m.insert("building_construction", "\u{1F3D7}\u{FE0F}");
-    m.insert("bulb", "\u{1F4A1}");
-    m.insert("bulgaria", "\u{1F1E7}\u{1F1EC}");
-    m.insert("bullettrain_front", "\u{1F685}");
-    m.insert("bullettrain_side", "\u{1F684}");
-    m.insert("burkina_faso", "\u{1F1E7}\u{1F1EB}");
-    m.insert("burrito", "\u{1F32F}");
-    m.insert("burundi", "\u{1F1E7}\u{1F1EE}");
-    m.insert("bus", "\u{1F68C}");
-    m.insert("business_suit_levitating", "\u{1F574}\u{FE0F}");
-    m.insert("busstop", "\u{1F68F}");
-    m.insert("bust_in_silhouette", "\u{1F464}");
-    m.insert("busts_in_silhouette", "\u{1F465}");
-    m.insert("butter", "\u{1F9C8}");
-    m.insert("butterfly", "\u{1F98B}");
-    m.insert("cactus", "\u{1F335}");
-    m.insert("cake", "\u{1F370}");
-    m.insert("calendar", "\u{1F4C6}");
-    m.insert("call_me_hand", "\u{1F919}");
-    m.insert("calling", "\u{1F4F2}");
-    m.insert("cambodia", "\u{1F1F0}\u{1F1ED}");
-    m.insert("camel", "\u{1F42B}");
-    m.insert("camera", "\u{1F4F7}");
-    m.insert("camera_flash", "\u{1F4F8}");
-    m.insert("cameroon", "\u{1F1E8}\u{1F1F2}");
-    m.insert("camping", "\u{1F3D5}\u{FE0F}");
-    m.insert("canada", "\u{1F1E8}\u{1F1E6}");
-    m.insert("canary_islands", "\u{1F1EE}\u{1F1E8}");
-    m.insert("cancer", "\u{264B}");
-    m.insert("candle", "\u{1F56F}\u{FE0F}");
-    m.insert("candy", "\u{1F36C}");
-    m.insert("canned_food", "\u{1F96B}");
-    m.insert("canoe", "\u{1F6F6}");
-    m.insert("cape_verde", "\u{1F1E8}\u{1F1FB}");
-    m.insert("capital_abcd", "\u{1F520}");
-    m.insert("capricorn", "\u{2651}");
-    m.insert("car", "\u{1F697}");
-    m.insert("card_file_box", "\u{1F5C3}\u{FE0F}");
-    m.insert("card_index", "\u{1F4C7}");
-    m.insert("card_index_dividers", "\u{1F5C2}\u{FE0F}");
-    m.insert("caribbean_netherlands", "\u{1F1E7}\u{1F1F6}");
-    m.insert("carousel_horse", "\u{1F3A0}");
-    m.insert("carpentry_saw", "\u{1FA9A}");
-    m.insert("carrot", "\u{1F955}");
-    m.insert("cartwheeling", "\u{1F938}");
-    m.insert("cat", "\u{1F431}");
-    m.insert("cat2", "\u{1F408}");
-    m.insert("cayman_islands", "\u{1F1F0}\u{1F1FE}");
-    m.insert("cd", "\u{1F4BF}");
-    m.insert("central_african_republic", "\u{1F1E8}\u{1F1EB}");
-    m.insert("ceuta_melilla", "\u{1F1EA}\u{1F1E6}");
-    m.insert("chad", "\u{1F1F9}\u{1F1E9}");
-    m.insert("chains", "\u{26D3}\u{FE0F}");
-    m.insert("chair", "\u{1FA91}");
-    m.insert("champagne", "\u{1F37E}");
-    m.insert("chart", "\u{1F4B9}");
-    m.insert("chart_with_downwards_trend", "\u{1F4C9}");
-    m.insert("chart_with_upwards_trend", "\u{1F4C8}");
-    m.insert("checkered_flag", "\u{1F3C1}");
-    m.insert("cheese", "\u{1F9C0}");
-    m.insert("cherries", "\u{1F352}");
-    m.insert("cherry_blossom", "\u{1F338}");
-    m.insert("chess_pawn", "\u{265F}\u{FE0F}");
-    m.insert("chestnut", "\u{1F330}");
-    m.insert("chicken", "\u{1F414}");
-    m.insert("child", "\u{1F9D2}");
-    m.insert("children_crossing", "\u{1F6B8}");
-    m.insert("chile", "\u{1F1E8}\u{1F1F1}");
-    m.insert("chipmunk", "\u{1F43F}\u{FE0F}");
-    m.insert("chocolate_bar", "\u{1F36B}");
-    m.insert("chopsticks", "\u{1F962}");
-    m.insert("christmas_island", "\u{1F1E8}\u{1F1FD}");
-    m.insert("christmas_tree", "\u{1F384}");
-    m.insert("church", "\u{26EA}");
-    m.insert("cinema", "\u{1F3A6}");
-    m.insert("circus_tent", "\u{1F3AA}");
-    m.insert("city_sunrise", "\u{1F307}");
-    m.insert("city_sunset", "\u{1F306}");
-    m.insert("cityscape", "\u{1F3D9}\u{FE0F}");
-    m.insert("cl", "\u{1F191}");
-    m.insert("clamp", "\u{1F5DC}\u{FE0F}");
-    m.insert("clap", "\u{1F44F}");
-    m.insert("clapper", "\u{1F3AC}");
-    m.insert("classical_building", "\u{1F3DB}\u{FE0F}");
-    m.insert("climbing", "\u{1F9D7}");
-    m.insert("climbing_man", "\u{1F9D7}\u{2642}\u{FE0F}");
-    m.insert("climbing_woman", "\u{1F9D7}\u{2640}\u{FE0F}");
-    m.insert("clinking_glasses", "\u{1F942}");
-    m.insert("clipboard", "\u{1F4CB}");
-    m.insert("clipperton_island", "\u{1F1E8}\u{1F1F5}");
-    m.insert("clock1", "\u{1F550}");
-    m.insert("clock10", "\u{1F559}");
-    m.insert("clock1030", "\u{1F565}");
-    m.insert("clock11", "\u{1F55A}");
-    m.insert("clock1130", "\u{1F566}");
-    m.insert("clock12", "\u{1F55B}");
-    m.insert("clock1230", "\u{1F567}");
-    m.insert("clock130", "\u{1F55C}");
-    m.insert("clock2", "\u{1F551}");
-    m.insert("clock230", "\u{1F55D}");
-    m.insert("clock3", "\u{1F552}");
-    m.insert("clock330", "\u{1F55E}");
-    m.insert("clock4", "\u{1F553}");
-    m.insert("clock430", "\u{1F55F}");
-    m.insert("clock5", "\u{1F554}");
-    m.insert("clock530", "\u{1F560}");
-    m.insert("clock6", "\u{1F555}");
-    m.insert("clock630", "\u{1F561}");
-    m.insert("clock7", "\u{1F556}");
-    m.insert("clock730", "\u{1F562}");
-    m.insert("clock8", "\u{1F557}");
-    m.insert("clock830", "\u{1F563}");
-    m.insert("clock9", "\u{1F558}");
-    m.insert("clock930", "\u{1F564}");
-    m.insert("closed_book", "\u{1F4D5}");
-    m.insert("closed_lock_with_key", "\u{1F510}");
-    m.insert("closed_umbrella", "\u{1F302}");
-    m.insert("cloud", "\u{2601}\u{FE0F}");
-    m.insert("cloud_with_lightning", "\u{1F329}\u{FE0F}");
-    m.insert("cloud_with_lightning_and_rain", "\u{26C8}\u{FE0F}");
-    m.insert("cloud_with_rain", "\u{1F327}\u{FE0F}");
-    m.insert("cloud_with_snow", "\u{1F328}\u{FE0F}");
-    m.insert("clown_face", "\u{1F921}");
-    m.insert("clubs", "\u{2663}\u{FE0F}");
-    m.insert("cn", "\u{1F1E8}\u{1F1F3}");
-    m.insert("coat", "\u{1F9E5}");
-    m.insert("cockroach", "\u{1FAB3}");
-    m.insert("cocktail", "\u{1F378}");
-    m.insert("coconut", "\u{1F965}");
-    m.insert("cocos_islands", "\u{1F1E8}\u{1F1E8}");
-    m.insert("coffee", "\u{2615}");
-    m.insert("coffin", "\u{26B0}\u{FE0F}");
-    m.insert("coin", "\u{1FA99}");
-    m.insert("cold_face", "\u{1F976}");
-    m.insert("cold_sweat", "\u{1F630}");
-    m.insert("collision", "\u{1F4A5}");
-    m.insert("colombia", "\u{1F1E8}\u{1F1F4}");
-    m.insert("comet", "\u{2604}\u{FE0F}");
-    m.insert("comoros", "\u{1F1F0}\u{1F1F2}");
-    m.insert("compass", "\u{1F9ED}");
-    m.insert("computer", "\u{1F4BB}");
-    m.insert("computer_mouse", "\u{1F5B1}\u{FE0F}");
-    m.insert("confetti_ball", "\u{1F38A}");
-    m.insert("confounded", "\u{1F616}");
-    m.insert("confused", "\u{1F615}");
-    m.insert("congo_brazzaville", "\u{1F1E8}\u{1F1EC}");
-    m.insert("congo_kinshasa", "\u{1F1E8}\u{1F1E9}");
-    m.insert("congratulations", "\u{3297}\u{FE0F}");
-    m.insert("construction", "\u{1F6A7}");
-    m.insert("construction_worker", "\u{1F477}");
-    m.insert("construction_worker_man", "\u{1F477}\u{2642}\u{FE0F}");
-    m.insert("construction_worker_woman", "\u{1F477}\u{2640}\u{FE0F}");
-    m.insert("control_knobs", "\u{1F39B}\u{FE0F}");
-    m.insert("convenience_store", "\u{1F3EA}");
-    m.insert("cook", "\u{1F9D1}\u{1F373}");
-    m.insert("cook_islands", "\u{1F1E8}\u{1F1F0}");
-    m.insert("cookie", "\u{1F36A}");
-    m.insert("cool", "\u{1F192}");
-    m.insert("cop", "\u{1F46E}");
-    m.insert("copyright", "\u{00A9}\u{FE0F}");
-    m.insert("coral", "\u{1FAB8}");
-    m.insert("corn", "\u{1F33D}");
-    m.insert("costa_rica", "\u{1F1E8}\u{1F1F7}");
-    m.insert("cote_divoire", "\u{1F1E8}\u{1F1EE}");
-    m.insert("couch_and_lamp", "\u{1F6CB}\u{FE0F}");
-    m.insert("couple", "\u{1F46B}");
-    m.insert("couple_with_heart", "\u{1F491}");
-    m.insert("couple_with_heart_man_man", "\u{1F468}\u{2764}\u{FE0F}\u{1F468}");
-    m.insert("couple_with_heart_woman_man", "\u{1F469}\u{2764}\u{FE0F}\u{1F468}");
-    m.insert("couple_with_heart_woman_woman", "\u{1F469}\u{2764}\u{FE0F}\u{1F469}");
-    m.insert("couplekiss", "\u{1F48F}");
-    m.insert("couplekiss_man_man", "\u{1F468}\u{2764}\u{FE0F}\u{1F48B}\u{1F468}");
-    m.insert("couplekiss_man_woman", "\u{1F469}\u{2764}\u{FE0F}\u{1F48B}\u{1F468}");
-    m.insert("couplekiss_woman_woman", "\u{1F469}\u{2764}\u{FE0F}\u{1F48B}\u{1F469}");
-    m.insert("cow", "\u{1F42E}");
-    m.insert("cow2", "\u{1F404}");
-    m.insert("cowboy_hat_face", "\u{1F920}");
-    m.insert("crab", "\u{1F980}");
-    m.insert("crayon", "\u{1F58D}\u{FE0F}");
-    m.insert("credit_card", "\u{1F4B3}");
-    m.insert("crescent_moon", "\u{1F319}");
-    m.insert("cricket", "\u{1F997}");
-    m.insert("cricket_game", "\u{1F3CF}");
-    m.insert("croatia", "\u{1F1ED}\u{1F1F7}");
-    m.insert("crocodile", "\u{1F40A}");
-    m.insert("croissant", "\u{1F950}");
-    m.insert("crossed_fingers", "\u{1F91E}");
-    m.insert("crossed_flags", "\u{1F38C}");
-    m.insert("crossed_swords", "\u{2694}\u{FE0F}");
-    m.insert("crown", "\u{1F451}");
-    m.insert("crutch", "\u{1FA7C}");
-    m.insert("cry", "\u{1F622}");
-    m.insert("crying_cat_face", "\u{1F63F}");
-    m.insert("crystal_ball", "\u{1F52E}");
-    m.insert("cuba", "\u{1F1E8}\u{1F1FA}");
-    m.insert("cucumber", "\u{1F952}");
-    m.insert("cup_with_straw", "\u{1F964}");
-    m.insert("cupcake", "\u{1F9C1}");
-    m.insert("cupid", "\u{1F498}");
-    m.insert("curacao", "\u{1F1E8}\u{1F1FC}");
-    m.insert("curling_stone", "\u{1F94C}");
-    m.insert("curly_haired_man", "\u{1F468}\u{1F9B1}");
-    m.insert("curly_haired_woman", "\u{1F469}\u{1F9B1}");
-    m.insert("curly_loop", "\u{27B0}");
-    m.insert("currency_exchange", "\u{1F4B1}");
-    m.insert("curry", "\u{1F35B}");
-    m.insert("cursing_face", "\u{1F92C}");
-    m.insert("custard", "\u{1F36E}");
-    m.insert("customs", "\u{1F6C3}");
-    m.insert("cut_of_meat", "\u{1F969}");
-    m.insert("cyclone", "\u{1F300}");
-    m.insert("cyprus", "\u{1F1E8}\u{1F1FE}");
-    m.insert("czech_republic", "\u{1F1E8}\u{1F1FF}");
-    m.insert("dagger", "\u{1F5E1}\u{FE0F}");
-    m.insert("dancer", "\u{1F483}");
-    m.insert("dancers", "\u{1F46F}");
-    m.insert("dancing_men", "\u{1F46F}\u{2642}\u{FE0F}");
-    m.insert("dancing_women", "\u{1F46F}\u{2640}\u{FE0F}");
-    m.insert("dango", "\u{1F361}");
-    m.insert("dark_sunglasses", "\u{1F576}\u{FE0F}");
-    m.insert("dart", "\u{1F3AF}");
-    m.insert("dash", "\u{1F4A8}");
-    m.insert("date", "\u{1F4C5}");
-    m.insert("de", "\u{1F1E9}\u{1F1EA}");
-    m.insert("deaf_man", "\u{1F9CF}\u{2642}\u{FE0F}");
-    m.insert("deaf_person", "\u{1F9CF}");
-    m.insert("deaf_woman", "\u{1F9CF}\u{2640}\u{FE0F}");
-    m.insert("deciduous_tree", "\u{1F333}");
-    m.insert("deer", "\u{1F98C}");
-    m.insert("denmark", "\u{1F1E9}\u{1F1F0}");
-    m.insert("department_store", "\u{1F3EC}");
-    m.insert("derelict_house", "\u{1F3DA}\u{FE0F}");
-    m.insert("desert", "\u{1F3DC}\u{FE0F}");
-    m.insert("desert_island", "\u{1F3DD}\u{FE0F}");
-    m.insert("desktop_computer", "\u{1F5A5}\u{FE0F}");
-    m.insert("detective", "\u{1F575}\u{FE0F}");
-    m.insert("diamond_shape_with_a_dot_inside", "\u{1F4A0}");
-    m.insert("diamonds", "\u{2666}\u{FE0F}");
-    m.insert("diego_garcia", "\u{1F1E9}\u{1F1EC}");
-    m.insert("disappointed", "\u{1F61E}");
-    m.insert("disappointed_relieved", "\u{1F625}");
-    m.insert("disguised_face", "\u{1F978}");
-    m.insert("diving_mask", "\u{1F93F}");
-    m.insert("diya_lamp", "\u{1FA94}");
-    m.insert("dizzy", "\u{1F4AB}");
-    m.insert("dizzy_face", "\u{1F635}");
-    m.insert("djibouti", "\u{1F1E9}\u{1F1EF}");
-    m.insert("dna", "\u{1F9EC}");
-    m.insert("do_not_litter", "\u{1F6AF}");
-    m.insert("dodo", "\u{1F9A4}");
-    m.insert("dog", "\u{1F436}");
-    m.insert("dog2", "\u{1F415}");
-    m.insert("dollar", "\u{1F4B5}");
-    m.insert("dolls", "\u{1F38E}");
-    m.insert("dolphin", "\u{1F42C}");
-    m.insert("dominica", "\u{1F1E9}\u{1F1F2}");
-    m.insert("dominican_republic", "\u{1F1E9}\u{1F1F4}");
-    m.insert("donkey", "\u{1FACF}");
-    m.insert("door", "\u{1F6AA}");
-    m.insert("dotted_line_face", "\u{1FAE5}");
-    m.insert("doughnut", "\u{1F369}");
-    m.insert("dove", "\u{1F54A}\u{FE0F}");
-    m.insert("dragon", "\u{1F409}");
-    m.insert("dragon_face", "\u{1F432}");
-    m.insert("dress", "\u{1F457}");
-    m.insert("dromedary_camel", "\u{1F42A}");
-    m.insert("drooling_face", "\u{1F924}");
-    m.insert("drop_of_blood", "\u{1FA78}");
-    m.insert("droplet", "\u{1F4A7}");
-    m.insert("drum", "\u{1F941}");
-    m.insert("duck", "\u{1F986}");
-    m.insert("dumpling", "\u{1F95F}");
-    m.insert("dvd", "\u{1F4C0}");
-    m.insert("e-mail", "\u{1F4E7}");
-    m.insert("eagle", "\u{1F985}");
-    m.insert("ear", "\u{1F442}");
-    m.insert("ear_of_rice", "\u{1F33E}");
-    m.insert("ear_with_hearing_aid", "\u{1F9BB}");
-    m.insert("earth_africa", "\u{1F30D}");
-    m.insert("earth_americas", "\u{1F30E}");
-    m.insert("earth_asia", "\u{1F30F}");
-    m.insert("ecuador", "\u{1F1EA}\u{1F1E8}");
-    m.insert("egg", "\u{1F95A}");
-    m.insert("eggplant", "\u{1F346}");
-    m.insert("egypt", "\u{1F1EA}\u{1F1EC}");
-    m.insert("eight", "\u{0038}\u{FE0F}\u{20E3}");
-    m.insert("eight_pointed_black_star", "\u{2734}\u{FE0F}");
-    m.insert("eight_spoked_asterisk", "\u{2733}\u{FE0F}");
-    m.insert("eject_button", "\u{23CF}\u{FE0F}");
-    m.insert("el_salvador", "\u{1F1F8}\u{1F1FB}");
-    m.insert("electric_plug", "\u{1F50C}");
-    m.insert("elephant", "\u{1F418}");
-    m.insert("elevator", "\u{1F6D7}");
-    m.insert("elf", "\u{1F9DD}");
-    m.insert("elf_man", "\u{1F9DD}\u{2642}\u{FE0F}");
-    m.insert("elf_woman", "\u{1F9DD}\u{2640}\u{FE0F}");
-    m.insert("email", "\u{1F4E7}");
-    m.insert("empty_nest", "\u{1FAB9}");
-    m.insert("end", "\u{1F51A}");
-    m.insert("england", "\u{1F3F4}\u{E0067}\u{E0062}\u{E0065}\u{E006E}\u{E0067}\u{E007F}");
-    m.insert("envelope", "\u{2709}\u{FE0F}");
-    m.insert("envelope_with_arrow", "\u{1F4E9}");
-    m.insert("equatorial_guinea", "\u{1F1EC}\u{1F1F6}");
-    m.insert("eritrea", "\u{1F1EA}\u{1F1F7}");
-    m.insert("es", "\u{1F1EA}\u{1F1F8}");
-    m.insert("estonia", "\u{1F1EA}\u{1F1EA}");
-    m.insert("ethiopia", "\u{1F1EA}\u{1F1F9}");
-    m.insert("eu", "\u{1F1EA}\u{1F1FA}");
-    m.insert("euro", "\u{1F4B6}");
-    m.insert("european_castle", "\u{1F3F0}");
-    m.insert("european_post_office", "\u{1F3E4}");
-    m.insert("european_union", "\u{1F1EA}\u{1F1FA}");
-    m.insert("evergreen_tree", "\u{1F332}");
-    m.insert("exclamation", "\u{2757}");
-    m.insert("exploding_head", "\u{1F92F}");
-    m.insert("expressionless", "\u{1F611}");
-    m.insert("eye", "\u{1F441}\u{FE0F}");
-    m.insert("eye_speech_bubble", "\u{1F441}\u{FE0F}\u{1F5E8}\u{FE0F}");
-    m.insert("eyeglasses", "\u{1F453}");
-    m.insert("eyes", "\u{1F440}");
-    m.insert("face_exhaling", "\u{1F62E}\u{1F4A8}");
-    m.insert("face_holding_back_tears", "\u{1F979}");
-    m.insert("face_in_clouds", "\u{1F636}\u{1F32B}\u{FE0F}");
-    m.insert("face_with_diagonal_mouth", "\u{1FAE4}");
-    m.insert("face_with_head_bandage", "\u{1F915}");
-    m.insert("face_with_open_eyes_and_hand_over_mouth", "\u{1FAE2}");
-    m.insert("face_with_peeking_eye", "\u{1FAE3}");
-    m.insert("face_with_spiral_eyes", "\u{1F635}\u{1F4AB}");
-    m.insert("face_with_thermometer", "\u{1F912}");
-    m.insert("facepalm", "\u{1F926}");
-    m.insert("facepunch", "\u{1F44A}");
-    m.insert("factory", "\u{1F3ED}");
-    m.insert("factory_worker", "\u{1F9D1}\u{1F3ED}");
-    m.insert("fairy", "\u{1F9DA}");
-    m.insert("fairy_man", "\u{1F9DA}\u{2642}\u{FE0F}");
-    m.insert("fairy_woman", "\u{1F9DA}\u{2640}\u{FE0F}");
-    m.insert("falafel", "\u{1F9C6}");
-    m.insert("falkland_islands", "\u{1F1EB}\u{1F1F0}");
-    m.insert("fallen_leaf", "\u{1F342}");
-    m.insert("family", "\u{1F46A}");
-    m.insert("family_man_boy", "\u{1F468}\u{1F466}");
-    m.insert("family_man_boy_boy", "\u{1F468}\u{1F466}\u{1F466}");
-    m.insert("family_man_girl", "\u{1F468}\u{1F467}");
-    m.insert("family_man_girl_boy", "\u{1F468}\u{1F467}\u{1F466}");
-    m.insert("family_man_girl_girl", "\u{1F468}\u{1F467}\u{1F467}");
-    m.insert("family_man_man_boy", "\u{1F468}\u{1F468}\u{1F466}");
-    m.insert("family_man_man_boy_boy", "\u{1F468}\u{1F468}\u{1F466}\u{1F466}");
-    m.insert("family_man_man_girl", "\u{1F468}\u{1F468}\u{1F467}");
-    m.insert("family_man_man_girl_boy", "\u{1F468}\u{1F468}\u{1F467}\u{1F466}");
-    m.insert("family_man_man_girl_girl", "\u{1F468}\u{1F468}\u{1F467}\u{1F467}");
-    m.insert("family_man_woman_boy", "\u{1F468}\u{1F469}\u{1F466}");
-    m.insert("family_man_woman_boy_boy", "\u{1F468}\u{1F469}\u{1F466}\u{1F466}");
-    m.insert("family_man_woman_girl", "\u{1F468}\u{1F469}\u{1F467}");
-    m.insert("family_man_woman_girl_boy", "\u{1F468}\u{1F469}\u{1F467}\u{1F466}");
-    m.insert("family_man_woman_girl_girl", "\u{1F468}\u{1F469}\u{1F467}\u{1F467}");
-    m.insert("family_woman_boy", "\u{1F469}\u{1F466}");
-    m.insert("family_woman_boy_boy", "\u{1F469}\u{1F466}\u{1F466}");
-    m.insert("family_woman_girl", "\u{1F469}\u{1F467}");
-    m.insert("family_woman_girl_boy", "\u{1F469}\u{1F467}\u{1F466}");
-    m.insert("family_woman_girl_girl", "\u{1F469}\u{1F467}\u{1F467}");
-    m.insert("family_woman_woman_boy", "\u{1F469}\u{1F469}\u{1F466}");
-    m.insert("family_woman_woman_boy_boy", "\u{1F469}\u{1F469}\u{1F466}\u{1F466}");
-    m.insert("family_woman_woman_girl", "\u{1F469}\u{1F469}\u{1F467}");
-    m.insert("family_woman_woman_girl_boy", "\u{1F469}\u{1F469}\u{1F467}\u{1F466}");
-    m.insert("family_woman_woman_girl_girl", "\u{1F469}\u{1F469}\u{1F467}\u{1F467}");
-    m.insert("farmer", "\u{1F9D1}\u{1F33E}");
-    m.insert("faroe_islands", "\u{1F1EB}\u{1F1F4}");
-    m.insert("fast_forward", "\u{23E9}");
-    m.insert("fax", "\u{1F4E0}");
-    m.insert("fearful", "\u{1F628}");
-    m.insert("feather", "\u{1FAB6}");
-    m.insert("feet", "\u{1F43E}");
-    m.insert("female_detective", "\u{1F575}\u{FE0F}\u{2640}\u{FE0F}");
-    m.insert("female_sign", "\u{2640}\u{FE0F}");
-    m.insert("ferris_wheel", "\u{1F3A1}");
-    m.insert("ferry", "\u{26F4}\u{FE0F}");
-    m.insert("field_hockey", "\u{1F3D1}");
-    m.insert("fiji", "\u{1F1EB}\u{1F1EF}");
-    m.insert("file_cabinet", "\u{1F5C4}\u{FE0F}");
-    m.insert("file_folder", "\u{1F4C1}");
-    m.insert("film_projector", "\u{1F4FD}\u{FE0F}");
-    m.insert("film_strip", "\u{1F39E}\u{FE0F}");
-    m.insert("finland", "\u{1F1EB}\u{1F1EE}");
-    m.insert("fire", "\u{1F525}");
-    m.insert("fire_engine", "\u{1F692}");
-    m.insert("fire_extinguisher", "\u{1F9EF}");
-    m.insert("firecracker", "\u{1F9E8}");
-    m.insert("firefighter", "\u{1F9D1}\u{1F692}");
-    m.insert("fireworks", "\u{1F386}");
-    m.insert("first_quarter_moon", "\u{1F313}");
-    m.insert("first_quarter_moon_with_face", "\u{1F31B}");
-    m.insert("fish", "\u{1F41F}");
-    m.insert("fish_cake", "\u{1F365}");
-    m.insert("fishing_pole_and_fish", "\u{1F3A3}");
-    m.insert("fist", "\u{270A}");
-    m.insert("fist_left", "\u{1F91B}");
-    m.insert("fist_oncoming", "\u{1F44A}");
-    m.insert("fist_raised", "\u{270A}");
-    m.insert("fist_right", "\u{1F91C}");
-    m.insert("five", "\u{0035}\u{FE0F}\u{20E3}");
-    m.insert("flags", "\u{1F38F}");
-    m.insert("flamingo", "\u{1F9A9}");
-    m.insert("flashlight", "\u{1F526}");
-    m.insert("flat_shoe", "\u{1F97F}");
-    m.insert("flatbread", "\u{1FAD3}");
-    m.insert("fleur_de_lis", "\u{269C}\u{FE0F}");
-    m.insert("flight_arrival", "\u{1F6EC}");
-    m.insert("flight_departure", "\u{1F6EB}");
-    m.insert("flipper", "\u{1F42C}");
-    m.insert("floppy_disk", "\u{1F4BE}");
-    m.insert("flower_playing_cards", "\u{1F3B4}");
-    m.insert("flushed", "\u{1F633}");
-    m.insert("flute", "\u{1FA88}");
-    m.insert("fly", "\u{1FAB0}");
-    m.insert("flying_disc", "\u{1F94F}");
-    m.insert("flying_saucer", "\u{1F6F8}");
-    m.insert("fog", "\u{1F32B}\u{FE0F}");
-    m.insert("foggy", "\u{1F301}");
-    m.insert("folding_hand_fan", "\u{1FAAD}");
-    m.insert("fondue", "\u{1FAD5}");
-    m.insert("foot", "\u{1F9B6}");
-    m.insert("football", "\u{1F3C8}");
-    m.insert("footprints", "\u{1F463}");
-    m.insert("fork_and_knife", "\u{1F374}");
-    m.insert("fortune_cookie", "\u{1F960}");
-    m.insert("fountain", "\u{26F2}");
-    m.insert("fountain_pen", "\u{1F58B}\u{FE0F}");
-    m.insert("four", "\u{0034}\u{FE0F}\u{20E3}");
-    m.insert("four_leaf_clover", "\u{1F340}");
-    m.insert("fox_face", "\u{1F98A}");
-    m.insert("fr", "\u{1F1EB}\u{1F1F7}");
-    m.insert("framed_picture", "\u{1F5BC}\u{FE0F}");
-    m.insert("free", "\u{1F193}");
-    m.insert("french_guiana", "\u{1F1EC}\u{1F1EB}");
-    m.insert("french_polynesia", "\u{1F1F5}\u{1F1EB}");
-    m.insert("french_southern_territories", "\u{1F1F9}\u{1F1EB}");
-    m.insert("fried_egg", "\u{1F373}");
-    m.insert("fried_shrimp", "\u{1F364}");
-    m.insert("fries", "\u{1F35F}");
-    m.insert("frog", "\u{1F438}");
-    m.insert("frowning", "\u{1F626}");
-    m.insert("frowning_face", "\u{2639}\u{FE0F}");
-    m.insert("frowning_man", "\u{1F64D}\u{2642}\u{FE0F}");
-    m.insert("frowning_person", "\u{1F64D}");
-    m.insert("frowning_woman", "\u{1F64D}\u{2640}\u{FE0F}");
-    m.insert("fu", "\u{1F595}");
-    m.insert("fuelpump", "\u{26FD}");
-    m.insert("full_moon", "\u{1F315}");
-    m.insert("full_moon_with_face", "\u{1F31D}");
-    m.insert("funeral_urn", "\u{26B1}\u{FE0F}");
-    m.insert("gabon", "\u{1F1EC}\u{1F1E6}");
-    m.insert("gambia", "\u{1F1EC}\u{1F1F2}");
-    m.insert("game_die", "\u{1F3B2}");
-    m.insert("garlic", "\u{1F9C4}");
-    m.insert("gb", "\u{1F1EC}\u{1F1E7}");
-    m.insert("gear", "\u{2699}\u{FE0F}");
-    m.insert("gem", "\u{1F48E}");
-    m.insert("gemini", "\u{264A}");
-    m.insert("genie", "\u{1F9DE}");
-    m.insert("genie_man", "\u{1F9DE}\u{2642}\u{FE0F}");
-    m.insert("genie_woman", "\u{1F9DE}\u{2640}\u{FE0F}");
-    m.insert("georgia", "\u{1F1EC}\u{1F1EA}");
-    m.insert("ghana", "\u{1F1EC}\u{1F1ED}");
-    m.insert("ghost", "\u{1F47B}");
-    m.insert("gibraltar", "\u{1F1EC}\u{1F1EE}");
-    m.insert("gift", "\u{1F381}");
-    m.insert("gift_heart", "\u{1F49D}");
-    m.insert("ginger_root", "\u{1FADA}");
-    m.insert("giraffe", "\u{1F992}");
-    m.insert("girl", "\u{1F467}");
-    m.insert("globe_with_meridians", "\u{1F310}");
-    m.insert("gloves", "\u{1F9E4}");
-    m.insert("goal_net", "\u{1F945}");
-    m.insert("goat", "\u{1F410}");
-    m.insert("goggles", "\u{1F97D}");
-    m.insert("golf", "\u{26F3}");
-    m.insert("golfing", "\u{1F3CC}\u{FE0F}");
-    m.insert("golfing_man", "\u{1F3CC}\u{FE0F}\u{2642}\u{FE0F}");
-    m.insert("golfing_woman", "\u{1F3CC}\u{FE0F}\u{2640}\u{FE0F}");
-    m.insert("goose", "\u{1FABF}");
-    m.insert("gorilla", "\u{1F98D}");
-    m.insert("grapes", "\u{1F347}");
-    m.insert("greece", "\u{1F1EC}\u{1F1F7}");
-    m.insert("green_apple", "\u{1F34F}");
-    m.insert("green_book", "\u{1F4D7}");
-    m.insert("green_circle", "\u{1F7E2}");
-    m.insert("green_heart", "\u{1F49A}");
-    m.insert("green_salad", "\u{1F957}");
-    m.insert("green_square", "\u{1F7E9}");
-    m.insert("greenland", "\u{1F1EC}\u{1F1F1}");
-    m.insert("grenada", "\u{1F1EC}\u{1F1E9}");
-    m.insert("grey_exclamation", "\u{2755}");
-    m.insert("grey_heart", "\u{1FA76}");
-    m.insert("grey_question", "\u{2754}");
-    m.insert("grimacing", "\u{1F62C}");
-    m.insert("grin", "\u{1F601}");
-    m.insert("grinning", "\u{1F600}");
-    m.insert("guadeloupe", "\u{1F1EC}\u{1F1F5}");
-    m.insert("guam", "\u{1F1EC}\u{1F1FA}");
-    m.insert("guard", "\u{1F482}");
-    m.insert("guardsman", "\u{1F482}\u{2642}\u{FE0F}");
-    m.insert("guardswoman", "\u{1F482}\u{2640}\u{FE0F}");
-    m.insert("guatemala", "\u{1F1EC}\u{1F1F9}");
-    m.insert("guernsey", "\u{1F1EC}\u{1F1EC}");
-    m.insert("guide_dog", "\u{1F9AE}");
-    m.insert("guinea", "\u{1F1EC}\u{1F1F3}");
-    m.insert("guinea_bissau", "\u{1F1EC}\u{1F1FC}");
-    m.insert("guitar", "\u{1F3B8}");
-    m.insert("gun", "\u{1F52B}");
-    m.insert("guyana", "\u{1F1EC}\u{1F1FE}");
-    m.insert("hair_pick", "\u{1FAAE}");
-    m.insert("haircut", "\u{1F487}");
-    m.insert("haircut_man", "\u{1F487}\u{2642}\u{FE0F}");
-    m.insert("haircut_woman", "\u{1F487}\u{2640}\u{FE0F}");
-    m.insert("haiti", "\u{1F1ED}\u{1F1F9}");
-    m.insert("hamburger", "\u{1F354}");
-    m.insert("hammer", "\u{1F528}");
-    m.insert("hammer_and_pick", "\u{2692}\u{FE0F}");
-    m.insert("hammer_and_wrench", "\u{1F6E0}\u{FE0F}");
-    m.insert("hamsa", "\u{1FAAC}");
-    m.insert("hamster", "\u{1F439}");
-    m.insert("hand", "\u{270B}");
-    m.insert("hand_over_mouth", "\u{1F92D}");
-    m.insert("hand_with_index_finger_and_thumb_crossed", "\u{1FAF0}");
-    m.insert("handbag", "\u{1F45C}");
-    m.insert("handball_person", "\u{1F93E}");
-    m.insert("handshake", "\u{1F91D}");
-    m.insert("hankey", "\u{1F4A9}");
-    m.insert("hash", "\u{0023}\u{FE0F}\u{20E3}");
-    m.insert("hatched_chick", "\u{1F425}");
-    m.insert("hatching_chick", "\u{1F423}");
-    m.insert("headphones", "\u{1F3A7}");
-    m.insert("headstone", "\u{1FAA6}");
-    m.insert("health_worker", "\u{1F9D1}\u{2695}\u{FE0F}");
-    m.insert("hear_no_evil", "\u{1F649}");
-    m.insert("heard_mcdonald_islands", "\u{1F1ED}\u{1F1F2}");
-    m.insert("heart", "\u{2764}\u{FE0F}");
-    m.insert("heart_decoration", "\u{1F49F}");
-    m.insert("heart_eyes", "\u{1F60D}");
-    m.insert("heart_eyes_cat", "\u{1F63B}");
-    m.insert("heart_hands", "\u{1FAF6}");
-    m.insert("heart_on_fire", "\u{2764}\u{FE0F}\u{1F525}");
-    m.insert("heartbeat", "\u{1F493}");
-    m.insert("heartpulse", "\u{1F497}");
-    m.insert("hearts", "\u{2665}\u{FE0F}");
-    m.insert("heavy_check_mark", "\u{2714}\u{FE0F}");
-    m.insert("heavy_division_sign", "\u{2797}");
-    m.insert("heavy_dollar_sign", "\u{1F4B2}");
-    m.insert("heavy_equals_sign", "\u{1F7F0}");
-    m.insert("heavy_exclamation_mark", "\u{2757}");
-    m.insert("heavy_heart_exclamation", "\u{2763}\u{FE0F}");
-    m.insert("heavy_minus_sign", "\u{2796}");
-    m.insert("heavy_multiplication_x", "\u{2716}\u{FE0F}");
-    m.insert("heavy_plus_sign", "\u{2795}");
-    m.insert("hedgehog", "\u{1F994}");
-    m.insert("helicopter", "\u{1F681}");
-    m.insert("herb", "\u{1F33F}");
-    m.insert("hibiscus", "\u{1F33A}");
-    m.insert("high_brightness", "\u{1F506}");
-    m.insert("high_heel", "\u{1F460}");
-    m.insert("hiking_boot", "\u{1F97E}");
-    m.insert("hindu_temple", "\u{1F6D5}");
-    m.insert("hippopotamus", "\u{1F99B}");
-    m.insert("hocho", "\u{1F52A}");
-    m.insert("hole", "\u{1F573}\u{FE0F}");
-    m.insert("honduras", "\u{1F1ED}\u{1F1F3}");
-    m.insert("honey_pot", "\u{1F36F}");
-    m.insert("honeybee", "\u{1F41D}");
-    m.insert("hong_kong", "\u{1F1ED}\u{1F1F0}");
-    m.insert("hook", "\u{1FA9D}");
-    m.insert("horse", "\u{1F434}");
-    m.insert("horse_racing", "\u{1F3C7}");
-    m.insert("hospital", "\u{1F3E5}");
-    m.insert("hot_face", "\u{1F975}");
-    m.insert("hot_pepper", "\u{1F336}\u{FE0F}");
-    m.insert("hotdog", "\u{1F32D}");
-    m.insert("hotel", "\u{1F3E8}");
-    m.insert("hotsprings", "\u{2668}\u{FE0F}");
-    m.insert("hourglass", "\u{231B}");
-    m.insert("hourglass_flowing_sand", "\u{23F3}");
-    m.insert("house", "\u{1F3E0}");
-    m.insert("house_with_garden", "\u{1F3E1}");
-    m.insert("houses", "\u{1F3D8}\u{FE0F}");
-    m.insert("hugs", "\u{1F917}");
-    m.insert("hungary", "\u{1F1ED}\u{1F1FA}");
-    m.insert("hushed", "\u{1F62F}");
-    m.insert("hut", "\u{1F6D6}");
-    m.insert("hyacinth", "\u{1FABB}");
-    m.insert("ice_cream", "\u{1F368}");
-    m.insert("ice_cube", "\u{1F9CA}");
-    m.insert("ice_hockey", "\u{1F3D2}");
-    m.insert("ice_skate", "\u{26F8}\u{FE0F}");
-    m.insert("icecream", "\u{1F366}");
-    m.insert("iceland", "\u{1F1EE}\u{1F1F8}");
-    m.insert("id", "\u{1F194}");
-    m.insert("identification_card", "\u{1FAAA}");
-    m.insert("ideograph_advantage", "\u{1F250}");
-    m.insert("imp", "\u{1F47F}");
-    m.insert("inbox_tray", "\u{1F4E5}");
-    m.insert("incoming_envelope", "\u{1F4E8}");
-    m.insert("index_pointing_at_the_viewer", "\u{1FAF5}");
-    m.insert("india", "\u{1F1EE}\u{1F1F3}");
-    m.insert("indonesia", "\u{1F1EE}\u{1F1E9}");
-    m.insert("infinity", "\u{267E}\u{FE0F}");
-    m.insert("information_desk_person", "\u{1F481}");
-    m.insert("information_source", "\u{2139}\u{FE0F}");
-    m.insert("innocent", "\u{1F607}");
-    m.insert("interrobang", "\u{2049}\u{FE0F}");
-    m.insert("iphone", "\u{1F4F1}");
-    m.insert("iran", "\u{1F1EE}\u{1F1F7}");
-    m.insert("iraq", "\u{1F1EE}\u{1F1F6}");
-    m.insert("ireland", "\u{1F1EE}\u{1F1EA}");
-    m.insert("isle_of_man", "\u{1F1EE}\u{1F1F2}");
-    m.insert("israel", "\u{1F1EE}\u{1F1F1}");
-    m.insert("it", "\u{1F1EE}\u{1F1F9}");
-    m.insert("izakaya_lantern", "\u{1F3EE}");
-    m.insert("jack_o_lantern", "\u{1F383}");
-    m.insert("jamaica", "\u{1F1EF}\u{1F1F2}");
-    m.insert("japan", "\u{1F5FE}");
-    m.insert("japanese_castle", "\u{1F3EF}");
-    m.insert("japanese_goblin", "\u{1F47A}");
-    m.insert("japanese_ogre", "\u{1F479}");
-    m.insert("jar", "\u{1FAD9}");
-    m.insert("jeans", "\u{1F456}");
-    m.insert("jellyfish", "\u{1FABC}");
-    m.insert("jersey", "\u{1F1EF}\u{1F1EA}");
-    m.insert("jigsaw", "\u{1F9E9}");
-    m.insert("jordan", "\u{1F1EF}\u{1F1F4}");
-    m.insert("joy", "\u{1F602}");
-    m.insert("joy_cat", "\u{1F639}");
-    m.insert("joystick", "\u{1F579}\u{FE0F}");
-    m.insert("jp", "\u{1F1EF}\u{1F1F5}");
-    m.insert("judge", "\u{1F9D1}\u{2696}\u{FE0F}");
-    m.insert("juggling_person", "\u{1F939}");
-    m.insert("kaaba", "\u{1F54B}");
-    m.insert("kangaroo", "\u{1F998}");
-    m.insert("kazakhstan", "\u{1F1F0}\u{1F1FF}");
-    m.insert("kenya", "\u{1F1F0}\u{1F1EA}");
-    m.insert("key", "\u{1F511}");
-    m.insert("keyboard", "\u{2328}\u{FE0F}");
-    m.insert("keycap_ten", "\u{1F51F}");
-    m.insert("khanda", "\u{1FAAF}");
-    m.insert("kick_scooter", "\u{1F6F4}");
-    m.insert("kimono", "\u{1F458}");
-    m.insert("kiribati", "\u{1F1F0}\u{1F1EE}");
-    m.insert("kiss", "\u{1F48B}");
-    m.insert("kissing", "\u{1F617}");
-    m.insert("kissing_cat", "\u{1F63D}");
-    m.insert("kissing_closed_eyes", "\u{1F61A}");
-    m.insert("kissing_heart", "\u{1F618}");
-    m.insert("kissing_smiling_eyes", "\u{1F619}");
-    m.insert("kite", "\u{1FA81}");
-    m.insert("kiwi_fruit", "\u{1F95D}");
-    m.insert("kneeling_man", "\u{1F9CE}\u{2642}\u{FE0F}");
-    m.insert("kneeling_person", "\u{1F9CE}");
-    m.insert("kneeling_woman", "\u{1F9CE}\u{2640}\u{FE0F}");
-    m.insert("knife", "\u{1F52A}");
-    m.insert("knot", "\u{1FAA2}");
-    m.insert("koala", "\u{1F428}");
-    m.insert("koko", "\u{1F201}");
-    m.insert("kosovo", "\u{1F1FD}\u{1F1F0}");
-    m.insert("kr", "\u{1F1F0}\u{1F1F7}");
-    m.insert("kuwait", "\u{1F1F0}\u{1F1FC}");
-    m.insert("kyrgyzstan", "\u{1F1F0}\u{1F1EC}");
-    m.insert("lab_coat", "\u{1F97C}");
-    m.insert("label", "\u{1F3F7}\u{FE0F}");
-    m.insert("lacrosse", "\u{1F94D}");
-    m.insert("ladder", "\u{1FA9C}");
-    m.insert("lady_beetle", "\u{1F41E}");
-    m.insert("lantern", "\u{1F3EE}");
-    m.insert("laos", "\u{1F1F1}\u{1F1E6}");
-    m.insert("large_blue_circle", "\u{1F535}");
-    m.insert("large_blue_diamond", "\u{1F537}");
-    m.insert("large_orange_diamond", "\u{1F536}");
-    m.insert("last_quarter_moon", "\u{1F317}");
-    m.insert("last_quarter_moon_with_face", "\u{1F31C}");
-    m.insert("latin_cross", "\u{271D}\u{FE0F}");
-    m.insert("latvia", "\u{1F1F1}\u{1F1FB}");
-    m.insert("laughing", "\u{1F606}");
-    m.insert("leafy_green", "\u{1F96C}");
-    m.insert("leaves", "\u{1F343}");
-    m.insert("lebanon", "\u{1F1F1}\u{1F1E7}");
-    m.insert("ledger", "\u{1F4D2}");
-    m.insert("left_luggage", "\u{1F6C5}");
-    m.insert("left_right_arrow", "\u{2194}\u{FE0F}");
-    m.insert("left_speech_bubble", "\u{1F5E8}\u{FE0F}");
-    m.insert("leftwards_arrow_with_hook", "\u{21A9}\u{FE0F}");
-    m.insert("leftwards_hand", "\u{1FAF2}");
-    m.insert("leftwards_pushing_hand", "\u{1FAF7}");
-    m.insert("leg", "\u{1F9B5}");
-    m.insert("lemon", "\u{1F34B}");
-    m.insert("leo", "\u{264C}");
-    m.insert("leopard", "\u{1F406}");
-    m.insert("lesotho", "\u{1F1F1}\u{1F1F8}");
-    m.insert("level_slider", "\u{1F39A}\u{FE0F}");
-    m.insert("liberia", "\u{1F1F1}\u{1F1F7}");
-    m.insert("libra", "\u{264E}");
-    m.insert("libya", "\u{1F1F1}\u{1F1FE}");
-    m.insert("liechtenstein", "\u{1F1F1}\u{1F1EE}");
-    m.insert("light_blue_heart", "\u{1FA75}");
-    m.insert("light_rail", "\u{1F688}");
-    m.insert("link", "\u{1F517}");
-    m.insert("lion", "\u{1F981}");
-    m.insert("lips", "\u{1F444}");
-    m.insert("lipstick", "\u{1F484}");
-    m.insert("lithuania", "\u{1F1F1}\u{1F1F9}");
-    m.insert("lizard", "\u{1F98E}");
-    m.insert("llama", "\u{1F999}");
-    m.insert("lobster", "\u{1F99E}");
-    m.insert("lock", "\u{1F512}");
-    m.insert("lock_with_ink_pen", "\u{1F50F}");
-    m.insert("lollipop", "\u{1F36D}");
-    m.insert("long_drum", "\u{1FA98}");
-    m.insert("loop", "\u{27BF}");
-    m.insert("lotion_bottle", "\u{1F9F4}");
-    m.insert("lotus", "\u{1FAB7}");
-    m.insert("lotus_position", "\u{1F9D8}");
-    m.insert("lotus_position_man", "\u{1F9D8}\u{2642}\u{FE0F}");
-    m.insert("lotus_position_woman", "\u{1F9D8}\u{2640}\u{FE0F}");
-    m.insert("loud_sound", "\u{1F50A}");
-    m.insert("loudspeaker", "\u{1F4E2}");
-    m.insert("love_hotel", "\u{1F3E9}");
-    m.insert("love_letter", "\u{1F48C}");
-    m.insert("love_you_gesture", "\u{1F91F}");
-    m.insert("low_battery", "\u{1FAAB}");
-    m.insert("low_brightness", "\u{1F505}");
-    m.insert("luggage", "\u{1F9F3}");
-    m.insert("lungs", "\u{1FAC1}");
-    m.insert("luxembourg", "\u{1F1F1}\u{1F1FA}");
-    m.insert("lying_face", "\u{1F925}");
-    m.insert("m", "\u{24C2}\u{FE0F}");
-    m.insert("macau", "\u{1F1F2}\u{1F1F4}");
-    m.insert("macedonia", "\u{1F1F2}\u{1F1F0}");
-    m.insert("madagascar", "\u{1F1F2}\u{1F1EC}");
-    m.insert("mag", "\u{1F50D}");
-    m.insert("mag_right", "\u{1F50E}");
-    m.insert("mage", "\u{1F9D9}");
-    m.insert("mage_man", "\u{1F9D9}\u{2642}\u{FE0F}");
-    m.insert("mage_woman", "\u{1F9D9}\u{2640}\u{FE0F}");
-    m.insert("magic_wand", "\u{1FA84}");
-    m.insert("magnet", "\u{1F9F2}");
-    m.insert("mahjong", "\u{1F004}");
-    m.insert("mailbox", "\u{1F4EB}");
-    m.insert("mailbox_closed", "\u{1F4EA}");
-    m.insert("mailbox_with_mail", "\u{1F4EC}");
-    m.insert("mailbox_with_no_mail", "\u{1F4ED}");
-    m.insert("malawi", "\u{1F1F2}\u{1F1FC}");
-    m.insert("malaysia", "\u{1F1F2}\u{1F1FE}");
-    m.insert("maldives", "\u{1F1F2}\u{1F1FB}");
-    m.insert("male_detective", "\u{1F575}\u{FE0F}\u{2642}\u{FE0F}");
-    m.insert("male_sign", "\u{2642}\u{FE0F}");
-    m.insert("mali", "\u{1F1F2}\u{1F1F1}");
-    m.insert("malta", "\u{1F1F2}\u{1F1F9}");
-    m.insert("mammoth", "\u{1F9A3}");
-    m.insert("man", "\u{1F468}");
-    m.insert("man_artist", "\u{1F468}\u{1F3A8}");
-    m.insert("man_astronaut", "\u{1F468}\u{1F680}");
-    m.insert("man_beard", "\u{1F9D4}\u{2642}\u{FE0F}");
-    m.insert("man_cartwheeling", "\u{1F938}\u{2642}\u{FE0F}");
-    m.insert("man_cook", "\u{1F468}\u{1F373}");
-    m.insert("man_dancing", "\u{1F57A}");
-    m.insert("man_facepalming", "\u{1F926}\u{2642}\u{FE0F}");
-    m.insert("man_factory_worker", "\u{1F468}\u{1F3ED}");
-    m.insert("man_farmer", "\u{1F468}\u{1F33E}");
-    m.insert("man_feeding_baby", "\u{1F468}\u{1F37C}");
-    m.insert("man_firefighter", "\u{1F468}\u{1F692}");
-    m.insert("man_health_worker", "\u{1F468}\u{2695}\u{FE0F}");
-    m.insert("man_in_manual_wheelchair", "\u{1F468}\u{1F9BD}");
-    m.insert("man_in_motorized_wheelchair", "\u{1F468}\u{1F9BC}");
-    m.insert("man_in_tuxedo", "\u{1F935}\u{2642}\u{FE0F}");
-    m.insert("man_judge", "\u{1F468}\u{2696}\u{FE0F}");
-    m.insert("man_juggling", "\u{1F939}\u{2642}\u{FE0F}");
-    m.insert("man_mechanic", "\u{1F468}\u{1F527}");
-    m.insert("man_office_worker", "\u{1F468}\u{1F4BC}");
-    m.insert("man_pilot", "\u{1F468}\u{2708}\u{FE0F}");
-    m.insert("man_playing_handball", "\u{1F93E}\u{2642}\u{FE0F}");
-    m.insert("man_playing_water_polo", "\u{1F93D}\u{2642}\u{FE0F}");
-    m.insert("man_scientist", "\u{1F468}\u{1F52C}");
-    m.insert("man_shrugging", "\u{1F937}\u{2642}\u{FE0F}");
-    m.insert("man_singer", "\u{1F468}\u{1F3A4}");
-    m.insert("man_student", "\u{1F468}\u{1F393}");
-    m.insert("man_teacher", "\u{1F468}\u{1F3EB}");
-    m.insert("man_technologist", "\u{1F468}\u{1F4BB}");
-    m.insert("man_with_gua_pi_mao", "\u{1F472}");
-    m.insert("man_with_probing_cane", "\u{1F468}\u{1F9AF}");
-    m.insert("man_with_turban", "\u{1F473}\u{2642}\u{FE0F}");
-    m.insert("man_with_veil", "\u{1F470}\u{2642}\u{FE0F}");
-    m.insert("mandarin", "\u{1F34A}");
-    m.insert("mango", "\u{1F96D}");
-    m.insert("mans_shoe", "\u{1F45E}");
-    m.insert("mantelpiece_clock", "\u{1F570}\u{FE0F}");
-    m.insert("manual_wheelchair", "\u{1F9BD}");
-    m.insert("maple_leaf", "\u{1F341}");
-    m.insert("maracas", "\u{1FA87}");
-    m.insert("marshall_islands", "\u{1F1F2}\u{1F1ED}");
-    m.insert("martial_arts_uniform", "\u{1F94B}");
-    m.insert("martinique", "\u{1F1F2}\u{1F1F6}");
-    m.insert("mask", "\u{1F637}");
-    m.insert("massage", "\u{1F486}");
-    m.insert("massage_man", "\u{1F486}\u{2642}\u{FE0F}");
-    m.insert("massage_woman", "\u{1F486}\u{2640}\u{FE0F}");
-    m.insert("mate", "\u{1F9C9}");
-    m.insert("mauritania", "\u{1F1F2}\u{1F1F7}");
-    m.insert("mauritius", "\u{1F1F2}\u{1F1FA}");
-    m.insert("mayotte", "\u{1F1FE}\u{1F1F9}");
-    m.insert("meat_on_bone", "\u{1F356}");
-    m.insert("mechanic", "\u{1F9D1}\u{1F527}");
-    m.insert("mechanical_arm", "\u{1F9BE}");
-    m.insert("mechanical_leg", "\u{1F9BF}");
-    m.insert("medal_military", "\u{1F396}\u{FE0F}");
-    m.insert("medal_sports", "\u{1F3C5}");
-    m.insert("medical_symbol", "\u{2695}\u{FE0F}");
-    m.insert("mega", "\u{1F4E3}");
-    m.insert("melon", "\u{1F348}");
-    m.insert("melting_face", "\u{1FAE0}");
-    m.insert("memo", "\u{1F4DD}");
-    m.insert("men_wrestling", "\u{1F93C}\u{2642}\u{FE0F}");
-    m.insert("mending_heart", "\u{2764}\u{FE0F}\u{1FA79}");
-    m.insert("menorah", "\u{1F54E}");
-    m.insert("mens", "\u{1F6B9}");
-    m.insert("mermaid", "\u{1F9DC}\u{2640}\u{FE0F}");
-    m.insert("merman", "\u{1F9DC}\u{2642}\u{FE0F}");
-    m.insert("merperson", "\u{1F9DC}");
-    m.insert("metal", "\u{1F918}");
-    m.insert("metro", "\u{1F687}");
-    m.insert("mexico", "\u{1F1F2}\u{1F1FD}");
-    m.insert("microbe", "\u{1F9A0}");
-    m.insert("micronesia", "\u{1F1EB}\u{1F1F2}");
-    m.insert("microphone", "\u{1F3A4}");
-    m.insert("microscope", "\u{1F52C}");
-    m.insert("middle_finger", "\u{1F595}");
-    m.insert("military_helmet", "\u{1FA96}");
-    m.insert("milk_glass", "\u{1F95B}");
-    m.insert("milky_way", "\u{1F30C}");
-    m.insert("minibus", "\u{1F690}");
-    m.insert("minidisc", "\u{1F4BD}");
-    m.insert("mirror", "\u{1FA9E}");
-    m.insert("mirror_ball", "\u{1FAA9}");
-    m.insert("mobile_phone_off", "\u{1F4F4}");
-    m.insert("moldova", "\u{1F1F2}\u{1F1E9}");
-    m.insert("monaco", "\u{1F1F2}\u{1F1E8}");
-    m.insert("money_mouth_face", "\u{1F911}");
-    m.insert("money_with_wings", "\u{1F4B8}");
-    m.insert("moneybag", "\u{1F4B0}");
-    m.insert("mongolia", "\u{1F1F2}\u{1F1F3}");
-    m.insert("monkey", "\u{1F412}");
-    m.insert("monkey_face", "\u{1F435}");
-    m.insert("monocle_face", "\u{1F9D0}");
-    m.insert("monorail", "\u{1F69D}");
-    m.insert("montenegro", "\u{1F1F2}\u{1F1EA}");
-    m.insert("montserrat", "\u{1F1F2}\u{1F1F8}");
-    m.insert("moon", "\u{1F314}");
-    m.insert("moon_cake", "\u{1F96E}");
-    m.insert("moose", "\u{1FACE}");
-    m.insert("morocco", "\u{1F1F2}\u{1F1E6}");
-    m.insert("mortar_board", "\u{1F393}");
-    m.insert("mosque", "\u{1F54C}");
-    m.insert("mosquito", "\u{1F99F}");
-    m.insert("motor_boat", "\u{1F6E5}\u{FE0F}");
-    m.insert("motor_scooter", "\u{1F6F5}");
-    m.insert("motorcycle", "\u{1F3CD}\u{FE0F}");
-    m.insert("motorized_wheelchair", "\u{1F9BC}");
-    m.insert("motorway", "\u{1F6E3}\u{FE0F}");
-    m.insert("mount_fuji", "\u{1F5FB}");
-    m.insert("mountain", "\u{26F0}\u{FE0F}");
-    m.insert("mountain_bicyclist", "\u{1F6B5}");
-    m.insert("mountain_biking_man", "\u{1F6B5}\u{2642}\u{FE0F}");
-    m.insert("mountain_biking_woman", "\u{1F6B5}\u{2640}\u{FE0F}");
-    m.insert("mountain_cableway", "\u{1F6A0}");
-    m.insert("mountain_railway", "\u{1F69E}");
-    m.insert("mountain_snow", "\u{1F3D4}\u{FE0F}");
-    m.insert("mouse", "\u{1F42D}");
-    m.insert("mouse2", "\u{1F401}");
-    m.insert("mouse_trap", "\u{1FAA4}");
-    m.insert("movie_camera", "\u{1F3A5}");
-    m.insert("moyai", "\u{1F5FF}");
-    m.insert("mozambique", "\u{1F1F2}\u{1F1FF}");
-    m.insert("mrs_claus", "\u{1F936}");
-    m.insert("muscle", "\u{1F4AA}");
-    m.insert("mushroom", "\u{1F344}");
-    m.insert("musical_keyboard", "\u{1F3B9}");
-    m.insert("musical_note", "\u{1F3B5}");
-    m.insert("musical_score", "\u{1F3BC}");
-    m.insert("mute", "\u{1F507}");
-    m.insert("mx_claus", "\u{1F9D1}\u{1F384}");
-    m.insert("myanmar", "\u{1F1F2}\u{1F1F2}");
-    m.insert("nail_care", "\u{1F485}");
-    m.insert("name_badge", "\u{1F4DB}");
-    m.insert("namibia", "\u{1F1F3}\u{1F1E6}");
-    m.insert("national_park", "\u{1F3DE}\u{FE0F}");
-    m.insert("nauru", "\u{1F1F3}\u{1F1F7}");
-    m.insert("nauseated_face", "\u{1F922}");
-    m.insert("nazar_amulet", "\u{1F9FF}");
-    m.insert("necktie", "\u{1F454}");
-    m.insert("negative_squared_cross_mark", "\u{274E}");
-    m.insert("nepal", "\u{1F1F3}\u{1F1F5}");
-    m.insert("nerd_face", "\u{1F913}");
-    m.insert("nest_with_eggs", "\u{1FABA}");
-    m.insert("nesting_dolls", "\u{1FA86}");
-    m.insert("netherlands", "\u{1F1F3}\u{1F1F1}");
-    m.insert("neutral_face", "\u{1F610}");
-    m.insert("new", "\u{1F195}");
-    m.insert("new_caledonia", "\u{1F1F3}\u{1F1E8}");
-    m.insert("new_moon", "\u{1F311}");
-    m.insert("new_moon_with_face", "\u{1F31A}");
-    m.insert("new_zealand", "\u{1F1F3}\u{1F1FF}");
-    m.insert("newspaper", "\u{1F4F0}");
-    m.insert("newspaper_roll", "\u{1F5DE}\u{FE0F}");
-    m.insert("next_track_button", "\u{23ED}\u{FE0F}");
-    m.insert("ng", "\u{1F196}");
-    m.insert("ng_man", "\u{1F645}\u{2642}\u{FE0F}");
-    m.insert("ng_woman", "\u{1F645}\u{2640}\u{FE0F}");
-    m.insert("nicaragua", "\u{1F1F3}\u{1F1EE}");
-    m.insert("niger", "\u{1F1F3}\u{1F1EA}");
-    m.insert("nigeria", "\u{1F1F3}\u{1F1EC}");
-    m.insert("night_with_stars", "\u{1F303}");
-    m.insert("nine", "\u{0039}\u{FE0F}\u{20E3}");
-    m.insert("ninja", "\u{1F977}");
-    m.insert("niue", "\u{1F1F3}\u{1F1FA}");
-    m.insert("no_bell", "\u{1F515}");
-    m.insert("no_bicycles", "\u{1F6B3}");
-    m.insert("no_entry", "\u{26D4}");
-    m.insert("no_entry_sign", "\u{1F6AB}");
-    m.insert("no_good", "\u{1F645}");
-    m.insert("no_good_man", "\u{1F645}\u{2642}\u{FE0F}");
-    m.insert("no_good_woman", "\u{1F645}\u{2640}\u{FE0F}");
-    m.insert("no_mobile_phones", "\u{1F4F5}");
-    m.insert("no_mouth", "\u{1F636}");
-    m.insert("no_pedestrians", "\u{1F6B7}");
-    m.insert("no_smoking", "\u{1F6AD}");
-    m.insert("non-potable_water", "\u{1F6B1}");
-    m.insert("norfolk_island", "\u{1F1F3}\u{1F1EB}");
-    m.insert("north_korea", "\u{1F1F0}\u{1F1F5}");
-    m.insert("northern_mariana_islands", "\u{1F1F2}\u{1F1F5}");
-    m.insert("norway", "\u{1F1F3}\u{1F1F4}");
-    m.insert("nose", "\u{1F443}");
-    m.insert("notebook", "\u{1F4D3}");
-    m.insert("notebook_with_decorative_cover", "\u{1F4D4}");
-    m.insert("notes", "\u{1F3B6}");
-    m.insert("nut_and_bolt", "\u{1F529}");
-    m.insert("o", "\u{2B55}");
-    m.insert("o2", "\u{1F17E}\u{FE0F}");
-    m.insert("ocean", "\u{1F30A}");
-    m.insert("octopus", "\u{1F419}");
-    m.insert("oden", "\u{1F362}");
-    m.insert("office", "\u{1F3E2}");
-    m.insert("office_worker", "\u{1F9D1}\u{1F4BC}");
-    m.insert("oil_drum", "\u{1F6E2}\u{FE0F}");
-    m.insert("ok", "\u{1F197}");
-    m.insert("ok_hand", "\u{1F44C}");
-    m.insert("ok_man", "\u{1F646}\u{2642}\u{FE0F}");
-    m.insert("ok_person", "\u{1F646}");
-    m.insert("ok_woman", "\u{1F646}\u{2640}\u{FE0F}");
-    m.insert("old_key", "\u{1F5DD}\u{FE0F}");
-    m.insert("older_adult", "\u{1F9D3}");
-    m.insert("older_man", "\u{1F474}");
-    m.insert("older_woman", "\u{1F475}");
-    m.insert("olive", "\u{1FAD2}");
-    m.insert("om", "\u{1F549}\u{FE0F}");
-    m.insert("oman", "\u{1F1F4}\u{1F1F2}");
-    m.insert("on", "\u{1F51B}");
-    m.insert("oncoming_automobile", "\u{1F698}");
-    m.insert("oncoming_bus", "\u{1F68D}");
-    m.insert("oncoming_police_car", "\u{1F694}");
-    m.insert("oncoming_taxi", "\u{1F696}");
-    m.insert("one", "\u{0031}\u{FE0F}\u{20E3}");
-    m.insert("one_piece_swimsuit", "\u{1FA71}");
-    m.insert("onion", "\u{1F9C5}");
-    m.insert("open_book", "\u{1F4D6}");
-    m.insert("open_file_folder", "\u{1F4C2}");
-    m.insert("open_hands", "\u{1F450}");
-    m.insert("open_mouth", "\u{1F62E}");
-    m.insert("open_umbrella", "\u{2602}\u{FE0F}");
-    m.insert("ophiuchus", "\u{26CE}");
-    m.insert("orange", "\u{1F34A}");
-    m.insert("orange_book", "\u{1F4D9}");
-    m.insert("orange_circle", "\u{1F7E0}");
-    m.insert("orange_heart", "\u{1F9E1}");
-    m.insert("orange_square", "\u{1F7E7}");
-    m.insert("orangutan", "\u{1F9A7}");
-    m.insert("orthodox_cross", "\u{2626}\u{FE0F}");
-    m.insert("otter", "\u{1F9A6}");
-    m.insert("outbox_tray", "\u{1F4E4}");
-    m.insert("owl", "\u{1F989}");
-    m.insert("ox", "\u{1F402}");
-    m.insert("oyster", "\u{1F9AA}");
-    m.insert("package", "\u{1F4E6}");
-    m.insert("page_facing_up", "\u{1F4C4}");
-    m.insert("page_with_curl", "\u{1F4C3}");
-    m.insert("pager", "\u{1F4DF}");
-    m.insert("paintbrush", "\u{1F58C}\u{FE0F}");
-    m.insert("pakistan", "\u{1F1F5}\u{1F1F0}");
-    m.insert("palau", "\u{1F1F5}\u{1F1FC}");
-    m.insert("palestinian_territories", "\u{1F1F5}\u{1F1F8}");
-    m.insert("palm_down_hand", "\u{1FAF3}");
-    m.insert("palm_tree", "\u{1F334}");
-    m.insert("palm_up_hand", "\u{1FAF4}");
-    m.insert("palms_up_together", "\u{1F932}");
-    m.insert("panama", "\u{1F1F5}\u{1F1E6}");
-    m.insert("pancakes", "\u{1F95E}");
-    m.insert("panda_face", "\u{1F43C}");
-    m.insert("paperclip", "\u{1F4CE}");
-    m.insert("paperclips", "\u{1F587}\u{FE0F}");
-    m.insert("papua_new_guinea", "\u{1F1F5}\u{1F1EC}");
-    m.insert("parachute", "\u{1FA82}");
-    m.insert("paraguay", "\u{1F1F5}\u{1F1FE}");
-    m.insert("parasol_on_ground", "\u{26F1}\u{FE0F}");
-    m.insert("parking", "\u{1F17F}\u{FE0F}");
-    m.insert("parrot", "\u{1F99C}");
-    m.insert("part_alternation_mark", "\u{303D}\u{FE0F}");
-    m.insert("partly_sunny", "\u{26C5}");
-    m.insert("partying_face", "\u{1F973}");
-    m.insert("passenger_ship", "\u{1F6F3}\u{FE0F}");
-    m.insert("passport_control", "\u{1F6C2}");
-    m.insert("pause_button", "\u{23F8}\u{FE0F}");
-    m.insert("paw_prints", "\u{1F43E}");
-    m.insert("pea_pod", "\u{1FADB}");
-    m.insert("peace_symbol", "\u{262E}\u{FE0F}");
-    m.insert("peach", "\u{1F351}");
-    m.insert("peacock", "\u{1F99A}");
-    m.insert("peanuts", "\u{1F95C}");
-    m.insert("pear", "\u{1F350}");
-    m.insert("pen", "\u{1F58A}\u{FE0F}");
-    m.insert("pencil", "\u{1F4DD}");
-    m.insert("pencil2", "\u{270F}\u{FE0F}");
-    m.insert("penguin", "\u{1F427}");
-    m.insert("pensive", "\u{1F614}");
-    m.insert("people_holding_hands", "\u{1F9D1}\u{1F91D}\u{1F9D1}");
-    m.insert("people_hugging", "\u{1FAC2}");
-    m.insert("performing_arts", "\u{1F3AD}");
-    m.insert("persevere", "\u{1F623}");
-    m.insert("person_bald", "\u{1F9D1}\u{1F9B2}");
-    m.insert("person_curly_hair", "\u{1F9D1}\u{1F9B1}");
-    m.insert("person_feeding_baby", "\u{1F9D1}\u{1F37C}");
-    m.insert("person_fencing", "\u{1F93A}");
-    m.insert("person_in_manual_wheelchair", "\u{1F9D1}\u{1F9BD}");
-    m.insert("person_in_motorized_wheelchair", "\u{1F9D1}\u{1F9BC}");
-    m.insert("person_in_tuxedo", "\u{1F935}");
-    m.insert("person_red_hair", "\u{1F9D1}\u{1F9B0}");
-    m.insert("person_white_hair", "\u{1F9D1}\u{1F9B3}");
-    m.insert("person_with_crown", "\u{1FAC5}");
-    m.insert("person_with_probing_cane", "\u{1F9D1}\u{1F9AF}");
-    m.insert("person_with_turban", "\u{1F473}");
-    m.insert("person_with_veil", "\u{1F470}");
-    m.insert("peru", "\u{1F1F5}\u{1F1EA}");
-    m.insert("petri_dish", "\u{1F9EB}");
-    m.insert("philippines", "\u{1F1F5}\u{1F1ED}");
-    m.insert("phone", "\u{260E}\u{FE0F}");
-    m.insert("pick", "\u{26CF}\u{FE0F}");
-    m.insert("pickup_truck", "\u{1F6FB}");
-    m.insert("pie", "\u{1F967}");
-    m.insert("pig", "\u{1F437}");
-    m.insert("pig2", "\u{1F416}");
-    m.insert("pig_nose", "\u{1F43D}");
-    m.insert("pill", "\u{1F48A}");
-    m.insert("pilot", "\u{1F9D1}\u{2708}\u{FE0F}");
-    m.insert("pinata", "\u{1FA85}");
-    m.insert("pinched_fingers", "\u{1F90C}");
-    m.insert("pinching_hand", "\u{1F90F}");
-    m.insert("pineapple", "\u{1F34D}");
-    m.insert("ping_pong", "\u{1F3D3}");
-    m.insert("pink_heart", "\u{1FA77}");
-    m.insert("pirate_flag", "\u{1F3F4}\u{2620}\u{FE0F}");
-    m.insert("pisces", "\u{2653}");
-    m.insert("pitcairn_islands", "\u{1F1F5}\u{1F1F3}");
-    m.insert("pizza", "\u{1F355}");
-    m.insert("placard", "\u{1FAA7}");
-    m.insert("place_of_worship", "\u{1F6D0}");
-    m.insert("plate_with_cutlery", "\u{1F37D}\u{FE0F}");
-    m.insert("play_or_pause_button", "\u{23EF}\u{FE0F}");
-    m.insert("playground_slide", "\u{1F6DD}");
-    m.insert("pleading_face", "\u{1F97A}");
-    m.insert("plunger", "\u{1FAA0}");
-    m.insert("point_down", "\u{1F447}");
-    m.insert("point_left", "\u{1F448}");
-    m.insert("point_right", "\u{1F449}");
-    m.insert("point_up", "\u{261D}\u{FE0F}");
-    m.insert("point_up_2", "\u{1F446}");
-    m.insert("poland", "\u{1F1F5}\u{1F1F1}");
-    m.insert("polar_bear", "\u{1F43B}\u{2744}\u{FE0F}");
-    m.insert("police_car", "\u{1F693}");
-    m.insert("police_officer", "\u{1F46E}");
-    m.insert("policeman", "\u{1F46E}\u{2642}\u{FE0F}");
-    m.insert("policewoman", "\u{1F46E}\u{2640}\u{FE0F}");
-    m.insert("poodle", "\u{1F429}");
-    m.insert("poop", "\u{1F4A9}");
-    m.insert("popcorn", "\u{1F37F}");
-    m.insert("portugal", "\u{1F1F5}\u{1F1F9}");
-    m.insert("post_office", "\u{1F3E3}");
-    m.insert("postal_horn", "\u{1F4EF}");
-    m.insert("postbox", "\u{1F4EE}");
-    m.insert("potable_water", "\u{1F6B0}");
-    m.insert("potato", "\u{1F954}");
-    m.insert("potted_plant", "\u{1FAB4}");
-    m.insert("pouch", "\u{1F45D}");
-    m.insert("poultry_leg", "\u{1F357}");
-    m.insert("pound", "\u{1F4B7}");
-    m.insert("pouring_liquid", "\u{1FAD7}");
-    m.insert("pout", "\u{1F621}");
-    m.insert("pouting_cat", "\u{1F63E}");
-    m.insert("pouting_face", "\u{1F64E}");
-    m.insert("pouting_man", "\u{1F64E}\u{2642}\u{FE0F}");
-    m.insert("pouting_woman", "\u{1F64E}\u{2640}\u{FE0F}");
-    m.insert("pray", "\u{1F64F}");
-    m.insert("prayer_beads", "\u{1F4FF}");
-    m.insert("pregnant_man", "\u{1FAC3}");
-    m.insert("pregnant_person", "\u{1FAC4}");
-    m.insert("pregnant_woman", "\u{1F930}");
-    m.insert("pretzel", "\u{1F968}");
-    m.insert("previous_track_button", "\u{23EE}\u{FE0F}");
-    m.insert("prince", "\u{1F934}");
-    m.insert("princess", "\u{1F478}");
-    m.insert("printer", "\u{1F5A8}\u{FE0F}");
-    m.insert("probing_cane", "\u{1F9AF}");
-    m.insert("puerto_rico", "\u{1F1F5}\u{1F1F7}");
-    m.insert("punch", "\u{1F44A}");
-    m.insert("purple_circle", "\u{1F7E3}");
-    m.insert("purple_heart", "\u{1F49C}");
-    m.insert("purple_square", "\u{1F7EA}");
-    m.insert("purse", "\u{1F45B}");
-    m.insert("pushpin", "\u{1F4CC}");
-    m.insert("put_litter_in_its_place", "\u{1F6AE}");
-    m.insert("qatar", "\u{1F1F6}\u{1F1E6}");
-    m.insert("question", "\u{2753}");
-    m.insert("rabbit", "\u{1F430}");
-    m.insert("rabbit2", "\u{1F407}");
-    m.insert("raccoon", "\u{1F99D}");
-    m.insert("racehorse", "\u{1F40E}");
-    m.insert("racing_car", "\u{1F3CE}\u{FE0F}");
-    m.insert("radio", "\u{1F4FB}");
-    m.insert("radio_button", "\u{1F518}");
-    m.insert("radioactive", "\u{2622}\u{FE0F}");
-    m.insert("rage", "\u{1F621}");
-    m.insert("railway_car", "\u{1F683}");
-    m.insert("railway_track", "\u{1F6E4}\u{FE0F}");
-    m.insert("rainbow", "\u{1F308}");
-    m.insert("rainbow_flag", "\u{1F3F3}\u{FE0F}\u{1F308}");
-    m.insert("raised_back_of_hand", "\u{1F91A}");
-    m.insert("raised_eyebrow", "\u{1F928}");
-    m.insert("raised_hand", "\u{270B}");
-    m.insert("raised_hand_with_fingers_splayed", "\u{1F590}\u{FE0F}");
-    m.insert("raised_hands", "\u{1F64C}");
-    m.insert("raising_hand", "\u{1F64B}");
-    m.insert("raising_hand_man", "\u{1F64B}\u{2642}\u{FE0F}");
-    m.insert("raising_hand_woman", "\u{1F64B}\u{2640}\u{FE0F}");
-    m.insert("ram", "\u{1F40F}");
-    m.insert("ramen", "\u{1F35C}");
-    m.insert("rat", "\u{1F400}");
-    m.insert("razor", "\u{1FA92}");
-    m.insert("receipt", "\u{1F9FE}");
-    m.insert("record_button", "\u{23FA}\u{FE0F}");
-    m.insert("recycle", "\u{267B}\u{FE0F}");
-    m.insert("red_car", "\u{1F697}");
-    m.insert("red_circle", "\u{1F534}");
-    m.insert("red_envelope", "\u{1F9E7}");
-    m.insert("red_haired_man", "\u{1F468}\u{1F9B0}");
-    m.insert("red_haired_woman", "\u{1F469}\u{1F9B0}");
-    m.insert("red_square", "\u{1F7E5}");
-    m.insert("registered", "\u{00AE}\u{FE0F}");
-    m.insert("relaxed", "\u{263A}\u{FE0F}");
-    m.insert("relieved", "\u{1F60C}");
-    m.insert("reminder_ribbon", "\u{1F397}\u{FE0F}");
-    m.insert("repeat", "\u{1F501}");
-    m.insert("repeat_one", "\u{1F502}");
-    m.insert("rescue_worker_helmet", "\u{26D1}\u{FE0F}");
-    m.insert("restroom", "\u{1F6BB}");
-    m.insert("reunion", "\u{1F1F7}\u{1F1EA}");
-    m.insert("revolving_hearts", "\u{1F49E}");
-    m.insert("rewind", "\u{23EA}");
-    m.insert("rhinoceros", "\u{1F98F}");
-    m.insert("ribbon", "\u{1F380}");
-    m.insert("rice", "\u{1F35A}");
-    m.insert("rice_ball", "\u{1F359}");
-    m.insert("rice_cracker", "\u{1F358}");
-    m.insert("rice_scene", "\u{1F391}");
-    m.insert("right_anger_bubble", "\u{1F5EF}\u{FE0F}");
-    m.insert("rightwards_hand", "\u{1FAF1}");
-    m.insert("rightwards_pushing_hand", "\u{1FAF8}");
-    m.insert("ring", "\u{1F48D}");
-    m.insert("ring_buoy", "\u{1F6DF}");
-    m.insert("ringed_planet", "\u{1FA90}");
-    m.insert("robot", "\u{1F916}");
-    m.insert("rock", "\u{1FAA8}");
-    m.insert("rocket", "\u{1F680}");
-    m.insert("rofl", "\u{1F923}");
-    m.insert("roll_eyes", "\u{1F644}");
-    m.insert("roll_of_paper", "\u{1F9FB}");
-    m.insert("roller_coaster", "\u{1F3A2}");
-    m.insert("roller_skate", "\u{1F6FC}");
-    m.insert("romania", "\u{1F1F7}\u{1F1F4}");
-    m.insert("rooster", "\u{1F413}");
-    m.insert("rose", "\u{1F339}");
-    m.insert("rosette", "\u{1F3F5}\u{FE0F}");
-    m.insert("rotating_light", "\u{1F6A8}");
-    m.insert("round_pushpin", "\u{1F4CD}");
-    m.insert("rowboat", "\u{1F6A3}");
-    m.insert("rowing_man", "\u{1F6A3}\u{2642}\u{FE0F}");
-    m.insert("rowing_woman", "\u{1F6A3}\u{2640}\u{FE0F}");
-    m.insert("ru", "\u{1F1F7}\u{1F1FA}");
-    m.insert("rugby_football", "\u{1F3C9}");
-    m.insert("runner", "\u{1F3C3}");
-    m.insert("running", "\u{1F3C3}");
-    m.insert("running_man", "\u{1F3C3}\u{2642}\u{FE0F}");
-    m.insert("running_shirt_with_sash", "\u{1F3BD}");
-    m.insert("running_woman", "\u{1F3C3}\u{2640}\u{FE0F}");
-    m.insert("rwanda", "\u{1F1F7}\u{1F1FC}");
-    m.insert("sa", "\u{1F202}\u{FE0F}");
-    m.insert("safety_pin", "\u{1F9F7}");
-    m.insert("safety_vest", "\u{1F9BA}");
-    m.insert("sagittarius", "\u{2650}");
-    m.insert("sailboat", "\u{26F5}");
-    m.insert("sake", "\u{1F376}");
-    m.insert("salt", "\u{1F9C2}");
-    m.insert("saluting_face", "\u{1FAE1}");
-    m.insert("samoa", "\u{1F1FC}\u{1F1F8}");
-    m.insert("san_marino", "\u{1F1F8}\u{1F1F2}");
-    m.insert("sandal", "\u{1F461}");
-    m.insert("sandwich", "\u{1F96A}");
-    m.insert("santa", "\u{1F385}");
-    m.insert("sao_tome_principe", "\u{1F1F8}\u{1F1F9}");
-    m.insert("sari", "\u{1F97B}");
-    m.insert("sassy_man", "\u{1F481}\u{2642}\u{FE0F}");
-    m.insert("sassy_woman", "\u{1F481}\u{2640}\u{FE0F}");
-    m.insert("satellite", "\u{1F4E1}");
-    m.insert("satisfied", "\u{1F606}");
-    m.insert("saudi_arabia", "\u{1F1F8}\u{1F1E6}");
-    m.insert("sauna_man", "\u{1F9D6}\u{2642}\u{FE0F}");
-    m.insert("sauna_person", "\u{1F9D6}");
-    m.insert("sauna_woman", "\u{1F9D6}\u{2640}\u{FE0F}");
-    m.insert("sauropod", "\u{1F995}");
-    m.insert("saxophone", "\u{1F3B7}");
-    m.insert("scarf", "\u{1F9E3}");
-    m.insert("school", "\u{1F3EB}");
-    m.insert("school_satchel", "\u{1F392}");
-    m.insert("scientist", "\u{1F9D1}\u{1F52C}");
-    m.insert("scissors", "\u{2702}\u{FE0F}");
-    m.insert("scorpion", "\u{1F982}");
-    m.insert("scorpius", "\u{264F}");
-    m.insert("scotland", "\u{1F3F4}\u{E0067}\u{E0062}\u{E0073}\u{E0063}\u{E0074}\u{E007F}");
-    m.insert("scream", "\u{1F631}");
-    m.insert("scream_cat", "\u{1F640}");
-    m.insert("screwdriver", "\u{1FA9B}");
-    m.insert("scroll", "\u{1F4DC}");
-    m.insert("seal", "\u{1F9AD}");
-    m.insert("seat", "\u{1F4BA}");
-    m.insert("secret", "\u{3299}\u{FE0F}");
-    m.insert("see_no_evil", "\u{1F648}");
-    m.insert("seedling", "\u{1F331}");
-    m.insert("selfie", "\u{1F933}");
-    m.insert("senegal", "\u{1F1F8}\u{1F1F3}");
-    m.insert("serbia", "\u{1F1F7}\u{1F1F8}");
-    m.insert("service_dog", "\u{1F415}\u{1F9BA}");
-    m.insert("seven", "\u{0037}\u{FE0F}\u{20E3}");
-    m.insert("sewing_needle", "\u{1FAA1}");
-    m.insert("seychelles", "\u{1F1F8}\u{1F1E8}");
-    m.insert("shaking_face", "\u{1FAE8}");
-    m.insert("shallow_pan_of_food", "\u{1F958}");
-    m.insert("shamrock", "\u{2618}\u{FE0F}");
-    m.insert("shark", "\u{1F988}");
-    m.insert("shaved_ice", "\u{1F367}");
-    m.insert("sheep", "\u{1F411}");
-    m.insert("shell", "\u{1F41A}");
-    m.insert("shield", "\u{1F6E1}\u{FE0F}");
-    m.insert("shinto_shrine", "\u{26E9}\u{FE0F}");
-    m.insert("ship", "\u{1F6A2}");
-    m.insert("shirt", "\u{1F455}");
-    m.insert("shit", "\u{1F4A9}");
-    m.insert("shoe", "\u{1F45E}");
-    m.insert("shopping", "\u{1F6CD}\u{FE0F}");
-    m.insert("shopping_cart", "\u{1F6D2}");
-    m.insert("shorts", "\u{1FA73}");
-    m.insert("shower", "\u{1F6BF}");
-    m.insert("shrimp", "\u{1F990}");
-    m.insert("shrug", "\u{1F937}");
-    m.insert("shushing_face", "\u{1F92B}");
-    m.insert("sierra_leone", "\u{1F1F8}\u{1F1F1}");
-    m.insert("signal_strength", "\u{1F4F6}");
-    m.insert("singapore", "\u{1F1F8}\u{1F1EC}");
-    m.insert("singer", "\u{1F9D1}\u{1F3A4}");
-    m.insert("sint_maarten", "\u{1F1F8}\u{1F1FD}");
-    m.insert("six", "\u{0036}\u{FE0F}\u{20E3}");
-    m.insert("six_pointed_star", "\u{1F52F}");
-    m.insert("skateboard", "\u{1F6F9}");
-    m.insert("ski", "\u{1F3BF}");
-    m.insert("skier", "\u{26F7}\u{FE0F}");
-    m.insert("skull", "\u{1F480}");
-    m.insert("skull_and_crossbones", "\u{2620}\u{FE0F}");
-    m.insert("skunk", "\u{1F9A8}");
-    m.insert("sled", "\u{1F6F7}");
-    m.insert("sleeping", "\u{1F634}");
-    m.insert("sleeping_bed", "\u{1F6CC}");
-    m.insert("sleepy", "\u{1F62A}");
-    m.insert("slightly_frowning_face", "\u{1F641}");
-    m.insert("slightly_smiling_face", "\u{1F642}");
-    m.insert("slot_machine", "\u{1F3B0}");
-    m.insert("sloth", "\u{1F9A5}");
-    m.insert("slovakia", "\u{1F1F8}\u{1F1F0}");
-    m.insert("slovenia", "\u{1F1F8}\u{1F1EE}");
-    m.insert("small_airplane", "\u{1F6E9}\u{FE0F}");
-    m.insert("small_blue_diamond", "\u{1F539}");
-    m.insert("small_orange_diamond", "\u{1F538}");
-    m.insert("small_red_triangle", "\u{1F53A}");
-    m.insert("small_red_triangle_down", "\u{1F53B}");
-    m.insert("smile", "\u{1F604}");
-    m.insert("smile_cat", "\u{1F638}");
-    m.insert("smiley", "\u{1F603}");
-    m.insert("smiley_cat", "\u{1F63A}");
-    m.insert("smiling_face_with_tear", "\u{1F972}");
-    m.insert("smiling_face_with_three_hearts", "\u{1F970}");
-    m.insert("smiling_imp", "\u{1F608}");
-    m.insert("smirk", "\u{1F60F}");
-    m.insert("smirk_cat", "\u{1F63C}");
-    m.insert("smoking", "\u{1F6AC}");
-    m.insert("snail", "\u{1F40C}");
-    m.insert("snake", "\u{1F40D}");
-    m.insert("sneezing_face", "\u{1F927}");
-    m.insert("snowboarder", "\u{1F3C2}");
-    m.insert("snowflake", "\u{2744}\u{FE0F}");
-    m.insert("snowman", "\u{26C4}");
-    m.insert("snowman_with_snow", "\u{2603}\u{FE0F}");
-    m.insert("soap", "\u{1F9FC}");
-    m.insert("sob", "\u{1F62D}");
-    m.insert("soccer", "\u{26BD}");
-    m.insert("socks", "\u{1F9E6}");
-    m.insert("softball", "\u{1F94E}");
-    m.insert("solomon_islands", "\u{1F1F8}\u{1F1E7}");
-    m.insert("somalia", "\u{1F1F8}\u{1F1F4}");
-    m.insert("soon", "\u{1F51C}");
-    m.insert("sos", "\u{1F198}");
-    m.insert("sound", "\u{1F509}");
-    m.insert("south_africa", "\u{1F1FF}\u{1F1E6}");
-    m.insert("south_georgia_south_sandwich_islands", "\u{1F1EC}\u{1F1F8}");
-    m.insert("south_sudan", "\u{1F1F8}\u{1F1F8}");
-    m.insert("space_invader", "\u{1F47E}");
-    m.insert("spades", "\u{2660}\u{FE0F}");
-    m.insert("spaghetti", "\u{1F35D}");
-    m.insert("sparkle", "\u{2747}\u{FE0F}");
-    m.insert("sparkler", "\u{1F387}");
-    m.insert("sparkles", "\u{2728}");
-    m.insert("sparkling_heart", "\u{1F496}");
-    m.insert("speak_no_evil", "\u{1F64A}");
-    m.insert("speaker", "\u{1F508}");
-    m.insert("speaking_head", "\u{1F5E3}\u{FE0F}");
-    m.insert("speech_balloon", "\u{1F4AC}");
-    m.insert("speedboat", "\u{1F6A4}");
-    m.insert("spider", "\u{1F577}\u{FE0F}");
-    m.insert("spider_web", "\u{1F578}\u{FE0F}");
-    m.insert("spiral_calendar", "\u{1F5D3}\u{FE0F}");
-    m.insert("spiral_notepad", "\u{1F5D2}\u{FE0F}");
-    m.insert("sponge", "\u{1F9FD}");
-    m.insert("spoon", "\u{1F944}");
-    m.insert("squid", "\u{1F991}");
-    m.insert("sri_lanka", "\u{1F1F1}\u{1F1F0}");
-    m.insert("st_barthelemy", "\u{1F1E7}\u{1F1F1}");
-    m.insert("st_helena", "\u{1F1F8}\u{1F1ED}");
-    m.insert("st_kitts_nevis", "\u{1F1F0}\u{1F1F3}");
-    m.insert("st_lucia", "\u{1F1F1}\u{1F1E8}");
-    m.insert("st_martin", "\u{1F1F2}\u{1F1EB}");
-    m.insert("st_pierre_miquelon", "\u{1F1F5}\u{1F1F2}");
-    m.insert("st_vincent_grenadines", "\u{1F1FB}\u{1F1E8}");
-    m.insert("stadium", "\u{1F3DF}\u{FE0F}");
-    m.insert("standing_man", "\u{1F9CD}\u{2642}\u{FE0F}");
-    m.insert("standing_person", "\u{1F9CD}");
-    m.insert("standing_woman", "\u{1F9CD}\u{2640}\u{FE0F}");
-    m.insert("star", "\u{2B50}");
-    m.insert("star2", "\u{1F31F}");
-    m.insert("star_and_crescent", "\u{262A}\u{FE0F}");
-    m.insert("star_of_david", "\u{2721}\u{FE0F}");
-    m.insert("star_struck", "\u{1F929}");
-    m.insert("stars", "\u{1F320}");
-    m.insert("station", "\u{1F689}");
-    m.insert("statue_of_liberty", "\u{1F5FD}");
-    m.insert("steam_locomotive", "\u{1F682}");
-    m.insert("stethoscope", "\u{1FA7A}");
-    m.insert("stew", "\u{1F372}");
-    m.insert("stop_button", "\u{23F9}\u{FE0F}");
-    m.insert("stop_sign", "\u{1F6D1}");
-    m.insert("stopwatch", "\u{23F1}\u{FE0F}");
-    m.insert("straight_ruler", "\u{1F4CF}");
-    m.insert("strawberry", "\u{1F353}");
-    m.insert("stuck_out_tongue", "\u{1F61B}");
-    m.insert("stuck_out_tongue_closed_eyes", "\u{1F61D}");
-    m.insert("stuck_out_tongue_winking_eye", "\u{1F61C}");
-    m.insert("student", "\u{1F9D1}\u{1F393}");
-    m.insert("studio_microphone", "\u{1F399}\u{FE0F}");
-    m.insert("stuffed_flatbread", "\u{1F959}");
-    m.insert("sudan", "\u{1F1F8}\u{1F1E9}");
-    m.insert("sun_behind_large_cloud", "\u{1F325}\u{FE0F}");
-    m.insert("sun_behind_rain_cloud", "\u{1F326}\u{FE0F}");
-    m.insert("sun_behind_small_cloud", "\u{1F324}\u{FE0F}");
-    m.insert("sun_with_face", "\u{1F31E}");
-    m.insert("sunflower", "\u{1F33B}");
-    m.insert("sunglasses", "\u{1F60E}");
-    m.insert("sunny", "\u{2600}\u{FE0F}");
-    m.insert("sunrise", "\u{1F305}");
-    m.insert("sunrise_over_mountains", "\u{1F304}");
-    m.insert("superhero", "\u{1F9B8}");
-    m.insert("superhero_man", "\u{1F9B8}\u{2642}\u{FE0F}");
-    m.insert("superhero_woman", "\u{1F9B8}\u{2640}\u{FE0F}");
-    m.insert("supervillain", "\u{1F9B9}");
-    m.insert("supervillain_man", "\u{1F9B9}\u{2642}\u{FE0F}");
-    m.insert("supervillain_woman", "\u{1F9B9}\u{2640}\u{FE0F}");
-    m.insert("surfer", "\u{1F3C4}");
-    m.insert("surfing_man", "\u{1F3C4}\u{2642}\u{FE0F}");
-    m.insert("surfing_woman", "\u{1F3C4}\u{2640}\u{FE0F}");
-    m.insert("suriname", "\u{1F1F8}\u{1F1F7}");
-    m.insert("sushi", "\u{1F363}");
-    m.insert("suspension_railway", "\u{1F69F}");
-    m.insert("svalbard_jan_mayen", "\u{1F1F8}\u{1F1EF}");
-    m.insert("swan", "\u{1F9A2}");
-    m.insert("swaziland", "\u{1F1F8}\u{1F1FF}");
-    m.insert("sweat", "\u{1F613}");
-    m.insert("sweat_drops", "\u{1F4A6}");
-    m.insert("sweat_smile", "\u{1F605}");
-    m.insert("sweden", "\u{1F1F8}\u{1F1EA}");
-    m.insert("sweet_potato", "\u{1F360}");
-    m.insert("swim_brief", "\u{1FA72}");
-    m.insert("swimmer", "\u{1F3CA}");
-    m.insert("swimming_man", "\u{1F3CA}\u{2642}\u{FE0F}");
-    m.insert("swimming_woman", "\u{1F3CA}\u{2640}\u{FE0F}");
-    m.insert("switzerland", "\u{1F1E8}\u{1F1ED}");
-    m.insert("symbols", "\u{1F523}");
-    m.insert("synagogue", "\u{1F54D}");
-    m.insert("syria", "\u{1F1F8}\u{1F1FE}");
-    m.insert("syringe", "\u{1F489}");
-    m.insert("t-rex", "\u{1F996}");
-    m.insert("taco", "\u{1F32E}");
-    m.insert("tada", "\u{1F389}");
-    m.insert("taiwan", "\u{1F1F9}\u{1F1FC}");
-    m.insert("tajikistan", "\u{1F1F9}\u{1F1EF}");
-    m.insert("takeout_box", "\u{1F961}");
-    m.insert("tamale", "\u{1FAD4}");
-    m.insert("tanabata_tree", "\u{1F38B}");
-    m.insert("tangerine", "\u{1F34A}");
-    m.insert("tanzania", "\u{1F1F9}\u{1F1FF}");
-    m.insert("taurus", "\u{2649}");
-    m.insert("taxi", "\u{1F695}");
-    m.insert("tea", "\u{1F375}");
-    m.insert("teacher", "\u{1F9D1}\u{1F3EB}");
-    m.insert("teapot", "\u{1FAD6}");
-    m.insert("technologist", "\u{1F9D1}\u{1F4BB}");
-    m.insert("teddy_bear", "\u{1F9F8}");
-    m.insert("telephone", "\u{260E}\u{FE0F}");
-    m.insert("telephone_receiver", "\u{1F4DE}");
-    m.insert("telescope", "\u{1F52D}");
-    m.insert("tennis", "\u{1F3BE}");
-    m.insert("tent", "\u{26FA}");
-    m.insert("test_tube", "\u{1F9EA}");
-    m.insert("thailand", "\u{1F1F9}\u{1F1ED}");
-    m.insert("thermometer", "\u{1F321}\u{FE0F}");
-    m.insert("thinking", "\u{1F914}");
-    m.insert("thong_sandal", "\u{1FA74}");
-    m.insert("thought_balloon", "\u{1F4AD}");
-    m.insert("thread", "\u{1F9F5}");
-    m.insert("three", "\u{0033}\u{FE0F}\u{20E3}");
-    m.insert("thumbsdown", "\u{1F44E}");
-    m.insert("thumbsup", "\u{1F44D}");
-    m.insert("ticket", "\u{1F3AB}");
-    m.insert("tickets", "\u{1F39F}\u{FE0F}");
-    m.insert("tiger", "\u{1F42F}");
-    m.insert("tiger2", "\u{1F405}");
-    m.insert("timer_clock", "\u{23F2}\u{FE0F}");
-    m.insert("timor_leste", "\u{1F1F9}\u{1F1F1}");
-    m.insert("tipping_hand_man", "\u{1F481}\u{2642}\u{FE0F}");
-    m.insert("tipping_hand_person", "\u{1F481}");
-    m.insert("tipping_hand_woman", "\u{1F481}\u{2640}\u{FE0F}");
-    m.insert("tired_face", "\u{1F62B}");
-    m.insert("tm", "\u{2122}\u{FE0F}");
-    m.insert("togo", "\u{1F1F9}\u{1F1EC}");
-    m.insert("toilet", "\u{1F6BD}");
-    m.insert("tokelau", "\u{1F1F9}\u{1F1F0}");
-    m.insert("tokyo_tower", "\u{1F5FC}");
-    m.insert("tomato", "\u{1F345}");
-    m.insert("tonga", "\u{1F1F9}\u{1F1F4}");
-    m.insert("tongue", "\u{1F445}");
-    m.insert("toolbox", "\u{1F9F0}");
-    m.insert("tooth", "\u{1F9B7}");
-    m.insert("toothbrush", "\u{1FAA5}");
-    m.insert("top", "\u{1F51D}");
-    m.insert("tophat", "\u{1F3A9}");
-    m.insert("tornado", "\u{1F32A}\u{FE0F}");
-    m.insert("tr", "\u{1F1F9}\u{1F1F7}");
-    m.insert("trackball", "\u{1F5B2}\u{FE0F}");
-    m.insert("tractor", "\u{1F69C}");
-    m.insert("traffic_light", "\u{1F6A5}");
-    m.insert("train", "\u{1F68B}");
-    m.insert("train2", "\u{1F686}");
-    m.insert("tram", "\u{1F68A}");
-    m.insert("transgender_flag", "\u{1F3F3}\u{FE0F}\u{26A7}\u{FE0F}");
-    m.insert("transgender_symbol", "\u{26A7}\u{FE0F}");
-    m.insert("triangular_flag_on_post", "\u{1F6A9}");
-    m.insert("triangular_ruler", "\u{1F4D0}");
-    m.insert("trident", "\u{1F531}");
-    m.insert("trinidad_tobago", "\u{1F1F9}\u{1F1F9}");
-    m.insert("tristan_da_cunha", "\u{1F1F9}\u{1F1E6}");
-    m.insert("triumph", "\u{1F624}");
-    m.insert("troll", "\u{1F9CC}");
-    m.insert("trolleybus", "\u{1F68E}");
-    m.insert("trophy", "\u{1F3C6}");
-    m.insert("tropical_drink", "\u{1F379}");
-    m.insert("tropical_fish", "\u{1F420}");
-    m.insert("truck", "\u{1F69A}");
-    m.insert("trumpet", "\u{1F3BA}");
-    m.insert("tshirt", "\u{1F455}");
-    m.insert("tulip", "\u{1F337}");
-    m.insert("tumbler_glass", "\u{1F943}");
-    m.insert("tunisia", "\u{1F1F9}\u{1F1F3}");
-    m.insert("turkey", "\u{1F983}");
-    m.insert("turkmenistan", "\u{1F1F9}\u{1F1F2}");
-    m.insert("turks_caicos_islands", "\u{1F1F9}\u{1F1E8}");
-    m.insert("turtle", "\u{1F422}");
-    m.insert("tuvalu", "\u{1F1F9}\u{1F1FB}");
-    m.insert("tv", "\u{1F4FA}");
-    m.insert("twisted_rightwards_arrows", "\u{1F500}");
-    m.insert("two", "\u{0032}\u{FE0F}\u{20E3}");
-    m.insert("two_hearts", "\u{1F495}");
-    m.insert("two_men_holding_hands", "\u{1F46C}");
-    m.insert("two_women_holding_hands", "\u{1F46D}");
-    m.insert("u5272", "\u{1F239}");
-    m.insert("u5408", "\u{1F234}");
-    m.insert("u55b6", "\u{1F23A}");
-    m.insert("u6307", "\u{1F22F}");
-    m.insert("u6708", "\u{1F237}\u{FE0F}");
-    m.insert("u6709", "\u{1F236}");
-    m.insert("u6e80", "\u{1F235}");
-    m.insert("u7121", "\u{1F21A}");
-    m.insert("u7533", "\u{1F238}");
-    m.insert("u7981", "\u{1F232}");
-    m.insert("u7a7a", "\u{1F233}");
-    m.insert("uganda", "\u{1F1FA}\u{1F1EC}");
-    m.insert("uk", "\u{1F1EC}\u{1F1E7}");
-    m.insert("ukraine", "\u{1F1FA}\u{1F1E6}");
-    m.insert("umbrella", "\u{2614}");
-    m.insert("unamused", "\u{1F612}");
-    m.insert("underage", "\u{1F51E}");
-    m.insert("unicorn", "\u{1F984}");
-    m.insert("united_arab_emirates", "\u{1F1E6}\u{1F1EA}");
-    m.insert("united_nations", "\u{1F1FA}\u{1F1F3}");
-    m.insert("unlock", "\u{1F513}");
-    m.insert("up", "\u{1F199}");
-    m.insert("upside_down_face", "\u{1F643}");
-    m.insert("uruguay", "\u{1F1FA}\u{1F1FE}");
-    m.insert("us", "\u{1F1FA}\u{1F1F8}");
-    m.insert("us_outlying_islands", "\u{1F1FA}\u{1F1F2}");
-    m.insert("us_virgin_islands", "\u{1F1FB}\u{1F1EE}");
-    m.insert("uzbekistan", "\u{1F1FA}\u{1F1FF}");
-    m.insert("v", "\u{270C}\u{FE0F}");
-    m.insert("vampire", "\u{1F9DB}");
-    m.insert("vampire_man", "\u{1F9DB}\u{2642}\u{FE0F}");
-    m.insert("vampire_woman", "\u{1F9DB}\u{2640}\u{FE0F}");
-    m.insert("vanuatu", "\u{1F1FB}\u{1F1FA}");
-    m.insert("vatican_city", "\u{1F1FB}\u{1F1E6}");
-    m.insert("venezuela", "\u{1F1FB}\u{1F1EA}");
-    m.insert("vertical_traffic_light", "\u{1F6A6}");
-    m.insert("vhs", "\u{1F4FC}");
-    m.insert("vibration_mode", "\u{1F4F3}");
-    m.insert("video_camera", "\u{1F4F9}");
-    m.insert("video_game", "\u{1F3AE}");
-    m.insert("vietnam", "\u{1F1FB}\u{1F1F3}");
-    m.insert("violin", "\u{1F3BB}");
-    m.insert("virgo", "\u{264D}");
-    m.insert("volcano", "\u{1F30B}");
-    m.insert("volleyball", "\u{1F3D0}");
-    m.insert("vomiting_face", "\u{1F92E}");
-    m.insert("vs", "\u{1F19A}");
-    m.insert("vulcan_salute", "\u{1F596}");
-    m.insert("waffle", "\u{1F9C7}");
-    m.insert("wales", "\u{1F3F4}\u{E0067}\u{E0062}\u{E0077}\u{E006C}\u{E0073}\u{E007F}");
-    m.insert("walking", "\u{1F6B6}");
-    m.insert("walking_man", "\u{1F6B6}\u{2642}\u{FE0F}");
-    m.insert("walking_woman", "\u{1F6B6}\u{2640}\u{FE0F}");
-    m.insert("wallis_futuna", "\u{1F1FC}\u{1F1EB}");
-    m.insert("waning_crescent_moon", "\u{1F318}");
-    m.insert("waning_gibbous_moon", "\u{1F316}");
-    m.insert("warning", "\u{26A0}\u{FE0F}");
-    m.insert("wastebasket", "\u{1F5D1}\u{FE0F}");
-    m.insert("watch", "\u{231A}");
-    m.insert("water_buffalo", "\u{1F403}");
-    m.insert("water_polo", "\u{1F93D}");
-    m.insert("watermelon", "\u{1F349}");
-    m.insert("wave", "\u{1F44B}");
-    m.insert("wavy_dash", "\u{3030}\u{FE0F}");
-    m.insert("waxing_crescent_moon", "\u{1F312}");
-    m.insert("waxing_gibbous_moon", "\u{1F314}");
-    m.insert("wc", "\u{1F6BE}");
-    m.insert("weary", "\u{1F629}");
-    m.insert("wedding", "\u{1F492}");
-    m.insert("weight_lifting", "\u{1F3CB}\u{FE0F}");
-    m.insert("weight_lifting_man", "\u{1F3CB}\u{FE0F}\u{2642}\u{FE0F}");
-    m.insert("weight_lifting_woman", "\u{1F3CB}\u{FE0F}\u{2640}\u{FE0F}");
-    m.insert("western_sahara", "\u{1F1EA}\u{1F1ED}");
-    m.insert("whale", "\u{1F433}");
-    m.insert("whale2", "\u{1F40B}");
-    m.insert("wheel", "\u{1F6DE}");
-    m.insert("wheel_of_dharma", "\u{2638}\u{FE0F}");
-    m.insert("wheelchair", "\u{267F}");
-    m.insert("white_check_mark", "\u{2705}");
-    m.insert("white_circle", "\u{26AA}");
-    m.insert("white_flag", "\u{1F3F3}\u{FE0F}");
-    m.insert("white_flower", "\u{1F4AE}");
-    m.insert("white_haired_man", "\u{1F468}\u{1F9B3}");
-    m.insert("white_haired_woman", "\u{1F469}\u{1F9B3}");
-    m.insert("white_heart", "\u{1F90D}");
-    m.insert("white_large_square", "\u{2B1C}");
-    m.insert("white_medium_small_square", "\u{25FD}");
-    m.insert("white_medium_square", "\u{25FB}\u{FE0F}");
-    m.insert("white_small_square", "\u{25AB}\u{FE0F}");
-    m.insert("white_square_button", "\u{1F533}");
-    m.insert("wilted_flower", "\u{1F940}");
-    m.insert("wind_chime", "\u{1F390}");
-    m.insert("wind_face", "\u{1F32C}\u{FE0F}");
-    m.insert("window", "\u{1FA9F}");
-    m.insert("wine_glass", "\u{1F377}");
-    m.insert("wing", "\u{1FABD}");
-    m.insert("wink", "\u{1F609}");
-    m.insert("wireless", "\u{1F6DC}");
-    m.insert("wolf", "\u{1F43A}");
-    m.insert("woman", "\u{1F469}");
-    m.insert("woman_artist", "\u{1F469}\u{1F3A8}");
-    m.insert("woman_astronaut", "\u{1F469}\u{1F680}");
-    m.insert("woman_beard", "\u{1F9D4}\u{2640}\u{FE0F}");
-    m.insert("woman_cartwheeling", "\u{1F938}\u{2640}\u{FE0F}");
-    m.insert("woman_cook", "\u{1F469}\u{1F373}");
-    m.insert("woman_dancing", "\u{1F483}");
-    m.insert("woman_facepalming", "\u{1F926}\u{2640}\u{FE0F}");
-    m.insert("woman_factory_worker", "\u{1F469}\u{1F3ED}");
-    m.insert("woman_farmer", "\u{1F469}\u{1F33E}");
-    m.insert("woman_feeding_baby", "\u{1F469}\u{1F37C}");
-    m.insert("woman_firefighter", "\u{1F469}\u{1F692}");
-    m.insert("woman_health_worker", "\u{1F469}\u{2695}\u{FE0F}");
-    m.insert("woman_in_manual_wheelchair", "\u{1F469}\u{1F9BD}");
-    m.insert("woman_in_motorized_wheelchair", "\u{1F469}\u{1F9BC}");
-    m.insert("woman_in_tuxedo", "\u{1F935}\u{2640}\u{FE0F}");
-    m.insert("woman_judge", "\u{1F469}\u{2696}\u{FE0F}");
-    m.insert("woman_juggling", "\u{1F939}\u{2640}\u{FE0F}");
-    m.insert("woman_mechanic", "\u{1F469}\u{1F527}");
-    m.insert("woman_office_worker", "\u{1F469}\u{1F4BC}");
-    m.insert("woman_pilot", "\u{1F469}\u{2708}\u{FE0F}");
-    m.insert("woman_playing_handball", "\u{1F93E}\u{2640}\u{FE0F}");
-    m.insert("woman_playing_water_polo", "\u{1F93D}\u{2640}\u{FE0F}");
-    m.insert("woman_scientist", "\u{1F469}\u{1F52C}");
-    m.insert("woman_shrugging", "\u{1F937}\u{2640}\u{FE0F}");
-    m.insert("woman_singer", "\u{1F469}\u{1F3A4}");
-    m.insert("woman_student", "\u{1F469}\u{1F393}");
-    m.insert("woman_teacher", "\u{1F469}\u{1F3EB}");
-    m.insert("woman_technologist", "\u{1F469}\u{1F4BB}");
-    m.insert("woman_with_headscarf", "\u{1F9D5}");
-    m.insert("woman_with_probing_cane", "\u{1F469}\u{1F9AF}");
-    m.insert("woman_with_turban", "\u{1F473}\u{2640}\u{FE0F}");
-    m.insert("woman_with_veil", "\u{1F470}\u{2640}\u{FE0F}");
-    m.insert("womans_clothes", "\u{1F45A}");
-    m.insert("womans_hat", "\u{1F452}");
-    m.insert("women_wrestling", "\u{1F93C}\u{2640}\u{FE0F}");
-    m.insert("womens", "\u{1F6BA}");
-    m.insert("wood", "\u{1FAB5}");
-    m.insert("woozy_face", "\u{1F974}");
-    m.insert("world_map", "\u{1F5FA}\u{FE0F}");
-    m.insert("worm", "\u{1FAB1}");
-    m.insert("worried", "\u{1F61F}");
-    m.insert("wrench", "\u{1F527}");
-    m.insert("wrestling", "\u{1F93C}");
-    m.insert("writing_hand", "\u{270D}\u{FE0F}");
-    m.insert("x", "\u{274C}");
-    m.insert("x_ray", "\u{1FA7B}");
-    m.insert("yarn", "\u{1F9F6}");
-    m.insert("yawning_face", "\u{1F971}");
-    m.insert("yellow_circle", "\u{1F7E1}");
-    m.insert("yellow_heart", "\u{1F49B}");
-    m.insert("yellow_square", "\u{1F7E8}");
-    m.insert("yemen", "\u{1F1FE}\u{1F1EA}");
-    m.insert("yen", "\u{1F4B4}");
-    m.insert("yin_yang", "\u{262F}\u{FE0F}");
-    m.insert("yo_yo", "\u{1FA80}");
-    m.insert("yum", "\u{1F60B}");
-    m.insert("zambia", "\u{1F1FF}\u{1F1F2}");
-    m.insert("zany_face", "\u{1F92A}");
-    m.insert("zap", "\u{26A1}");
-    m.insert("zebra", "\u{1F993}");
-    m.insert("zero", "\u{0030}\u{FE0F}\u{20E3}");
-    m.insert("zimbabwe", "\u{1F1FF}\u{1F1FC}");
-    m.insert("zipper_mouth_face", "\u{1F910}");
-    m.insert("zombie", "\u{1F9DF}");
-    m.insert("zombie_man", "\u{1F9DF}\u{2642}\u{FE0F}");
-    m.insert("zombie_woman", "\u{1F9DF}\u{2640}\u{FE0F}");
-    m.insert("zzz", "\u{1F4A4}");
-    m
-});
+// `EMOJIS`, `GITHUB_EMOJIS` and `EMOJI_CODES`, generated by build.rs from
+// data/github_emojis.rs
+include!(concat!(env!("OUT_DIR"), "/emoji_maps.rs"));
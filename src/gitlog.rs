@@ -0,0 +1,91 @@
+//! `git log` in its default format, with `commit <hash>`, author and date
+//! lines in front of each message and the message indented. Only the message
+//! lines are formatted, the rest is left as it is. `--graph`, `--stat` and
+//! `--patch` output and colors are understood too.
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+/// The line starting a commit, after the graph
+static COMMIT_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^(?:\x1b\[[0-9;]*m|[*|/\\_ ])*commit [0-9a-f]{7,64}\b").unwrap()
+});
+
+/// The graph and indentation in front of a line
+static PREFIX_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^(?:\x1b\[[0-9;]*m|[*|/\\_ ])*").unwrap());
+
+/// What a line of `git log` output is
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    /// Not in a `git log` in the default format, e.g. `--oneline` output
+    Line,
+    /// The commit, author and date lines, and whatever follows the message
+    Meta,
+    /// The first line of a message, which starts at the offset
+    Subject(usize),
+    /// The other lines of a message, which start at the offset
+    Body(usize),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum State {
+    Outside,
+    /// Between the commit line and the blank line before the message
+    Headers,
+    Message { subject: bool },
+    /// After the message, e.g. `--stat` or `--patch` output
+    After,
+}
+
+/// Tells the parts of `git log` output apart, line by line
+pub struct Classifier {
+    state: State,
+}
+
+impl Default for Classifier {
+    fn default() -> Self {
+        Classifier {
+            state: State::Outside,
+        }
+    }
+}
+
+impl Classifier {
+    pub fn classify(&mut self, line: &str) -> Part {
+        if COMMIT_RE.is_match(line) {
+            self.state = State::Headers;
+            return Part::Meta;
+        }
+        let prefix = PREFIX_RE.find(line).map_or(0, |m| m.end());
+        let indented = line[..prefix].ends_with("    ");
+        match self.state {
+            State::Outside => Part::Line,
+            State::Headers => {
+                if prefix == line.len() {
+                    self.state = State::Message { subject: false };
+                }
+                Part::Meta
+            }
+            State::Message { subject } if indented => {
+                // Empty message lines are only indented, their offset is
+                // the end of the line
+                let at = line.len().min(line[..prefix].rfind("    ").unwrap() + 4);
+                self.state = State::Message { subject: true };
+                match subject {
+                    false => Part::Subject(at),
+                    true => Part::Body(at),
+                }
+            }
+            State::Message { .. } | State::After => {
+                self.state = State::After;
+                Part::Meta
+            }
+        }
+    }
+}
+
+/// Whether `text` is `git log` output in the default format
+pub fn is_git_log(text: &str) -> bool {
+    text.lines().next().is_some_and(|line| COMMIT_RE.is_match(line))
+}
@@ -0,0 +1,73 @@
+use unicode_width::UnicodeWidthStr;
+
+use crate::config::Config;
+use crate::devmoji::Devmoji;
+use crate::{git, tr};
+
+const START: &str = "---- devmoji ----";
+const END: &str = "---- end of devmoji ----";
+
+/// Add a cheat sheet of the types, with their emoji and descriptions, to a
+/// commit message file as comment lines. It goes after the message and git's
+/// own comments, in front of the scissors line, and replaces an earlier one.
+pub fn add(text: &str, dm: &Devmoji, cfg: &Config, comment: &str) -> String {
+    let text = strip(text, comment);
+    let (head, tail) = git::split_scissors(&text, comment);
+    let mut result = head.to_string();
+    if !result.is_empty() && !result.ends_with('\n') {
+        result.push('\n');
+    }
+    result.push_str(&render(dm, cfg, comment));
+    result.push_str(tail);
+    result
+}
+
+/// Remove the cheat sheet `add` put in a commit message file, if any
+pub fn strip(text: &str, comment: &str) -> String {
+    let is = |line: &str, marker: &str| {
+        line.trim_end_matches(['\r', '\n']) == format!("{} {}", comment, marker)
+    };
+    let lines: Vec<&str> = text.split_inclusive('\n').collect();
+    let Some(start) = lines.iter().position(|line| is(line, START)) else {
+        return text.to_string();
+    };
+    let Some(end) = lines[start..].iter().position(|line| is(line, END)) else {
+        return text.to_string();
+    };
+    lines[..start]
+        .iter()
+        .chain(&lines[start + end + 1..])
+        .copied()
+        .collect()
+}
+
+fn render(dm: &Devmoji, cfg: &Config, comment: &str) -> String {
+    let emoji = |commit_type: &str| dm.pack_emoji(commit_type).unwrap_or("");
+    // Emoji are two columns wide, literal entries may be wider
+    let emoji_width = cfg.types.iter().map(|t| emoji(t).width()).max().unwrap_or(0).max(2);
+    let type_width = cfg.types.iter().map(|t| t.width()).max().unwrap_or(0);
+    let mut block = format!("{} {}\n", comment, START);
+    block.push_str(&format!(
+        "{} {}\n",
+        comment,
+        tr!("type(scope): description, with one of these types:")
+    ));
+    for commit_type in &cfg.types {
+        let entry = dm.pack().iter().find(|e| &e.code == commit_type);
+        let emoji = emoji(commit_type);
+        let description = entry.map_or("", |e| e.description.as_str());
+        let line = format!(
+            "{}   {}{} {}{}  {}",
+            comment,
+            emoji,
+            " ".repeat(emoji_width - emoji.width()),
+            commit_type,
+            " ".repeat(type_width - commit_type.width()),
+            description
+        );
+        block.push_str(line.trim_end());
+        block.push('\n');
+    }
+    block.push_str(&format!("{} {}\n", comment, END));
+    block
+}
@@ -0,0 +1,194 @@
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use crate::{debug, git, info};
+
+const HOOK_NAME: &str = "prepare-commit-msg";
+const HOOK_MARKER: &str = "# Installed by devmoji";
+const HOOK_SCRIPT: &str = "#!/bin/sh\n# Installed by devmoji\nexec devmoji --edit \"$1\"\n";
+
+/// Editor command git runs once the hook is installed. Messages only go
+/// through devmoji while a rebase is running, other commits and tags open the
+/// user's editor as before, also when devmoji isn't on the PATH.
+const EDITOR_WRAPPER: &str = concat!(
+    r#"f() { if [ -d "$(git rev-parse --git-path rebase-merge)" ] && "#,
+    r#"command -v devmoji >/dev/null; then devmoji hook rebase-msg "$1"; "#,
+    r#"else eval "$(git config devmoji.editor || git config --global core.editor || "#,
+    r#"git config --system core.editor || echo "${VISUAL:-${EDITOR:-vi}}")" '"$1"'; fi; }; f"#
+);
+/// The wrapper earlier versions installed, which ran devmoji for every message
+const OLD_EDITOR_WRAPPER: &str = "devmoji hook rebase-msg";
+/// Git config key remembering the editor this repository configured before
+/// install
+const EDITOR_KEY: &str = "devmoji.editor";
+
+fn is_wrapper(editor: &str) -> bool {
+    editor == EDITOR_WRAPPER || editor == OLD_EDITOR_WRAPPER
+}
+
+fn hooks_dir() -> Result<PathBuf, String> {
+    if let Some(path) = git::config_get("core.hooksPath") {
+        return Ok(PathBuf::from(path));
+    }
+    let git_dir = git::find_git_dir().ok_or_else(|| "Could not find .git directory".to_string())?;
+    Ok(git_dir.join("hooks"))
+}
+
+/// What `hook install` set up in the current repository
+pub struct Status {
+    pub hook: PathBuf,
+    /// The hook exists and runs devmoji
+    pub installed: bool,
+    /// A hook devmoji didn't write is in its place
+    pub foreign: bool,
+    pub executable: bool,
+    /// `core.editor` is the rebase editor wrapper
+    pub editor: bool,
+}
+
+pub fn status() -> Result<Status, String> {
+    let hook = hooks_dir()?.join(HOOK_NAME);
+    let existing = std::fs::read_to_string(&hook).ok();
+    Ok(Status {
+        installed: existing.as_deref().is_some_and(|s| s.contains(HOOK_MARKER)),
+        foreign: existing.as_deref().is_some_and(|s| !s.contains(HOOK_MARKER)),
+        executable: is_executable(&hook),
+        editor: git::config_get("core.editor").as_deref() == Some(EDITOR_WRAPPER),
+        hook,
+    })
+}
+
+/// Install the prepare-commit-msg hook and wrap `core.editor` so messages
+/// edited during a rebase are formatted as well. Only an editor set in this
+/// repository is remembered, uninstall puts it back.
+pub fn install(force: bool) -> Result<Vec<String>, String> {
+    let dir = hooks_dir()?;
+    let hook = dir.join(HOOK_NAME);
+    let mut done = Vec::new();
+
+    if let Ok(existing) = std::fs::read_to_string(&hook) {
+        if !existing.contains(HOOK_MARKER) && !force {
+            return Err(format!(
+                "{} already exists, use --force to overwrite it",
+                hook.display()
+            ));
+        }
+    }
+
+    std::fs::create_dir_all(&dir).map_err(|e| format!("Error creating {}: {}", dir.display(), e))?;
+    std::fs::write(&hook, HOOK_SCRIPT).map_err(|e| format!("Error writing {}: {}", hook.display(), e))?;
+    make_executable(&hook).map_err(|e| format!("Error writing {}: {}", hook.display(), e))?;
+    done.push(format!("Installed {}", hook.display()));
+
+    let editor = git::config_get_from("local", "core.editor");
+    if editor.as_deref() != Some(EDITOR_WRAPPER) {
+        if let Some(editor) = editor.filter(|e| !is_wrapper(e)) {
+            git::config_set(EDITOR_KEY, &editor).map_err(|e| e.to_string())?;
+        }
+        git::config_set("core.editor", EDITOR_WRAPPER).map_err(|e| e.to_string())?;
+        done.push("Set core.editor to the rebase editor wrapper".to_string());
+    }
+
+    Ok(done)
+}
+
+/// Remove the hook and restore the previous `core.editor` of the repository,
+/// or remove it from the repository config when there was none.
+pub fn uninstall() -> Result<Vec<String>, String> {
+    let hook = hooks_dir()?.join(HOOK_NAME);
+    let mut done = Vec::new();
+
+    if let Ok(existing) = std::fs::read_to_string(&hook) {
+        if existing.contains(HOOK_MARKER) {
+            std::fs::remove_file(&hook).map_err(|e| format!("Error removing {}: {}", hook.display(), e))?;
+            done.push(format!("Removed {}", hook.display()));
+        }
+    }
+
+    if git::config_get_from("local", "core.editor").is_some_and(|e| is_wrapper(&e)) {
+        match git::config_get(EDITOR_KEY) {
+            Some(editor) => {
+                git::config_set("core.editor", &editor).map_err(|e| e.to_string())?;
+                git::config_unset(EDITOR_KEY).map_err(|e| e.to_string())?;
+                done.push(format!("Restored core.editor to \"{}\"", editor));
+            }
+            None => {
+                git::config_unset("core.editor").map_err(|e| e.to_string())?;
+                done.push("Removed core.editor from the repository config".to_string());
+            }
+        }
+    }
+
+    Ok(done)
+}
+
+/// Editor entry point: while a rebase is running, format every message in
+/// `file` (including each part of a squash combination), then open the
+/// user's real editor on it. Returns the editor's exit code.
+pub fn rebase_msg(file: &Path, format: impl Fn(&str) -> String) -> Result<i32, String> {
+    let in_rebase = git::find_git_dir().is_some_and(|dir| git::rebase_in_progress(&dir));
+
+    if in_rebase && !git::is_rebase_todo(file) {
+        let text = std::fs::read_to_string(file)
+            .map_err(|e| format!("Error reading {}: {}", file.display(), e))?;
+        let formatted = git::map_squash_messages(&text, format);
+        info!("hook", "formatting {}", file.display());
+        if formatted != text {
+            std::fs::write(file, formatted)
+                .map_err(|e| format!("Error writing {}: {}", file.display(), e))?;
+        }
+    } else {
+        debug!("hook", "not formatting {}, no rebase message", file.display());
+    }
+
+    run_editor(file)
+}
+
+/// Open the editor git would have opened without the wrapper: the one this
+/// repository had, `$GIT_EDITOR`, the global or system `core.editor`, then
+/// `$VISUAL` and `$EDITOR`
+fn run_editor(file: &Path) -> Result<i32, String> {
+    let usable = |editor: Option<String>| editor.filter(|e| !e.is_empty() && !is_wrapper(e));
+    let editor = usable(git::config_get(EDITOR_KEY))
+        .or_else(|| usable(std::env::var("GIT_EDITOR").ok()))
+        .or_else(|| usable(git::config_get_from("global", "core.editor")))
+        .or_else(|| usable(git::config_get_from("system", "core.editor")))
+        .or_else(|| usable(std::env::var("VISUAL").ok()))
+        .or_else(|| usable(std::env::var("EDITOR").ok()))
+        .unwrap_or_else(|| "vi".to_string());
+
+    info!("hook", "running editor {:?}", editor);
+    // Run through the shell like git does, so editors with arguments work
+    let status = Command::new("sh")
+        .arg("-c")
+        .arg(format!("{} \"$@\"", editor))
+        .arg(&editor)
+        .arg(file)
+        .status()
+        .map_err(|e| format!("Error running {}: {}", editor, e))?;
+    Ok(status.code().unwrap_or(1))
+}
+
+#[cfg(unix)]
+fn make_executable(path: &Path) -> std::io::Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+    let mut perms = std::fs::metadata(path)?.permissions();
+    perms.set_mode(0o755);
+    std::fs::set_permissions(path, perms)
+}
+
+#[cfg(not(unix))]
+fn make_executable(_path: &Path) -> std::io::Result<()> {
+    Ok(())
+}
+
+#[cfg(unix)]
+fn is_executable(path: &Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+    std::fs::metadata(path).is_ok_and(|m| m.permissions().mode() & 0o111 != 0)
+}
+
+#[cfg(not(unix))]
+fn is_executable(path: &Path) -> bool {
+    path.exists()
+}
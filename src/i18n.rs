@@ -0,0 +1,437 @@
+use std::ffi::OsString;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// Environment variable selecting the language, e.g. `DEVMOJI_LANG=cs`
+pub const ENV_VAR: &str = "DEVMOJI_LANG";
+
+static LANG: AtomicU8 = AtomicU8::new(0);
+
+/// Language of lint messages, `--list` descriptions and help text. Messages
+/// without a translation are shown in English.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum)]
+pub enum Lang {
+    #[default]
+    En = 0,
+    /// Czech
+    Cs = 1,
+    /// German
+    De = 2,
+}
+
+impl Lang {
+    fn from_u8(n: u8) -> Self {
+        match n {
+            1 => Lang::Cs,
+            2 => Lang::De,
+            _ => Lang::En,
+        }
+    }
+
+    /// Parse a language code or locale name like `cs`, `de-AT` or `cs_CZ.UTF-8`
+    pub fn parse(value: &str) -> Option<Self> {
+        let code = value.trim().split(['_', '-', '.']).next()?;
+        match code.to_ascii_lowercase().as_str() {
+            "en" | "c" | "posix" => Some(Lang::En),
+            "cs" => Some(Lang::Cs),
+            "de" => Some(Lang::De),
+            _ => None,
+        }
+    }
+
+    /// The code config files key translations by
+    pub fn code(self) -> &'static str {
+        match self {
+            Lang::En => "en",
+            Lang::Cs => "cs",
+            Lang::De => "de",
+        }
+    }
+
+    fn catalog(self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            Lang::En => &[],
+            Lang::Cs => CS,
+            Lang::De => DE,
+        }
+    }
+}
+
+/// Use `lang`, or `DEVMOJI_LANG` when `None`. English is used when
+/// `DEVMOJI_LANG` is unsupported, the `Err` says so.
+pub fn init(lang: Option<Lang>) -> Result<(), String> {
+    let (lang, result) = match (lang, std::env::var(ENV_VAR)) {
+        (Some(lang), _) => (lang, Ok(())),
+        (None, Ok(value)) => match Lang::parse(&value) {
+            Some(lang) => (lang, Ok(())),
+            None => (
+                Lang::En,
+                Err(format!("unsupported {}={}, expected en, cs or de", ENV_VAR, value)),
+            ),
+        },
+        (None, Err(_)) => (Lang::En, Ok(())),
+    };
+    LANG.store(lang as u8, Ordering::Relaxed);
+    result
+}
+
+pub fn current() -> Lang {
+    Lang::from_u8(LANG.load(Ordering::Relaxed))
+}
+
+/// The translation of an English message, or the message itself
+pub fn translate(message: &str) -> &str {
+    current()
+        .catalog()
+        .iter()
+        .find(|(en, _)| *en == message)
+        .map_or(message, |(_, translated)| translated)
+}
+
+/// Fill in the `{name}` placeholders of a translated message. Use the `tr!`
+/// macro instead of calling this directly.
+pub fn fill(message: &str, args: &[(&str, &str)]) -> String {
+    let mut result = message.to_string();
+    for (name, value) in args {
+        result = result.replace(&format!("{{{}}}", name), value);
+    }
+    result
+}
+
+/// The `--lang` the command line asks for, before it is parsed: help text has
+/// to be translated first.
+pub fn lang_arg(args: &[OsString]) -> Option<Lang> {
+    let mut args = args.iter().filter_map(|arg| arg.to_str());
+    while let Some(arg) = args.next() {
+        if let Some(value) = arg.strip_prefix("--lang=") {
+            return Lang::parse(value);
+        }
+        if arg == "--lang" {
+            return args.next().and_then(Lang::parse);
+        }
+    }
+    None
+}
+
+/// Translate the about and help texts of a command and its subcommands
+pub fn localize(command: clap::Command) -> clap::Command {
+    if current() == Lang::En {
+        return command;
+    }
+    let about = command.get_about().map(ToString::to_string);
+    let command = match about {
+        Some(about) => command.about(translate(&about).to_string()),
+        None => command,
+    };
+    command
+        .mut_args(|arg| match arg.get_help().map(ToString::to_string) {
+            Some(help) => {
+                let help = translate(&help).to_string();
+                arg.help(help)
+            }
+            None => arg,
+        })
+        .mut_subcommands(localize)
+}
+
+/// Translate a message, filling in `name = value` placeholders
+#[macro_export]
+macro_rules! tr {
+    ($message:expr) => {
+        $crate::i18n::translate($message).to_string()
+    };
+    ($message:expr, $($name:ident = $value:expr),+ $(,)?) => {
+        $crate::i18n::fill(
+            $crate::i18n::translate($message),
+            &[$((stringify!($name), &$value.to_string())),+],
+        )
+    };
+}
+
+static CS: &[(&str, &str)] = &[
+    // Lint
+    (
+        "Gitmoji-style headers are not allowed, use: type(scope): description",
+        "Hlavičky ve stylu gitmoji nejsou povoleny, použijte: typ(rozsah): popis",
+    ),
+    ("Expecting a commit message like: {template}", "Očekávána zpráva commitu ve tvaru: {template}"),
+    ("Missing description", "Chybí popis"),
+    ("warning: {message}", "varování: {message}"),
+    ("{count} warnings, more than the {max} allowed", "{count} varování, povoleno je nejvýše {max}"),
+    ("Line {line} is longer than {max} characters", "Řádek {line} je delší než {max} znaků"),
+    (
+        "Footer '{footer}' should look like: Token: value",
+        "Patička '{footer}' by měla vypadat takto: Token: hodnota",
+    ),
+    ("Footers should not have emoji: {footer}", "Patičky by neměly obsahovat emoji: {footer}"),
+    ("Description should not end with a period", "Popis by neměl končit tečkou"),
+    ("Missing space after the colon", "Chybí mezera za dvojtečkou"),
+    ("Type should be one of: {types}", "Typ by měl být jeden z: {types}"),
+    (
+        "Type should be one of: {types} (did you mean '{suggestion}'?)",
+        "Typ by měl být jeden z: {types} (mysleli jste '{suggestion}'?)",
+    ),
+    ("Missing scope", "Chybí rozsah"),
+    ("Missing scope, use one of: {scopes}", "Chybí rozsah, použijte jeden z: {scopes}"),
+    ("Scope should be one of: {scopes}", "Rozsah by měl být jeden z: {scopes}"),
+    (
+        "Scope should be one of: {scopes} (did you mean '{suggestion}'?)",
+        "Rozsah by měl být jeden z: {scopes} (mysleli jste '{suggestion}'?)",
+    ),
+    (
+        "! in the header without a BREAKING CHANGE footer",
+        "! v hlavičce bez patičky BREAKING CHANGE",
+    ),
+    (
+        "BREAKING CHANGE footer without a ! in the header",
+        "Patička BREAKING CHANGE bez ! v hlavičce",
+    ),
+    (
+        "Unknown shortcode :{code}:, did you mean :{suggestion}:?",
+        "Neznámý kód :{code}:, mysleli jste :{suggestion}:?",
+    ),
+    ("{message} (accepted without --spec)", "{message} (bez --spec povoleno)"),
+    ("Line {line}: {message}", "Řádek {line}: {message}"),
+    (
+        "The body must begin one blank line after the description",
+        "Tělo musí začínat po jednom prázdném řádku za popisem",
+    ),
+    (
+        "Footer token '{token}' must use - in place of whitespace, e.g. '{suggestion}'",
+        "Klíč patičky '{token}' musí místo mezer používat -, např. '{suggestion}'",
+    ),
+    ("BREAKING CHANGE must be uppercase", "BREAKING CHANGE musí být velkými písmeny"),
+    (
+        "BREAKING CHANGE must start at the beginning of the line",
+        "BREAKING CHANGE musí být na začátku řádku",
+    ),
+    (
+        "BREAKING CHANGE must be followed by a colon, a space and a description",
+        "Za BREAKING CHANGE musí následovat dvojtečka, mezera a popis",
+    ),
+    (
+        "BREAKING CHANGE must be a footer after the body",
+        "BREAKING CHANGE musí být patička za tělem",
+    ),
+    (
+        "Expecting a header like: type(scope)!: description",
+        "Očekávána hlavička ve tvaru: typ(rozsah)!: popis",
+    ),
+    (
+        "Type '{commit_type}' must be a noun made of letters only",
+        "Typ '{commit_type}' musí být podstatné jméno jen z písmen",
+    ),
+    (
+        "The colon must be followed by exactly one space and the description",
+        "Za dvojtečkou musí následovat právě jedna mezera a popis",
+    ),
+    // Fixes
+    ("Fixed {fix}", "Opraveno: {fix}"),
+    ("type {from} -> {to}", "typ {from} -> {to}"),
+    ("trailing period", "tečka na konci"),
+    ("space after the colon", "mezera za dvojtečkou"),
+    // Devmoji descriptions
+    ("a new feature", "nová funkce"),
+    ("a bug fix", "oprava chyby"),
+    ("documentation only changes", "změny jen v dokumentaci"),
+    (
+        "changes that do not affect the meaning of the code",
+        "změny, které nemění význam kódu",
+    ),
+    (
+        "a code change that neither fixes a bug nor adds a feature",
+        "změna kódu, která neopravuje chybu ani nepřidává funkci",
+    ),
+    ("a code change that improves performance", "změna kódu, která zlepšuje výkon"),
+    ("adding missing or correcting existing tests", "přidání chybějících nebo oprava stávajících testů"),
+    ("changes to the build process or auxiliary tools", "změny procesu sestavení nebo pomocných nástrojů"),
+    (
+        "code deployment or publishing to external repositories",
+        "nasazení kódu nebo publikování do externích repozitářů",
+    ),
+    ("add or delete dependencies", "přidání nebo odebrání závislostí"),
+    ("changes related to build processes", "změny související se sestavením"),
+    ("updates to the continuous integration system", "úpravy systému průběžné integrace"),
+    ("fixing security issues", "oprava bezpečnostních problémů"),
+    ("internationalization and localization", "internacionalizace a lokalizace"),
+    ("introducing breaking changes", "zavedení nekompatibilních změn"),
+    ("changing configuration files", "změny konfiguračních souborů"),
+    ("add something", "něco přidat"),
+    ("remove something", "něco odebrat"),
+    // --group-by heading of devmojis without a type
+    ("other", "ostatní"),
+    // --hints cheat sheet
+    (
+        "type(scope): description, with one of these types:",
+        "typ(rozsah): popis, s jedním z těchto typů:",
+    ),
+    // Help
+    ("Emojify conventional commits", "Přidá emoji do konvenčních commitů"),
+    ("List all known devmojis", "Vypíše všechny známé devmoji"),
+    ("Lint the conventional commit", "Zkontroluje konvenční commit"),
+    (
+        "With --lint, fix what can be fixed (misspelled or uppercase types, space after the colon, trailing period) instead of failing",
+        "S --lint opraví, co jde (překlepy a velká písmena v typu, mezeru za dvojtečkou, tečku na konci), místo selhání",
+    ),
+    (
+        "Read and edit a commit message file. Accepts a path or a git dir file name like MERGE_MSG [default: most recent of COMMIT_EDITMSG, MERGE_MSG, SQUASH_MSG and rebase-merge/message]",
+        "Načte a upraví soubor se zprávou commitu. Přijímá cestu nebo název souboru v adresáři gitu, např. MERGE_MSG [výchozí: nejnovější z COMMIT_EDITMSG, MERGE_MSG, SQUASH_MSG a rebase-merge/message]",
+    ),
+    (
+        "Don't write anything, exit with 1 and print a diff if the input is not already formatted",
+        "Nic nezapisuje, skončí s kódem 1 a vypíše rozdíl, pokud vstup ještě není naformátovaný",
+    ),
+    ("Manage the git hooks that run devmoji", "Správa git hooků, které spouštějí devmoji"),
+    (
+        "Language of messages, descriptions and help (also DEVMOJI_LANG)",
+        "Jazyk zpráv, popisů a nápovědy (také DEVMOJI_LANG)",
+    ),
+];
+
+static DE: &[(&str, &str)] = &[
+    // Lint
+    (
+        "Gitmoji-style headers are not allowed, use: type(scope): description",
+        "Kopfzeilen im Gitmoji-Stil sind nicht erlaubt, verwende: typ(bereich): beschreibung",
+    ),
+    (
+        "Expecting a commit message like: {template}",
+        "Erwartet wird eine Commit-Nachricht wie: {template}",
+    ),
+    ("Missing description", "Beschreibung fehlt"),
+    ("warning: {message}", "Warnung: {message}"),
+    ("{count} warnings, more than the {max} allowed", "{count} Warnungen, erlaubt sind höchstens {max}"),
+    ("Line {line} is longer than {max} characters", "Zeile {line} ist länger als {max} Zeichen"),
+    (
+        "Footer '{footer}' should look like: Token: value",
+        "Die Fußzeile '{footer}' sollte so aussehen: Token: Wert",
+    ),
+    ("Footers should not have emoji: {footer}", "Fußzeilen sollten keine Emoji enthalten: {footer}"),
+    ("Description should not end with a period", "Die Beschreibung sollte nicht mit einem Punkt enden"),
+    ("Missing space after the colon", "Leerzeichen nach dem Doppelpunkt fehlt"),
+    ("Type should be one of: {types}", "Der Typ sollte einer von diesen sein: {types}"),
+    (
+        "Type should be one of: {types} (did you mean '{suggestion}'?)",
+        "Der Typ sollte einer von diesen sein: {types} (meintest du '{suggestion}'?)",
+    ),
+    ("Missing scope", "Bereich fehlt"),
+    ("Missing scope, use one of: {scopes}", "Bereich fehlt, verwende einen von: {scopes}"),
+    ("Scope should be one of: {scopes}", "Der Bereich sollte einer von diesen sein: {scopes}"),
+    (
+        "Scope should be one of: {scopes} (did you mean '{suggestion}'?)",
+        "Der Bereich sollte einer von diesen sein: {scopes} (meintest du '{suggestion}'?)",
+    ),
+    (
+        "! in the header without a BREAKING CHANGE footer",
+        "! in der Kopfzeile ohne BREAKING CHANGE-Fußzeile",
+    ),
+    (
+        "BREAKING CHANGE footer without a ! in the header",
+        "BREAKING CHANGE-Fußzeile ohne ! in der Kopfzeile",
+    ),
+    (
+        "Unknown shortcode :{code}:, did you mean :{suggestion}:?",
+        "Unbekannter Shortcode :{code}:, meintest du :{suggestion}:?",
+    ),
+    ("{message} (accepted without --spec)", "{message} (ohne --spec erlaubt)"),
+    ("Line {line}: {message}", "Zeile {line}: {message}"),
+    (
+        "The body must begin one blank line after the description",
+        "Der Textkörper muss eine Leerzeile nach der Beschreibung beginnen",
+    ),
+    (
+        "Footer token '{token}' must use - in place of whitespace, e.g. '{suggestion}'",
+        "Das Fußzeilen-Token '{token}' muss - statt Leerzeichen verwenden, z. B. '{suggestion}'",
+    ),
+    ("BREAKING CHANGE must be uppercase", "BREAKING CHANGE muss großgeschrieben werden"),
+    (
+        "BREAKING CHANGE must start at the beginning of the line",
+        "BREAKING CHANGE muss am Zeilenanfang stehen",
+    ),
+    (
+        "BREAKING CHANGE must be followed by a colon, a space and a description",
+        "Auf BREAKING CHANGE müssen ein Doppelpunkt, ein Leerzeichen und eine Beschreibung folgen",
+    ),
+    (
+        "BREAKING CHANGE must be a footer after the body",
+        "BREAKING CHANGE muss eine Fußzeile nach dem Textkörper sein",
+    ),
+    (
+        "Expecting a header like: type(scope)!: description",
+        "Erwartet wird eine Kopfzeile wie: typ(bereich)!: beschreibung",
+    ),
+    (
+        "Type '{commit_type}' must be a noun made of letters only",
+        "Der Typ '{commit_type}' muss ein Substantiv nur aus Buchstaben sein",
+    ),
+    (
+        "The colon must be followed by exactly one space and the description",
+        "Auf den Doppelpunkt müssen genau ein Leerzeichen und die Beschreibung folgen",
+    ),
+    // Fixes
+    ("Fixed {fix}", "Korrigiert: {fix}"),
+    ("type {from} -> {to}", "Typ {from} -> {to}"),
+    ("trailing period", "Punkt am Ende"),
+    ("space after the colon", "Leerzeichen nach dem Doppelpunkt"),
+    // Devmoji descriptions
+    ("a new feature", "eine neue Funktion"),
+    ("a bug fix", "eine Fehlerbehebung"),
+    ("documentation only changes", "nur Änderungen an der Dokumentation"),
+    (
+        "changes that do not affect the meaning of the code",
+        "Änderungen, die die Bedeutung des Codes nicht beeinflussen",
+    ),
+    (
+        "a code change that neither fixes a bug nor adds a feature",
+        "eine Codeänderung, die weder einen Fehler behebt noch eine Funktion hinzufügt",
+    ),
+    ("a code change that improves performance", "eine Codeänderung, die die Leistung verbessert"),
+    (
+        "adding missing or correcting existing tests",
+        "fehlende Tests hinzufügen oder bestehende korrigieren",
+    ),
+    (
+        "changes to the build process or auxiliary tools",
+        "Änderungen am Build-Prozess oder an Hilfswerkzeugen",
+    ),
+    (
+        "code deployment or publishing to external repositories",
+        "Auslieferung von Code oder Veröffentlichung in externen Repositories",
+    ),
+    ("add or delete dependencies", "Abhängigkeiten hinzufügen oder entfernen"),
+    ("changes related to build processes", "Änderungen rund um den Build"),
+    ("updates to the continuous integration system", "Aktualisierungen der Continuous Integration"),
+    ("fixing security issues", "Sicherheitsprobleme beheben"),
+    ("internationalization and localization", "Internationalisierung und Lokalisierung"),
+    ("introducing breaking changes", "inkompatible Änderungen einführen"),
+    ("changing configuration files", "Konfigurationsdateien ändern"),
+    ("add something", "etwas hinzufügen"),
+    ("remove something", "etwas entfernen"),
+    // --group-by heading of devmojis without a type
+    ("other", "sonstige"),
+    // --hints cheat sheet
+    (
+        "type(scope): description, with one of these types:",
+        "Typ(Bereich): Beschreibung, mit einem dieser Typen:",
+    ),
+    // Help
+    ("Emojify conventional commits", "Versieht Conventional Commits mit Emoji"),
+    ("List all known devmojis", "Alle bekannten Devmojis auflisten"),
+    ("Lint the conventional commit", "Den Conventional Commit prüfen"),
+    (
+        "With --lint, fix what can be fixed (misspelled or uppercase types, space after the colon, trailing period) instead of failing",
+        "Mit --lint korrigieren, was sich korrigieren lässt (falsch oder großgeschriebene Typen, Leerzeichen nach dem Doppelpunkt, Punkt am Ende), statt fehlzuschlagen",
+    ),
+    (
+        "Read and edit a commit message file. Accepts a path or a git dir file name like MERGE_MSG [default: most recent of COMMIT_EDITMSG, MERGE_MSG, SQUASH_MSG and rebase-merge/message]",
+        "Eine Commit-Nachrichtendatei lesen und bearbeiten. Nimmt einen Pfad oder einen Dateinamen im Git-Verzeichnis wie MERGE_MSG [Standard: die neueste von COMMIT_EDITMSG, MERGE_MSG, SQUASH_MSG und rebase-merge/message]",
+    ),
+    (
+        "Don't write anything, exit with 1 and print a diff if the input is not already formatted",
+        "Nichts schreiben, mit 1 beenden und einen Diff ausgeben, wenn die Eingabe noch nicht formatiert ist",
+    ),
+    ("Manage the git hooks that run devmoji", "Die Git-Hooks verwalten, die devmoji ausführen"),
+    (
+        "Language of messages, descriptions and help (also DEVMOJI_LANG)",
+        "Sprache von Meldungen, Beschreibungen und Hilfe (auch DEVMOJI_LANG)",
+    ),
+];
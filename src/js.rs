@@ -0,0 +1,168 @@
+//! JS and TS configs. Evaluating them needs the `js` feature, without it they
+//! are still found but fail to load.
+
+use std::path::Path;
+#[cfg(feature = "js")]
+use std::path::PathBuf;
+#[cfg(feature = "js")]
+use std::process::Command;
+
+#[cfg(feature = "js")]
+use crate::{debug, info};
+
+/// Extensions of configs that are evaluated instead of parsed
+pub const CONFIG_EXTENSIONS: &[&str] = &["js", "cjs", "mjs", "ts", "cts", "mts"];
+
+#[cfg(feature = "js")]
+const TS_EXTENSIONS: &[&str] = &["ts", "cts", "mts"];
+
+/// Imports the config given as first argument and prints its export as JSON.
+/// The export may be the config itself, or a (async) function returning it.
+#[cfg(feature = "js")]
+const EVAL_SCRIPT: &str = r#"
+const { pathToFileURL } = require("url");
+import(pathToFileURL(process.argv[1]).href)
+  .then((m) => (m.default !== undefined ? m.default : m))
+  .then((c) => (typeof c === "function" ? c() : c))
+  .then((c) => process.stdout.write(JSON.stringify(c)))
+  .catch((e) => {
+    console.error(e && e.stack ? e.stack : e);
+    process.exit(1);
+  });
+"#;
+
+pub fn is_js_config(path: &Path) -> bool {
+    path.extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|e| CONFIG_EXTENSIONS.contains(&e))
+}
+
+/// Evaluate a JS or TS config and return its export as JSON. With the
+/// `js-engine` feature simple configs are evaluated in-process, Node (tsx for
+/// TypeScript) is only needed for configs using Node APIs or type annotations.
+#[cfg(feature = "js")]
+pub fn evaluate(path: &Path) -> Result<String, String> {
+    #[cfg(feature = "js-engine")]
+    {
+        let source = std::fs::read_to_string(path).map_err(|e| e.to_string())?;
+        match embedded::evaluate(path, &source) {
+            Ok(json) => {
+                info!("js", "evaluated {} with the embedded engine", path.display());
+                return Ok(json);
+            }
+            Err(e) => debug!("js", "embedded engine failed, falling back to Node: {}", e.trim_end()),
+        }
+    }
+    evaluate_node(path)
+}
+
+#[cfg(feature = "js")]
+fn evaluate_node(path: &Path) -> Result<String, String> {
+    let program = runtime(path);
+
+    info!("js", "running {} -e <script> {}", program.display(), path.display());
+    let output = Command::new(&program)
+        .arg("-e")
+        .arg(EVAL_SCRIPT)
+        .arg(path)
+        .output()
+        .map_err(|e| format!("could not run {}: {}", program.display(), e))?;
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    if !stderr.trim().is_empty() {
+        debug!("js", "{} stderr:\n{}", program.display(), stderr.trim_end());
+    }
+    if !output.status.success() {
+        return Err(format!(
+            "{} failed ({}): {}",
+            program.display(),
+            output.status,
+            stderr.lines().next().unwrap_or("").trim()
+        ));
+    }
+    String::from_utf8(output.stdout).map_err(|e| e.to_string())
+}
+
+/// The program evaluating a config: Node, or tsx for TypeScript
+#[cfg(feature = "js")]
+pub fn runtime(config: &Path) -> PathBuf {
+    let is_ts = config
+        .extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|e| TS_EXTENSIONS.contains(&e));
+    if is_ts {
+        find_bin(config, "tsx")
+    } else {
+        PathBuf::from("node")
+    }
+}
+
+/// A locally installed binary from the nearest `node_modules/.bin`, else the
+/// one on the PATH.
+#[cfg(feature = "js")]
+fn find_bin(config: &Path, name: &str) -> PathBuf {
+    config
+        .ancestors()
+        .skip(1)
+        .map(|dir| dir.join("node_modules").join(".bin").join(name))
+        .find(|bin| bin.exists())
+        .unwrap_or_else(|| PathBuf::from(name))
+}
+
+#[cfg(not(feature = "js"))]
+pub fn evaluate(_path: &Path) -> Result<String, String> {
+    Err("devmoji was built without the js feature, use a JSON config".to_string())
+}
+
+#[cfg(feature = "js-engine")]
+mod embedded {
+    use std::path::Path;
+
+    use once_cell::sync::Lazy;
+    use regex::Regex;
+    use rquickjs::{CatchResultExt, Context, Ctx, Module, Runtime, Value};
+
+    static ESM_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?m)^\s*(?:import|export)\b").unwrap());
+
+    /// Evaluate a config with QuickJS. There is no `require`, `process` or
+    /// module loader, so configs using them fail and are left to Node.
+    pub fn evaluate(path: &Path, source: &str) -> Result<String, String> {
+        let runtime = Runtime::new().map_err(|e| e.to_string())?;
+        let context = Context::full(&runtime).map_err(|e| e.to_string())?;
+        let esm = ESM_RE.is_match(source)
+            || path.extension().is_some_and(|e| e == "mjs" || e == "mts");
+
+        context.with(|ctx| {
+            let json = run(&ctx, source, esm).catch(&ctx).map_err(|e| e.to_string())?;
+            json.ok_or_else(|| "the config does not export a value".to_string())
+        })
+    }
+
+    fn run(ctx: &Ctx, source: &str, esm: bool) -> rquickjs::Result<Option<String>> {
+        let mut value: Value = if esm {
+            let (module, done) = Module::declare(ctx.clone(), "devmoji.config", source)?.eval()?;
+            done.finish::<()>()?;
+            let namespace = module.namespace()?;
+            let default: Value = namespace.get("default")?;
+            if default.is_undefined() {
+                namespace.into_value()
+            } else {
+                default
+            }
+        } else {
+            // CommonJS: give the script a `module` to assign its exports to
+            ctx.eval(format!(
+                "(function () {{ var module = {{ exports: {{}} }}; var exports = module.exports;\n{}\n;return module.exports; }})()",
+                source
+            ))?
+        };
+
+        if let Some(f) = value.as_function() {
+            value = f.call(())?;
+        }
+        if let Some(promise) = value.as_promise() {
+            value = promise.finish()?;
+        }
+        ctx.json_stringify(value)?.map(|s| s.to_string()).transpose()
+    }
+}
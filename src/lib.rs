@@ -1,5 +1,36 @@
+#[cfg(feature = "js")]
+pub mod cache;
+pub mod clipboard;
+pub mod commitizen;
 pub mod commits;
 pub mod config;
 pub mod devmoji;
+pub mod diff;
+pub mod doctor;
+pub mod error;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+pub mod filter;
+pub mod fnv;
+pub mod git;
 pub mod github_emoji;
+pub mod gitlog;
 pub mod gitmoji;
+pub mod hints;
+pub mod hook;
+pub mod i18n;
+pub mod js;
+pub mod log;
+pub mod output;
+pub mod parse;
+pub mod pick;
+pub mod precommit;
+pub mod reporter;
+pub mod rewrite;
+pub mod search;
+pub mod snippets;
+pub mod spec;
+pub mod stats;
+pub mod theme;
+pub mod tickets;
+pub mod utf8;
@@ -0,0 +1,112 @@
+use std::fmt;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+use crate::theme::Colorize;
+
+/// Environment variable selecting the log level, e.g. `DEVMOJI_LOG=debug`
+pub const ENV_VAR: &str = "DEVMOJI_LOG";
+
+static LEVEL: AtomicU8 = AtomicU8::new(0);
+
+/// How much diagnostic output goes to stderr
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Off = 0,
+    /// Invalid config and environment that is ignored
+    Warn = 1,
+    /// Decisions: config file chosen, files read and written, commands run
+    Info = 2,
+    /// Why something was skipped or ignored
+    Debug = 3,
+    /// Per line and per match details
+    Trace = 4,
+}
+
+impl Level {
+    fn from_u8(n: u8) -> Self {
+        match n {
+            0 => Level::Off,
+            1 => Level::Warn,
+            2 => Level::Info,
+            3 => Level::Debug,
+            _ => Level::Trace,
+        }
+    }
+
+    fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "" | "off" | "0" => Some(Level::Off),
+            "warn" => Some(Level::Warn),
+            "info" | "1" => Some(Level::Info),
+            "debug" | "2" => Some(Level::Debug),
+            "trace" | "3" => Some(Level::Trace),
+            _ => None,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            Level::Off => "",
+            Level::Warn => "warn",
+            Level::Info => "info",
+            Level::Debug => "debug",
+            Level::Trace => "trace",
+        }
+    }
+}
+
+/// Set the level from the number of `-v` flags, or `DEVMOJI_LOG` when it asks
+/// for more. Warnings are always shown, until then nothing is, so the library
+/// is quiet unless its user asks for output.
+pub fn init(verbose: u8) {
+    let value = std::env::var(ENV_VAR).ok();
+    let from_env = value.as_deref().map_or(Some(Level::Off), Level::parse);
+    let level = Level::from_u8(verbose.saturating_add(1)).max(from_env.unwrap_or(Level::Off));
+    LEVEL.store(level as u8, Ordering::Relaxed);
+    if from_env.is_none() {
+        let value = value.unwrap_or_default();
+        let expected = "expected off, warn, info, debug or trace";
+        crate::warn!("log", "ignoring invalid {}={}, {}", ENV_VAR, value, expected);
+    }
+}
+
+pub fn enabled(level: Level) -> bool {
+    level != Level::Off && level as u8 <= LEVEL.load(Ordering::Relaxed)
+}
+
+/// Print a diagnostic line as `devmoji[level] topic: message`. Use the
+/// `info!`, `debug!` and `trace!` macros instead of calling this directly.
+pub fn write(level: Level, topic: &str, args: fmt::Arguments) {
+    if enabled(level) {
+        let prefix = format!("devmoji[{}]", level.label());
+        eprintln!("{} {}: {}", prefix.dimmed(), topic, args);
+    }
+}
+
+#[macro_export]
+macro_rules! warn {
+    ($topic:expr, $($arg:tt)*) => {
+        $crate::log::write($crate::log::Level::Warn, $topic, format_args!($($arg)*))
+    };
+}
+
+#[macro_export]
+macro_rules! info {
+    ($topic:expr, $($arg:tt)*) => {
+        $crate::log::write($crate::log::Level::Info, $topic, format_args!($($arg)*))
+    };
+}
+
+#[macro_export]
+macro_rules! debug {
+    ($topic:expr, $($arg:tt)*) => {
+        $crate::log::write($crate::log::Level::Debug, $topic, format_args!($($arg)*))
+    };
+}
+
+#[macro_export]
+macro_rules! trace {
+    ($topic:expr, $($arg:tt)*) => {
+        $crate::log::write($crate::log::Level::Trace, $topic, format_args!($($arg)*))
+    };
+}
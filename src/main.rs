@@ -1,42 +1,178 @@
-mod commits;
-mod config;
-mod devmoji;
-mod github_emoji;
-mod gitmoji;
-
-use std::io::{self, BufRead};
-use std::path::PathBuf;
+use std::borrow::Cow;
+use std::collections::HashSet;
+use std::io::{self, BufRead, Read, Write};
+use std::path::{Path, PathBuf};
 use std::process;
 
-use clap::Parser;
-use colored::Colorize;
+use clap::{ArgAction, ArgMatches, CommandFactory, FromArgMatches, Parser, Subcommand};
+use unicode_width::UnicodeWidthStr;
 
-use commits::ConventionalCommits;
-use config::Config;
-use devmoji::Devmoji;
+use devmoji::commits::ConventionalCommits;
+use devmoji::config::{self, BreakingPolicy, Config, EmojiPlacement, EmojiTrailer};
+use devmoji::devmoji::Devmoji;
+use devmoji::error::Error;
+use devmoji::gitlog::{self, Part};
+use devmoji::output::{self, Format, Output, Target};
+use devmoji::parse::{self, ParseFormat};
+use devmoji::precommit::{self, Stage};
+use devmoji::reporter::{self, Reporter};
+use devmoji::snippets::{self, Editor};
+use devmoji::spec::Spec;
+use devmoji::stats::{self, Commit, StatsFormat};
+use devmoji::theme::{self, Colorize};
+#[cfg(feature = "js")]
+use devmoji::cache;
+use devmoji::i18n::{self, Lang};
+use devmoji::{
+    clipboard, diff, doctor, filter, git, gitmoji, hints, hook, log, pick, rewrite, search, tr,
+    trace, utf8, warn,
+};
 
 #[derive(Parser)]
 #[command(name = "devmoji", version, about = "Emojify conventional commits")]
 struct Cli {
-    /// Location of the devmoji.config.json file
+    /// Location of the project's devmoji.config.json file, merged over the
+    /// global one in ~/.config/devmoji [default: the nearest one]
     #[arg(short, long, value_name = "FILE")]
     config: Option<String>,
 
+    /// Evaluate a JS/TS config even when a cached result exists
+    #[arg(long, global = true)]
+    no_cache: bool,
+
     /// List all known devmojis
     #[arg(short, long)]
     list: bool,
 
-    /// Text to format. Reads from stdin when omitted.
+    /// Only list the devmojis of a type, e.g. feat and its scopes
+    #[arg(long = "type", value_name = "TYPE", requires = "list")]
+    list_type: Option<String>,
+
+    /// Only list the devmojis matching a search, like devmoji search
+    #[arg(long = "search", value_name = "QUERY", requires = "list")]
+    list_search: Option<String>,
+
+    /// Order of the list [default: as in the pack]
+    #[arg(long = "sort", value_enum, requires = "list")]
+    list_sort: Option<ListSort>,
+
+    /// List the devmojis under a heading per group
+    #[arg(long, value_enum, requires = "list")]
+    group_by: Option<ListGroup>,
+
+    /// Only list the shortcodes, one per line, e.g. for scripts
+    #[arg(long, requires = "list", conflicts_with = "group_by")]
+    codes_only: bool,
+
+    /// Text to format, repeat for several commit headers. Reads from stdin
+    /// when omitted.
     #[arg(short, long)]
-    text: Option<String>,
+    text: Vec<String>,
+
+    /// More texts to format, each processed as its own commit header. Put
+    /// texts named like a subcommand, e.g. `stats`, after `--` or use --text
+    #[arg(value_name = "TEXT")]
+    texts: Vec<String>,
+
+    /// Read NUL-separated commit messages from stdin (like `git log -z
+    /// --format=%B`), each with its own header, and separate the output with NUL
+    #[arg(short = 'z', long, conflicts_with_all = ["text", "texts", "edit"])]
+    null: bool,
+
+    /// Format every stdin line as a commit header, e.g. the output of `git log
+    /// --format=%s`, instead of the first one only
+    #[arg(long, conflicts_with_all = ["text", "texts", "edit", "null"])]
+    each_line: bool,
+
+    /// Fail on stdin that isn't valid UTF-8, instead of passing the invalid
+    /// bytes through unchanged
+    #[arg(long)]
+    strict_utf8: bool,
+
+    /// Format the message on the system clipboard and put the result back,
+    /// e.g. a PR title being written in the browser
+    #[arg(
+        long,
+        conflicts_with_all = ["text", "texts", "edit", "null", "each_line", "output_file"]
+    )]
+    clipboard: bool,
+
+    /// Git clean filter: read stdin as is and write it with emoji as
+    /// shortcodes (or without emoji with --format strip)
+    #[arg(long, conflicts_with_all = ["smudge", "text", "texts", "edit", "null"])]
+    clean: bool,
+
+    /// Git smudge filter: read stdin as is and write it with emoji
+    #[arg(long, conflicts_with_all = ["text", "texts", "edit", "null"])]
+    smudge: bool,
 
     /// Lint the conventional commit
     #[arg(long)]
     lint: bool,
 
-    /// Format: unicode, shortcode, devmoji, strip
-    #[arg(short, long, default_value = "unicode")]
-    format: String,
+    /// Lint headers without a scope as errors (also "lint": {"scopeRequired": true})
+    #[arg(long, visible_alias = "require-scope")]
+    scope_required: bool,
+
+    /// Fail lint when there are more warnings than this (also "lint":
+    /// {"maxWarnings": N}). Rules are made warnings with "lint": {"rules": {...}}
+    #[arg(long, value_name = "N")]
+    max_warnings: Option<usize>,
+
+    /// Fail lint on any warning, like --max-warnings 0
+    #[arg(long, conflicts_with = "max_warnings")]
+    strict: bool,
+
+    /// Lint the messages of the commits in a revision range, e.g.
+    /// origin/main..HEAD, instead of stdin
+    #[arg(
+        long,
+        value_name = "RANGE",
+        requires = "lint",
+        conflicts_with_all = ["text", "texts", "edit", "clipboard", "fix"]
+    )]
+    range: Option<String>,
+
+    /// How --range reports the problems
+    #[arg(long, value_enum, default_value_t = Reporter::Text, requires = "range")]
+    reporter: Reporter,
+
+    /// Commit types allowed for this run, replacing the configured ones.
+    /// Types starting with + are added to them instead, e.g. --types +infra
+    #[arg(long, value_delimiter = ',', value_name = "TYPES")]
+    types: Vec<String>,
+
+    /// Add the ! to headers of messages with a BREAKING CHANGE footer, or
+    /// the footer to messages with a ! (also "completeBreaking": true in the config)
+    #[arg(long)]
+    complete_breaking: bool,
+
+    /// With --lint, fix what can be fixed (misspelled or uppercase types,
+    /// space after the colon, trailing period) instead of failing
+    #[arg(long, requires = "lint")]
+    fix: bool,
+
+    /// Enforce a Conventional Commits specification version exactly
+    #[arg(long, value_enum, value_name = "VERSION")]
+    spec: Option<Spec>,
+
+    /// Emoji format, overriding the output target's default representation
+    #[arg(short, long, value_enum)]
+    format: Option<Format>,
+
+    /// Where emoji go in a header [default: subject, or emojiPlacement from the config]
+    #[arg(long, value_enum)]
+    emoji_placement: Option<EmojiPlacement>,
+
+    /// Record the codes of the header emoji in a "Devmoji: feat, breaking"
+    /// trailer too, or only there [default: off, or emojiTrailer from the config]
+    #[arg(long, value_enum, value_name = "MODE")]
+    emoji_trailer: Option<EmojiTrailer>,
+
+    /// Where the output goes [default: file for --edit, terminal for --log
+    /// and a tty, file otherwise]
+    #[arg(long, value_enum)]
+    target: Option<Target>,
 
     /// Process conventional commit headers
     #[arg(long, default_value_t = true)]
@@ -46,14 +182,35 @@ struct Cli {
     #[arg(long)]
     no_commit: bool,
 
-    /// Read and edit a commit message file [default: .git/COMMIT_EDITMSG]
+    /// Read and edit a commit message file. Accepts a path or a git dir file name
+    /// like MERGE_MSG [default: most recent of COMMIT_EDITMSG, MERGE_MSG, SQUASH_MSG
+    /// and rebase-merge/message]
     #[arg(short, long)]
     edit: Option<Option<String>>,
 
+    /// With --edit, add a commented cheat sheet of the types and their emoji
+    /// to the message file. Git leaves comments out of the commit
+    #[arg(long, requires = "edit")]
+    hints: bool,
+
+    /// Print a diff of the edited message instead of writing it
+    #[arg(long, requires = "edit")]
+    dry_run: bool,
+
+    /// Print a diff of the edited message before writing it
+    #[arg(long, requires = "edit")]
+    diff: bool,
+
     /// Format conventional commits similar to git log
     #[arg(long)]
     log: bool,
 
+    /// Layout of --log lines, implies --log. Placeholders: %{hash}, %{emoji},
+    /// %{type}, %{scope}, %{breaking} and %{subject}. Text inside the braces is
+    /// only printed with a value, e.g. "%{hash} %{emoji} %{type}%{(scope)}: %{subject}"
+    #[arg(long, value_name = "TEMPLATE")]
+    log_format: Option<String>,
+
     /// Use colors for formatting
     #[arg(long)]
     color: Option<bool>,
@@ -61,11 +218,333 @@ struct Cli {
     /// Don't use colors
     #[arg(long)]
     no_color: bool,
+
+    /// Don't write anything, exit with 1 and print a diff if the input is
+    /// not already formatted
+    #[arg(long)]
+    check: bool,
+
+    /// Write the output to a file instead of stdout, or the edited message
+    /// there instead of back into its file. The file is replaced at once,
+    /// keeping its permissions
+    #[arg(
+        short,
+        long,
+        value_name = "FILE",
+        conflicts_with_all = ["check", "dry_run", "clean", "smudge"]
+    )]
+    output_file: Option<PathBuf>,
+
+    /// Add to the end of the --output-file instead of replacing it
+    #[arg(long, requires = "output_file")]
+    append: bool,
+
+    /// Print diagnostics to stderr, repeat for more detail (-vv, -vvv).
+    /// DEVMOJI_LOG=info|debug|trace does the same
+    #[arg(short, long, action = ArgAction::Count, global = true)]
+    verbose: u8,
+
+    /// Language of messages, descriptions and help (also DEVMOJI_LANG)
+    #[arg(long, value_enum, global = true)]
+    lang: Option<Lang>,
+
+    #[command(subcommand)]
+    command: Option<Commands>,
+}
+
+#[derive(Subcommand)]
+enum Commands {
+    /// Manage the git hooks that run devmoji
+    Hook {
+        #[command(subcommand)]
+        action: HookAction,
+    },
+    /// Generate files derived from the devmoji config
+    Generate {
+        #[command(subcommand)]
+        what: GenerateAction,
+    },
+    /// Count commits per type and scope, from git history or stdin
+    Stats {
+        /// Revision range passed to git log, e.g. v1.0.0..HEAD [default: HEAD]
+        range: Option<String>,
+
+        /// Read `git log --oneline` or `--format=%ct%x09%s` lines from stdin
+        #[arg(long, conflicts_with = "range")]
+        stdin: bool,
+
+        /// Output format
+        #[arg(long, value_enum, default_value_t = StatsFormat::Text)]
+        output: StatsFormat,
+
+        /// Number of time buckets in the sparklines
+        #[arg(long, default_value_t = 24)]
+        buckets: usize,
+    },
+    /// Download the latest gitmojis and use the ones newer than the bundled table
+    UpdateGitmojis {
+        /// Where to download gitmojis.json from (file:// URLs work too)
+        #[arg(long, default_value = gitmoji::GITMOJIS_URL)]
+        url: String,
+    },
+    /// Print the parts of a commit message as JSON or shell variables
+    Parse {
+        /// The commit message. Reads from stdin when omitted.
+        message: Option<String>,
+
+        /// Output format
+        #[arg(long, value_enum, default_value_t = ParseFormat::Json)]
+        output: ParseFormat,
+    },
+    /// Manage the git filter that stores files with shortcodes and checks
+    /// them out with emoji
+    Filter {
+        #[command(subcommand)]
+        action: FilterAction,
+    },
+    /// Entry point for the pre-commit framework, see .pre-commit-hooks.yaml
+    PreCommit {
+        /// The hook stage, deciding whether the files are staged files or the
+        /// commit message
+        #[arg(long, value_enum, default_value_t = Stage::CommitMsg)]
+        stage: Stage,
+
+        /// Files passed by pre-commit
+        files: Vec<PathBuf>,
+    },
+    /// Work with the devmoji config
+    Config {
+        #[command(subcommand)]
+        action: ConfigAction,
+    },
+    /// Manage the cache of evaluated JS/TS configs
+    #[cfg(feature = "js")]
+    Cache {
+        #[command(subcommand)]
+        action: CacheAction,
+    },
+    /// Check the config, Node, hooks, cache and terminal, suggesting fixes
+    Doctor,
+    /// Find emoji by shortcode or description, e.g. `devmoji search recycle`
+    Search {
+        /// Words to look for, typos and partial words match too
+        #[arg(required = true)]
+        query: Vec<String>,
+
+        /// Show at most this many emoji
+        #[arg(short = 'n', long, default_value_t = 20)]
+        limit: usize,
+    },
+    /// Format the messages of commits that aren't pushed yet. Only previews
+    /// the new messages without --force
+    Rewrite {
+        /// A base commit or base..HEAD [default: @{upstream}..HEAD]
+        #[arg(long)]
+        range: Option<String>,
+
+        /// Remove emoji instead of adding them
+        #[arg(long)]
+        strip: bool,
+
+        /// Rewrite the commits, not just preview them
+        #[arg(long)]
+        force: bool,
+    },
+    /// Pick an emoji interactively, typing narrows the list, and print its
+    /// shortcode, e.g. git commit -m "$(devmoji pick) add login"
+    Pick {
+        /// Query to start with
+        query: Option<String>,
+
+        /// Print the emoji instead of its shortcode
+        #[arg(long)]
+        emoji: bool,
+    },
+}
+
+#[derive(Subcommand)]
+enum ConfigAction {
+    /// Print a JSON Schema of devmoji.config.json, for editors to complete
+    /// and validate configs with "$schema" pointing at it
+    Schema,
+}
+
+#[cfg(feature = "js")]
+#[derive(Subcommand)]
+enum CacheAction {
+    /// Remove the project's and the per-user cache
+    Clear,
+}
+
+#[derive(Subcommand)]
+enum GenerateAction {
+    /// Editor snippets expanding each type into a header with its emoji
+    Snippets {
+        /// Editor to generate snippets for
+        #[arg(long, value_enum)]
+        editor: Editor,
+
+        /// Write to a file instead of stdout, e.g. .vscode/devmoji.code-snippets
+        #[arg(short, long, value_name = "FILE")]
+        output: Option<PathBuf>,
+    },
+}
+
+#[derive(Subcommand)]
+enum HookAction {
+    /// Install the prepare-commit-msg hook and the rebase editor wrapper
+    Install {
+        /// Overwrite an existing prepare-commit-msg hook
+        #[arg(long)]
+        force: bool,
+    },
+    /// Remove the hook and restore the core.editor the repository had before
+    Uninstall,
+    /// Editor wrapper: format rebase messages, then open the real editor
+    RebaseMsg {
+        /// Message file passed by git
+        file: PathBuf,
+    },
+}
+
+#[derive(Subcommand)]
+enum FilterAction {
+    /// Configure the filter and add it to .gitattributes for the given patterns
+    Install {
+        /// Paths or patterns to filter, e.g. '*.md' .gitmessage
+        #[arg(required = true)]
+        patterns: Vec<String>,
+
+        /// Store files without emoji instead of with shortcodes. Emoji are
+        /// not restored on checkout.
+        #[arg(long)]
+        strip: bool,
+    },
+    /// Remove the filter config and its .gitattributes entries
+    Uninstall,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+enum ListSort {
+    /// By shortcode
+    Code,
+    /// By type in the order of the config's types, then shortcode
+    Type,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+enum ListGroup {
+    /// By commit type, devmojis without one last
+    Type,
+}
+
+/// What `--list` shows and how
+struct ListOptions<'a> {
+    commit_type: Option<&'a str>,
+    search: Option<&'a str>,
+    sort: Option<ListSort>,
+    group_by: Option<ListGroup>,
+    codes_only: bool,
+}
+
+/// Settings shared by the text processing modes
+#[derive(Clone, Copy)]
+struct Options<'a> {
+    commit: bool,
+    log: bool,
+    log_format: Option<&'a str>,
+    output: Output,
+    color: bool,
+    lint: bool,
+    fix: bool,
+    check: bool,
+    dry_run: bool,
+    diff: bool,
+    /// Where the output goes instead of stdout, or instead of the edited file
+    output_file: Option<&'a Path>,
+    append: bool,
+}
+
+/// Where formatted text goes: stdout as it is produced, or the output file
+/// all at once when done
+struct Sink<'a> {
+    file: Option<&'a Path>,
+    append: bool,
+    buffer: String,
+}
+
+impl<'a> Sink<'a> {
+    fn new(opts: Options<'a>) -> Self {
+        Sink {
+            file: opts.output_file,
+            append: opts.append,
+            buffer: String::new(),
+        }
+    }
+
+    /// Bytes of the input that aren't UTF-8 are written as they were read
+    fn print(&mut self, text: &str) -> Result<(), Error> {
+        match self.file {
+            Some(_) => self.buffer.push_str(text),
+            None => io::stdout()
+                .lock()
+                .write_all(&utf8::encode(text))
+                .map_err(|e| Error::Io(format!("Error writing stdout: {}", e)))?,
+        }
+        Ok(())
+    }
+
+    fn println(&mut self, text: &str) -> Result<(), Error> {
+        self.print(text)?;
+        self.print("\n")
+    }
+
+    fn finish(self) -> Result<(), Error> {
+        match self.file {
+            Some(path) => output::write_file(path, &utf8::encode(&self.buffer), self.append)
+                .map_err(Error::Io),
+            None => Ok(()),
+        }
+    }
 }
 
 fn main() {
-    let cli = Cli::parse();
+    // The background process of `--clipboard`, see `clipboard::write`
+    if std::env::var_os(clipboard::HOLDER_ENV).is_some() {
+        process::exit(i32::from(clipboard::hold().is_err()));
+    }
+    // The language is needed before parsing, to translate the help
+    let args: Vec<_> = std::env::args_os().collect();
+    let lang = i18n::init(i18n::lang_arg(&args));
+    let matches = i18n::localize(Cli::command()).get_matches_from(&args);
+    let mut cli = Cli::from_arg_matches(&matches).unwrap_or_else(|e| e.exit());
+    order_texts(&mut cli, &matches);
+    log::init(cli.verbose);
+    if let Err(e) = lang {
+        warn!("i18n", "ignoring {}", e);
+    }
+    if let Err(e) = run(cli) {
+        let message = e.to_string();
+        if !message.is_empty() {
+            eprintln!("{}", message);
+        }
+        process::exit(e.exit_code());
+    }
+}
+
+/// Put the `--text` values and the positional texts together in `texts`, in
+/// the order they were given
+fn order_texts(cli: &mut Cli, matches: &ArgMatches) {
+    let indices = |id: &str| matches.indices_of(id).into_iter().flatten();
+    let mut texts: Vec<(usize, String)> = indices("text")
+        .zip(std::mem::take(&mut cli.text))
+        .chain(indices("texts").zip(std::mem::take(&mut cli.texts)))
+        .collect();
+    texts.sort_by_key(|&(index, _)| index);
+    cli.texts = texts.into_iter().map(|(_, text)| text).collect();
+}
 
+fn run(cli: Cli) -> Result<(), Error> {
     let commit_enabled = cli.commit && !cli.no_commit;
     let use_color = if cli.no_color {
         false
@@ -75,217 +554,870 @@ fn main() {
         atty::is(atty::Stream::Stdout)
     };
 
-    if !use_color {
-        colored::control::set_override(false);
+    theme::set_override(use_color);
+
+    // The doctor checks the config itself, and must run when it is broken
+    if let Some(Commands::Doctor) = &cli.command {
+        let checks = doctor::run(cli.config.as_deref(), use_color);
+        print!("{}", doctor::render(&checks));
+        if checks.iter().any(|c| c.status == doctor::Status::Fail) {
+            return Err(Error::Failed);
+        }
+        return Ok(());
+    }
+
+    // Clearing the cache must not evaluate the config first
+    #[cfg(feature = "js")]
+    if let Some(Commands::Cache {
+        action: CacheAction::Clear,
+    }) = &cli.command
+    {
+        return report(cache::clear().map_err(Error::Io));
+    }
+    // Nor printing the schema of configs
+    if let Some(Commands::Config {
+        action: ConfigAction::Schema,
+    }) = &cli.command
+    {
+        let schema = serde_json::to_string_pretty(&config::schema()).unwrap();
+        println!("{}", schema);
+        return Ok(());
+    }
+    // Neither does updating the gitmojis, which the config may refer to
+    if let Some(Commands::UpdateGitmojis { url }) = &cli.command {
+        return report(gitmoji::update(url).map_err(Error::Io));
     }
 
-    let cfg = Config::load(cli.config.as_deref());
+    let mut cfg = Config::load(cli.config.as_deref(), !cli.no_cache)?;
+    if cli.spec.is_some() {
+        cfg.spec = cli.spec;
+    }
+    if cli.complete_breaking {
+        cfg.complete_breaking = true;
+    }
+    if cli.scope_required {
+        cfg.lint.scope_required = true;
+    }
+    if !cli.types.is_empty() {
+        cfg.override_types(&cli.types);
+    }
+    if cli.strict {
+        cfg.lint.max_warnings = Some(0);
+    } else if cli.max_warnings.is_some() {
+        cfg.lint.max_warnings = cli.max_warnings;
+    }
+    if let Some(placement) = cli.emoji_placement {
+        cfg.emoji_placement = placement;
+    }
+    if let Some(trailer) = cli.emoji_trailer {
+        cfg.emoji_trailer = trailer;
+    }
     let dm = Devmoji::new(&cfg);
     let cc = ConventionalCommits::new(&dm, &cfg);
 
+    let log = cli.log || cli.log_format.is_some();
+    let target = cli.target.unwrap_or_else(|| {
+        if log {
+            Target::Terminal
+        } else {
+            Target::detect(cli.edit.is_some() || cli.output_file.is_some() || cli.clipboard)
+        }
+    });
+
+    let opts = Options {
+        commit: commit_enabled,
+        log,
+        log_format: cli.log_format.as_deref(),
+        output: Output::new(cli.format, target),
+        color: use_color,
+        lint: cli.lint,
+        fix: cli.fix,
+        check: cli.check,
+        dry_run: cli.dry_run,
+        diff: cli.diff,
+        output_file: cli.output_file.as_deref(),
+        append: cli.append,
+    };
+
+    if let Some(command) = cli.command {
+        return run_command(&dm, &cfg, &cc, command, use_color);
+    }
+
+    // Filter driver modes
+    if cli.clean || cli.smudge {
+        let format = match cli.format {
+            _ if cli.smudge => Format::Unicode,
+            Some(Format::Strip) => Format::Strip,
+            _ => Format::Shortcode,
+        };
+        return handle_filter(&dm, Output::new(Some(format), Target::File));
+    }
+
     // --list mode
     if cli.list {
-        print_list(&dm, &cfg);
-        return;
+        let list = ListOptions {
+            commit_type: cli.list_type.as_deref(),
+            search: cli.list_search.as_deref(),
+            sort: cli.list_sort,
+            group_by: cli.group_by,
+            codes_only: cli.codes_only,
+        };
+        print_list(&dm, &cfg, &list);
+        return Ok(());
+    }
+
+    // --lint --range mode
+    if let Some(range) = cli.range {
+        let commits = git::log_messages(&range).map_err(Error::Git)?;
+        let linted = reporter::lint(&cc, commits);
+        print!("{}", reporter::render(&linted, cli.reporter));
+        if linted.iter().any(|commit| !commit.passed) {
+            return Err(Error::Failed);
+        }
+        return Ok(());
     }
 
     // --edit mode
     if let Some(edit_file) = cli.edit {
-        handle_edit(&dm, &cc, commit_enabled, &cli.format, edit_file);
-        return;
+        let hints = cli.hints.then_some(&cfg);
+        return handle_edit(&dm, &cc, opts, edit_file, hints);
     }
 
-    // --text mode
-    if let Some(text) = &cli.text {
-        let output = process_text(
-            &dm,
-            &cc,
-            text,
-            commit_enabled,
-            cli.log,
-            &cli.format,
-            use_color,
-            cli.lint,
-        );
-        println!("{}", output);
-        return;
+    // Compare against the uncolored output in --check mode, and keep files uncolored
+    let process_opts = Options {
+        color: use_color && !cli.check && cli.output_file.is_none(),
+        ..opts
+    };
+
+    // --clipboard mode
+    if cli.clipboard {
+        let input = clipboard::read().map_err(Error::Io)?;
+        let formatted = process_message(&dm, &cc, &input, Options { color: false, ..opts })?;
+        if cli.check {
+            return check_formatted(&input, &formatted, use_color);
+        }
+        clipboard::write(&formatted).map_err(Error::Io)?;
+        let first_line = formatted.lines().next().unwrap_or_default();
+        println!("{} {}", "\u{2714}".green(), first_line);
+        return Ok(());
     }
 
-    // stdin mode
+    // --text and positional mode
+    let texts = &cli.texts;
+    let mut sink = Sink::new(opts);
+    if !texts.is_empty() {
+        let mut input = String::new();
+        let mut formatted = String::new();
+        for text in texts {
+            let output = if commit_enabled && !log {
+                process_text(&dm, &cc, &cc.complete_message(text), process_opts)?
+            } else {
+                process_text(&dm, &cc, text, process_opts)?
+            };
+            if cli.check {
+                input.push_str(text);
+                input.push('\n');
+                formatted.push_str(&output);
+                formatted.push('\n');
+            } else {
+                sink.println(&output)?;
+            }
+        }
+        if cli.check {
+            check_formatted(&input, &formatted, use_color)?;
+        }
+        return sink.finish();
+    }
+
+    // NUL-separated stdin mode
+    if cli.null {
+        let mut stdin = Vec::new();
+        io::stdin()
+            .lock()
+            .read_to_end(&mut stdin)
+            .map_err(|e| Error::Io(format!("Error reading stdin: {}", e)))?;
+        let stdin = decode_input(&stdin, cli.strict_utf8)?;
+        let records = stdin.strip_suffix('\0').unwrap_or(&stdin);
+        let mut input = String::new();
+        let mut formatted = String::new();
+        for record in records.split('\0').filter(|_| !records.is_empty()) {
+            input.push_str(record);
+            input.push('\0');
+            formatted.push_str(&process_message(&dm, &cc, record, process_opts)?);
+            formatted.push('\0');
+        }
+        if cli.check {
+            // Show the records one after another in the diff
+            let (input, formatted) = (input.replace('\0', "\n"), formatted.replace('\0', "\n"));
+            check_formatted(&input, &formatted, use_color)?;
+        } else {
+            sink.print(&formatted)?;
+        }
+        return sink.finish();
+    }
+
+    // stdin mode, as one message when breaking changes need its footers or it
+    // gets a trailer, and a log as a whole when it has trailers to read back
+    let trailer = cfg.emoji_trailer != EmojiTrailer::Off;
+    let whole_message = commit_enabled
+        && !log
+        && !cli.each_line
+        && (cfg.complete_breaking || cfg.lint.breaking == BreakingPolicy::Consistent || trailer);
+    let whole_log = log && cli.log_format.is_none() && trailer;
+    if !atty::is(atty::Stream::Stdin) && (whole_message || whole_log) {
+        let mut stdin = Vec::new();
+        io::stdin()
+            .lock()
+            .read_to_end(&mut stdin)
+            .map_err(|e| Error::Io(format!("Error reading stdin: {}", e)))?;
+        let input = decode_input(&stdin, cli.strict_utf8)?;
+        let formatted = match whole_log {
+            true if gitlog::is_git_log(&input) => process_git_log(&dm, &cc, &input, process_opts)?,
+            true => process_text(&dm, &cc, &input, process_opts)?,
+            false => process_message(&dm, &cc, &input, process_opts)?,
+        };
+        if cli.check {
+            check_formatted(&input, &formatted, use_color)?;
+        } else {
+            sink.print(&formatted)?;
+        }
+        return sink.finish();
+    }
     if !atty::is(atty::Stream::Stdin) {
-        let stdin = io::stdin();
+        let mut stdin = io::stdin().lock();
         let mut first_line = true;
-        for line in stdin.lock().lines() {
-            let line = match line {
-                Ok(l) => l,
-                Err(_) => break,
-            };
+        let mut log_parts = gitlog::Classifier::default();
+        let mut input = String::new();
+        let mut formatted = String::new();
+        let mut raw = Vec::new();
+        loop {
+            raw.clear();
+            let read = stdin
+                .read_until(b'\n', &mut raw)
+                .map_err(|e| Error::Io(format!("Error reading stdin: {}", e)))?;
+            if read == 0 {
+                break;
+            }
+            let raw = raw.strip_suffix(b"\n").unwrap_or(&raw);
+            let line = decode_input(raw.strip_suffix(b"\r").unwrap_or(raw), cli.strict_utf8)?;
 
-            let output = if first_line {
-                process_text(
-                    &dm,
-                    &cc,
-                    &line,
-                    commit_enabled,
-                    cli.log,
-                    &cli.format,
-                    use_color,
-                    cli.lint,
-                )
+            let output = if log {
+                process_log_line(&dm, &cc, &line, log_parts.classify(&line), process_opts)?
+            } else if first_line || cli.each_line {
+                process_text(&dm, &cc, &line, process_opts)?
             } else {
-                process_text(&dm, &cc, &line, false, cli.log, &cli.format, use_color, false)
+                trace!("stdin", "treating {:?} as body text, only the first line is a header without --each-line", line);
+                let rest = Options {
+                    commit: false,
+                    lint: false,
+                    ..process_opts
+                };
+                process_text(&dm, &cc, &line, rest)?
             };
 
-            println!("{}", output);
+            if cli.check {
+                input.push_str(&line);
+                input.push('\n');
+                formatted.push_str(&output);
+                formatted.push('\n');
+            } else {
+                sink.println(&output)?;
+            }
             first_line = false;
         }
-        return;
+        if cli.check {
+            check_formatted(&input, &formatted, use_color)?;
+        }
+        return sink.finish();
     }
 
     // No input - show help
-    eprintln!("No input provided. Use --text, --edit, or pipe input via stdin.");
-    eprintln!("Run with --help for usage information.");
-    process::exit(1);
+    Err(Error::Usage(
+        "No input provided. Pass texts, use --text or --edit, or pipe input via stdin.\n\
+         Run with --help for usage information."
+            .to_string(),
+    ))
+}
+
+/// Stdin as text. Bytes that aren't UTF-8 are kept to be written back, or
+/// an error with `strict`.
+fn decode_input(bytes: &[u8], strict: bool) -> Result<Cow<'_, str>, Error> {
+    if strict {
+        return std::str::from_utf8(bytes)
+            .map(Cow::Borrowed)
+            .map_err(|e| Error::Io(format!("stdin is not valid UTF-8: {}", e)));
+    }
+    Ok(utf8::decode(bytes))
+}
+
+/// Process a whole commit message: the first line as its header, the rest as
+/// body text
+fn process_message(
+    dm: &Devmoji,
+    cc: &ConventionalCommits,
+    message: &str,
+    opts: Options,
+) -> Result<String, Error> {
+    let mut message = message.to_string();
+    if opts.commit && !opts.log {
+        message = cc.complete_message(&message);
+        // Lint the whole message, footers included
+        if opts.lint {
+            if opts.fix {
+                message = fix_message(cc, &message);
+            }
+            lint_message(cc, &message)?;
+        }
+    }
+    let header_opts = Options { lint: false, ..opts };
+    let body_opts = Options {
+        commit: false,
+        lint: false,
+        ..opts
+    };
+    message
+        .split('\n')
+        .enumerate()
+        .map(|(i, line)| match (i, line) {
+            (_, "") => Ok(String::new()),
+            (0, _) => process_text(dm, cc, line, header_opts),
+            _ => process_text(dm, cc, line, body_opts),
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .map(|lines| lines.join("\n"))
+}
+
+/// A line of `git log` output, as `part`. In the default format only the
+/// message is formatted, the commit, author and date lines are kept as they are.
+fn process_log_line(
+    dm: &Devmoji,
+    cc: &ConventionalCommits,
+    line: &str,
+    part: Part,
+    opts: Options,
+) -> Result<String, Error> {
+    let body_opts = Options {
+        log: false,
+        commit: false,
+        lint: false,
+        ..opts
+    };
+    match part {
+        Part::Line => process_text(dm, cc, line, opts),
+        Part::Meta => Ok(line.to_string()),
+        Part::Subject(at) => Ok(line[..at].to_string() + &process_text(dm, cc, &line[at..], opts)?),
+        Part::Body(at) => {
+            Ok(line[..at].to_string() + &process_text(dm, cc, &line[at..], body_opts)?)
+        }
+    }
+}
+
+/// Whole `git log` output in the default format, with each subject formatted
+/// with the `Devmoji:` trailers in its body
+fn process_git_log(
+    dm: &Devmoji,
+    cc: &ConventionalCommits,
+    text: &str,
+    opts: Options,
+) -> Result<String, Error> {
+    let lines: Vec<&str> = text.split('\n').collect();
+    let mut classifier = gitlog::Classifier::default();
+    let parts: Vec<Part> = lines.iter().map(|line| classifier.classify(line)).collect();
+    let mut result = Vec::with_capacity(lines.len());
+    for (i, (line, part)) in lines.iter().zip(&parts).enumerate() {
+        let Part::Subject(at) = *part else {
+            result.push(process_log_line(dm, cc, line, *part, opts)?);
+            continue;
+        };
+        let body: Vec<&str> = lines[i + 1..]
+            .iter()
+            .zip(&parts[i + 1..])
+            .map_while(|(line, part)| match part {
+                Part::Body(at) => Some(&line[*at..]),
+                _ => None,
+            })
+            .collect();
+        let subject = cc.format_log_subject(&line[at..], &body.join("\n"), opts.color);
+        result.push(line[..at].to_string() + &opts.output.render(dm, &subject));
+    }
+    Ok(result.join("\n"))
+}
+
+/// Apply lint fixes, telling which on stderr
+fn fix_message(cc: &ConventionalCommits, text: &str) -> String {
+    let (fixed, fixes) = cc.fix(text);
+    for fix in fixes {
+        eprintln!("{} {}", "\u{2714}".green(), tr!("Fixed {fix}", fix = fix));
+    }
+    fixed
+}
+
+/// Lint `text`, printing the warnings when it passes
+fn lint_message(cc: &ConventionalCommits, text: &str) -> Result<(), Error> {
+    let warnings = cc.lint(text).map_err(Error::Lint)?;
+    for warning in &warnings {
+        eprintln!("{}", warning);
+    }
+    Ok(())
 }
 
 fn process_text(
     dm: &Devmoji,
     cc: &ConventionalCommits,
     text: &str,
-    commit: bool,
-    log: bool,
-    format: &str,
-    color: bool,
-    lint: bool,
-) -> String {
+    opts: Options,
+) -> Result<String, Error> {
+    let Options {
+        commit,
+        log,
+        log_format,
+        output,
+        color,
+        lint,
+        fix,
+        ..
+    } = opts;
+
     // Lint first if requested
+    let fixed;
+    let mut text = text;
     if lint && commit && !log {
-        if let Err(errors) = cc.lint(text) {
-            for err in &errors {
-                eprintln!("{}", err);
-            }
-            process::exit(1);
+        if fix {
+            fixed = fix_message(cc, text);
+            text = &fixed;
         }
+        lint_message(cc, text)?;
     }
 
     let result = if log {
-        cc.format_log(text, color)
+        match log_format {
+            Some(template) => cc.format_log_template(text, template, color),
+            None => cc.format_log(text, color),
+        }
     } else if commit {
         cc.format_commit(text, color)
     } else {
-        match format {
-            "shortcode" => dm.demojify(text),
-            "devmoji" => dm.devmojify(text),
-            "strip" => dm.strip(text),
-            _ => dm.emojify(text),
-        }
+        text.to_string()
     };
 
-    // Apply format conversion if commit/log mode
-    if commit || log {
-        match format {
-            "shortcode" => dm.demojify(&result),
-            "devmoji" => dm.devmojify(&result),
-            "strip" => dm.strip(&result),
-            _ => result,
+    Ok(output.render(dm, &result))
+}
+
+fn run_command(
+    dm: &Devmoji,
+    cfg: &Config,
+    cc: &ConventionalCommits,
+    command: Commands,
+    color: bool,
+) -> Result<(), Error> {
+    match command {
+        Commands::Stats {
+            range,
+            stdin,
+            output,
+            buckets,
+        } => {
+            let commits: Vec<Commit> = if stdin {
+                io::stdin()
+                    .lock()
+                    .lines()
+                    .map_while(Result::ok)
+                    .map(|line| Commit::parse(&line))
+                    .collect()
+            } else {
+                git::log_times(range.as_deref())
+                    .map_err(Error::Git)?
+                    .into_iter()
+                    .map(|(time, message)| Commit {
+                        time: Some(time),
+                        message,
+                    })
+                    .collect()
+            };
+            let stats = stats::collect(cc, &commits, buckets);
+            print!("{}", stats::render(&stats, cfg, output, color));
         }
-    } else {
-        result
+        Commands::Parse { message, output } => {
+            let message = match message {
+                Some(message) => message,
+                None => {
+                    let mut message = String::new();
+                    io::stdin()
+                        .read_to_string(&mut message)
+                        .map_err(|e| Error::Io(format!("Error reading stdin: {}", e)))?;
+                    message
+                }
+            };
+            let parsed = parse::parse(dm, cc, &message);
+            print!("{}", parse::render(&parsed, output));
+        }
+        Commands::Generate {
+            what: GenerateAction::Snippets { editor, output },
+        } => {
+            let generated = snippets::generate(dm, cfg, editor);
+            match output {
+                Some(path) => {
+                    std::fs::write(&path, generated).map_err(|e| {
+                        Error::Io(format!("Error writing {}: {}", path.display(), e))
+                    })?;
+                    println!("{} {}", "\u{2714}".green(), path.display());
+                }
+                None => print!("{}", generated),
+            }
+        }
+        #[cfg(feature = "js")]
+        Commands::Cache { .. } => unreachable!("handled before loading the config"),
+        Commands::Config { .. }
+        | Commands::UpdateGitmojis { .. }
+        | Commands::Doctor => {
+            unreachable!("handled before loading the config")
+        }
+        Commands::Hook { action } => {
+            let result = match action {
+                HookAction::Install { force } => hook::install(force),
+                HookAction::Uninstall => hook::uninstall(),
+                HookAction::RebaseMsg { file } => {
+                    let comment = git::comment_prefix();
+                    let code = hook::rebase_msg(&file, |msg| {
+                        git::map_message(msg, &comment, |message| {
+                            cc.format_commit(&cc.complete_message(message), false)
+                        })
+                    })
+                    .map_err(Error::Git)?;
+                    return match code {
+                        0 => Ok(()),
+                        code => Err(Error::Exit(code)),
+                    };
+                }
+            };
+            report(result.map_err(Error::Git))?;
+        }
+        Commands::PreCommit { stage, files } => {
+            let outcome = precommit::run(dm, cc, Output::new(None, Target::File), stage, &files);
+            for line in &outcome.done {
+                println!("{} {}", "\u{2714}".green(), line);
+            }
+            for error in &outcome.errors {
+                eprintln!("{}", error);
+            }
+            if outcome.failed {
+                return Err(Error::Failed);
+            }
+        }
+        Commands::Search { query, limit } => {
+            let query = query.join(" ");
+            let hits = search::search(dm, &query);
+            if hits.is_empty() {
+                return Err(Error::NotFound(format!("No emoji match '{}'", query)));
+            }
+            for hit in hits.iter().take(limit) {
+                println!("{}  {:30} {}", hit.emoji, format!(":{}:", hit.code), hit.description);
+            }
+        }
+        Commands::Rewrite {
+            range,
+            strip,
+            force,
+        } => {
+            let output = match strip {
+                true => Output::new(Some(Format::Strip), Target::File),
+                false => Output::new(None, Target::File),
+            };
+            let rewords = rewrite::plan(range.as_deref(), |message| {
+                output.render(dm, &cc.format_commit(&cc.complete_message(message), false))
+            })
+            .map_err(Error::Git)?;
+            if force {
+                return report(rewrite::apply(&rewords).map_err(Error::Git));
+            }
+            for reword in rewords.iter().filter(|r| r.changed()) {
+                println!("{}", reword.short_hash().yellow());
+                print!("{}", diff::render(&diff::diff_lines(&reword.old, &reword.new), color));
+            }
+            let changed = rewords.iter().filter(|r| r.changed()).count();
+            if changed == 0 {
+                println!("{} Every message is already formatted", "\u{2714}".green());
+                return Ok(());
+            }
+            let pushed = rewrite::pushed(&rewords);
+            if pushed > 0 {
+                eprintln!(
+                    "{} of the commits are pushed already, rewriting them needs a force push",
+                    pushed
+                );
+            }
+            println!("Run again with --force to rewrite {} of {} commits", changed, rewords.len());
+        }
+        Commands::Pick { query, emoji } => {
+            match pick::pick(dm, query.as_deref().unwrap_or_default()).map_err(Error::Io)? {
+                Some(hit) if emoji => println!("{}", hit.emoji),
+                Some(hit) => println!(":{}:", hit.code),
+                // Like fzf, when cancelled
+                None => return Err(Error::Cancelled),
+            }
+        }
+        Commands::Filter { action } => report(
+            match action {
+                FilterAction::Install { patterns, strip } => filter::install(&patterns, strip),
+                FilterAction::Uninstall => filter::uninstall(),
+            }
+            .map_err(Error::Git),
+        )?,
+    }
+    Ok(())
+}
+
+/// Git filter driver: the whole of stdin to stdout, without headers being
+/// formatted. Input that isn't UTF-8 is passed through unchanged.
+fn handle_filter(dm: &Devmoji, output: Output) -> Result<(), Error> {
+    let mut input = Vec::new();
+    io::stdin()
+        .lock()
+        .read_to_end(&mut input)
+        .map_err(|e| Error::Io(format!("Error reading stdin: {}", e)))?;
+    let filtered = match std::str::from_utf8(&input) {
+        Ok(text) => output.render(dm, text).into_bytes(),
+        Err(_) => input,
+    };
+    io::stdout()
+        .lock()
+        .write_all(&filtered)
+        .map_err(|e| Error::Io(format!("Error writing stdout: {}", e)))
+}
+
+/// Print each completed step with a checkmark, or pass the error on.
+fn report(result: Result<Vec<String>, Error>) -> Result<(), Error> {
+    for line in result? {
+        println!("{} {}", "\u{2714}".green(), line);
     }
+    Ok(())
 }
 
-fn print_list(dm: &Devmoji, cfg: &Config) {
-    for entry in dm.pack() {
-        let emoji = dm.get(&entry.emoji);
+/// Print a diff and fail unless `input` is already formatted.
+fn check_formatted(input: &str, output: &str, color: bool) -> Result<(), Error> {
+    if input == output {
+        return Ok(());
+    }
+    print!("{}", diff::render(&diff::diff_lines(input, output), color));
+    Err(Error::Failed)
+}
 
-        let type_prefix = if cfg.types.iter().any(|t| t == &entry.code) {
-            format!("{}: ", entry.code)
-        } else if entry.code.contains('-') {
-            let parts: Vec<&str> = entry.code.splitn(2, '-').collect();
-            if cfg.types.iter().any(|t| t == parts[0]) {
-                format!("{}({}): ", parts[0], parts[1])
-            } else {
-                String::new()
+/// Print the pack in columns, with the descriptions wrapped under each other
+/// to fit the terminal. Piped output keeps one entry per line.
+fn print_list(dm: &Devmoji, cfg: &Config, list: &ListOptions) {
+    // The type and scope a devmoji stands for, e.g. chore(deps)
+    let type_of = |code: &str| -> Option<(usize, Option<String>)> {
+        let position = |t: &str| cfg.types.iter().position(|known| known == t);
+        if let Some(i) = position(code) {
+            return Some((i, None));
+        }
+        let (commit_type, scope) = code.split_once('-')?;
+        position(commit_type).map(|i| (i, Some(scope.to_string())))
+    };
+
+    let matches: Option<HashSet<String>> = list
+        .search
+        .map(|query| search::search(dm, query).into_iter().map(|hit| hit.code).collect());
+    let mut entries: Vec<_> = dm
+        .pack()
+        .iter()
+        .map(|entry| (entry, type_of(&entry.code)))
+        .filter(|(_, commit_type)| match list.commit_type {
+            Some(wanted) => commit_type
+                .as_ref()
+                .is_some_and(|(i, _)| cfg.types[*i] == wanted),
+            None => true,
+        })
+        .filter(|(entry, _)| matches.as_ref().is_none_or(|m| m.contains(&entry.code)))
+        .collect();
+    // Stable, so ties stay in pack order
+    match list.sort {
+        Some(ListSort::Code) => entries.sort_by(|(a, _), (b, _)| a.code.cmp(&b.code)),
+        Some(ListSort::Type) => entries.sort_by_key(|(entry, commit_type)| {
+            (commit_type.as_ref().map_or(usize::MAX, |(i, _)| *i), entry.code.clone())
+        }),
+        None => {}
+    }
+    if list.group_by == Some(ListGroup::Type) {
+        entries.sort_by_key(|(_, commit_type)| {
+            commit_type.as_ref().map_or(usize::MAX, |(i, _)| *i)
+        });
+    }
+
+    if list.codes_only {
+        for (entry, _) in entries {
+            println!("{}", entry.code);
+        }
+        return;
+    }
+
+    let rows: Vec<(String, String, String)> = entries
+        .iter()
+        .map(|(entry, commit_type)| {
+            let type_prefix = match commit_type {
+                Some((i, None)) => format!("{}: ", cfg.types[*i]),
+                Some((i, Some(scope))) => format!("{}({}): ", cfg.types[*i], scope),
+                None => String::new(),
+            };
+            (
+                dm.entry_emoji(entry),
+                format!(":{}:", entry.code),
+                format!("{}{}", type_prefix, entry.description),
+            )
+        })
+        .collect();
+
+    // Emoji are two columns wide, literal entries may be wider
+    let emoji_width = rows.iter().map(|(e, _, _)| e.width()).max().unwrap_or(0).max(2);
+    let code_width = rows.iter().map(|(_, c, _)| c.width()).max().unwrap_or(0);
+    let indent = emoji_width + 2 + code_width + 1;
+    let columns = atty::is(atty::Stream::Stdout)
+        .then(crossterm::terminal::size)
+        .and_then(Result::ok)
+        .map(|(columns, _)| columns as usize)
+        .filter(|&columns| columns > 0);
+
+    let mut group = None;
+    for ((emoji, code, description), (_, commit_type)) in rows.into_iter().zip(&entries) {
+        if list.group_by == Some(ListGroup::Type) {
+            let heading = commit_type.as_ref().map(|(i, _)| *i);
+            if group != Some(heading) {
+                if group.is_some() {
+                    println!();
+                }
+                let name = heading.map_or_else(|| tr!("other"), |i| cfg.types[i].clone());
+                println!("{}", name.bold());
+                group = Some(heading);
             }
-        } else {
-            String::new()
+        }
+        let lines = match columns {
+            // Too narrow for the columns, the lines wrap wherever the terminal wraps them
+            Some(columns) if columns >= indent + 20 => wrap(&description, columns - indent),
+            _ => vec![description],
         };
-
+        let mut lines = lines.into_iter();
         println!(
-            "{}  {:30} {}{}",
+            "{}{}  {}{} {}",
             emoji,
-            format!(":{}:", entry.code),
-            type_prefix,
-            entry.description
+            " ".repeat(emoji_width - emoji.width()),
+            code,
+            " ".repeat(code_width - code.width()),
+            lines.next().unwrap_or_default()
         );
+        for line in lines {
+            println!("{}{}", " ".repeat(indent), line);
+        }
     }
 }
 
-fn handle_edit(dm: &Devmoji, cc: &ConventionalCommits, commit: bool, format: &str, file: Option<String>) {
-    let msg_file = if let Some(path) = file {
-        PathBuf::from(path)
-    } else {
-        match find_git_dir() {
-            Some(dir) => dir.join("COMMIT_EDITMSG"),
-            None => {
-                eprintln!("Could not find .git directory");
-                process::exit(1);
-            }
+/// Split `text` into lines at most `width` columns wide, between words
+fn wrap(text: &str, width: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut line = String::new();
+    for word in text.split_whitespace() {
+        if !line.is_empty() && line.width() + 1 + word.width() > width {
+            lines.push(std::mem::take(&mut line));
         }
-    };
+        if !line.is_empty() {
+            line.push(' ');
+        }
+        line.push_str(word);
+    }
+    if !line.is_empty() || lines.is_empty() {
+        lines.push(line);
+    }
+    lines
+}
+
+fn handle_edit(
+    dm: &Devmoji,
+    cc: &ConventionalCommits,
+    opts: Options,
+    file: Option<String>,
+    hints: Option<&Config>,
+) -> Result<(), Error> {
+    let Options { commit, output, .. } = opts;
+
+    let msg_file = git::resolve_message_file(file.as_deref()).map_err(Error::Git)?;
 
     if !msg_file.exists() {
-        eprintln!("Could not find {}", msg_file.display());
-        process::exit(1);
+        return Err(Error::Io(format!("Could not find {}", msg_file.display())));
     }
 
-    let text = match std::fs::read_to_string(&msg_file) {
-        Ok(t) => t,
-        Err(e) => {
-            eprintln!("Error reading {}: {}", msg_file.display(), e);
-            process::exit(1);
+    let original = std::fs::read_to_string(&msg_file)
+        .map_err(|e| Error::Io(format!("Error reading {}: {}", msg_file.display(), e)))?;
+
+    // Todo lists hold one subject per command, message files a single message
+    // with comment lines and maybe a diff after the scissors line
+    let is_todo = git::is_rebase_todo(&msg_file);
+    let comment = git::comment_prefix();
+    // Cheat sheets of earlier runs are replaced or left out
+    let mut text = match is_todo {
+        true => original.clone(),
+        false => hints::strip(&original, &comment),
+    };
+    if commit && !is_todo {
+        let mut linted = Ok(());
+        text = git::map_message(&text, &comment, |message| {
+            let mut message = cc.complete_message(message);
+            if opts.lint {
+                if opts.fix {
+                    message = fix_message(cc, &message);
+                }
+                linted = lint_message(cc, &message);
+            }
+            message
+        });
+        linted?;
+    }
+
+    let format_message = |text: &str, color: bool| {
+        if commit {
+            output.render(dm, &cc.format_commit(text, color))
+        } else {
+            output.render(dm, text)
         }
     };
 
-    // Format without color for file
-    let formatted = if commit {
-        cc.format_commit(&text, false)
-    } else {
-        match format {
-            "shortcode" => dm.demojify(&text),
-            "devmoji" => dm.devmojify(&text),
-            "strip" => dm.strip(&text),
-            _ => dm.emojify(&text),
+    let format_file = |color: bool| {
+        if is_todo {
+            git::map_rebase_todo(&text, |subject| format_message(subject, color))
+        } else {
+            git::map_message(&text, &comment, |message| format_message(message, color))
         }
     };
 
-    // Write back
-    if let Err(e) = std::fs::write(&msg_file, &formatted) {
-        eprintln!("Error writing {}: {}", msg_file.display(), e);
-        process::exit(1);
+    // Format without color for file
+    let mut formatted = format_file(false);
+
+    if opts.check {
+        return check_formatted(&hints::strip(&original, &comment), &formatted, opts.color);
+    }
+    if let Some(cfg) = hints.filter(|_| !is_todo) {
+        formatted = hints::add(&formatted, dm, cfg, &comment);
+    }
+
+    if opts.dry_run || opts.diff {
+        print!("{}", diff::render(&diff::diff_lines(&original, &formatted), opts.color));
+        if opts.dry_run {
+            return Ok(());
+        }
+    }
+
+    // Write back, or to the output file
+    let written = opts.output_file.unwrap_or(&msg_file);
+    output::write_file(written, formatted.as_bytes(), opts.append).map_err(Error::Io)?;
+
+    if is_todo {
+        println!("{} {}", "\u{2714}".green(), written.display());
+        return Ok(());
     }
 
     // Format with color for display
-    let display = if commit {
-        cc.format_commit(&text, true)
-    } else {
-        formatted.clone()
-    };
+    let display = format_file(true);
 
     // Print with checkmark
     let first_line = display.lines().next().unwrap_or(&display);
     println!("{} {}", "\u{2714}".green(), first_line);
-}
-
-fn find_git_dir() -> Option<PathBuf> {
-    let mut dir = std::env::current_dir().ok()?;
-    loop {
-        let git = dir.join(".git");
-        if git.exists() {
-            return Some(git);
-        }
-        if !dir.pop() {
-            return None;
-        }
-    }
+    Ok(())
 }
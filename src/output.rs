@@ -0,0 +1,210 @@
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::Path;
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::devmoji::Devmoji;
+
+static SHORTCODE_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r":([a-zA-Z0-9_\-+]+):").unwrap());
+
+/// Emoji format explicitly requested with `--format`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Format {
+    Unicode,
+    Shortcode,
+    Devmoji,
+    Strip,
+    /// Bracketed tags like `[feat]`, for terminals without emoji
+    Ascii,
+    /// Escape sequences like `\u{1f41b}`, for Rust and JS source
+    Escape,
+    /// Code points like `U+1F41B`, e.g. to debug rendering
+    Codepoint,
+}
+
+/// Where the output ends up, which decides how emoji are best represented
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Target {
+    Terminal,
+    File,
+    Markdown,
+    Html,
+    Email,
+}
+
+/// A way of writing a single emoji
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Repr {
+    Unicode,
+    Shortcode,
+    /// HTML numeric character references, e.g. `&#x2728;`
+    HtmlEntity,
+    /// A bracketed tag like `[feat]`
+    Ascii,
+    /// `\u{..}` escape sequences
+    Escape,
+    /// `U+..` code points, space separated
+    Codepoint,
+}
+
+impl Target {
+    /// Pick the target for a mode: message files are files, everything else
+    /// goes to the terminal unless stdout is redirected.
+    pub fn detect(edit: bool) -> Self {
+        if !edit && atty::is(atty::Stream::Stdout) {
+            Target::Terminal
+        } else {
+            Target::File
+        }
+    }
+
+    /// Representations to try in order; the first one able to render an emoji wins.
+    pub fn fallbacks(self, unicode: bool) -> &'static [Repr] {
+        match self {
+            Target::Terminal if !unicode => &[Repr::Ascii, Repr::Shortcode],
+            Target::Terminal => &[Repr::Unicode, Repr::Shortcode, Repr::Ascii],
+            Target::File | Target::Email => &[Repr::Unicode, Repr::Shortcode],
+            Target::Markdown => &[Repr::Shortcode, Repr::Unicode],
+            Target::Html => &[Repr::HtmlEntity, Repr::Shortcode],
+        }
+    }
+}
+
+/// How emoji are written in the final output
+#[derive(Debug, Clone, Copy)]
+pub struct Output {
+    /// Explicit format, overriding the target's fallback chain
+    pub format: Option<Format>,
+    pub target: Target,
+    /// Whether the terminal can display emoji
+    pub unicode: bool,
+}
+
+impl Output {
+    pub fn new(format: Option<Format>, target: Target) -> Self {
+        let unicode = target != Target::Terminal || terminal_supports_unicode();
+        Output {
+            format,
+            target,
+            unicode,
+        }
+    }
+
+    /// Write all emoji in `text` in this output's representation.
+    pub fn render(&self, dm: &Devmoji, text: &str) -> String {
+        match self.format {
+            Some(Format::Unicode) => dm.emojify(text),
+            Some(Format::Shortcode) => dm.demojify(text),
+            Some(Format::Devmoji) => dm.devmojify(text),
+            Some(Format::Strip) => dm.strip(text),
+            Some(Format::Ascii) => self.render_with(dm, text, &[Repr::Ascii]),
+            Some(Format::Escape) => self.render_with(dm, text, &[Repr::Escape]),
+            Some(Format::Codepoint) => self.render_with(dm, text, &[Repr::Codepoint]),
+            None => self.render_with(dm, text, self.target.fallbacks(self.unicode)),
+        }
+    }
+
+    /// Write each emoji in the first of `reprs` able to represent it
+    fn render_with(&self, dm: &Devmoji, text: &str, reprs: &[Repr]) -> String {
+        let text = dm.demojify(text);
+        SHORTCODE_RE
+            .replace_all(&text, |caps: &regex::Captures| {
+                let code = &caps[1];
+                reprs
+                    .iter()
+                    .find_map(|repr| render_emoji(dm, code, *repr))
+                    .unwrap_or_else(|| caps[0].to_string())
+            })
+            .into_owned()
+    }
+}
+
+/// Write a single emoji, or `None` when `repr` can't represent it.
+fn render_emoji(dm: &Devmoji, code: &str, repr: Repr) -> Option<String> {
+    let emoji = dm.try_get(code)?;
+    // Literals have no other form, and their shortcode means nothing elsewhere
+    if dm.is_literal(code) && repr != Repr::Ascii {
+        return Some(emoji);
+    }
+    match repr {
+        Repr::Unicode => Some(emoji),
+        Repr::Shortcode => Some(format!(":{}:", code)),
+        Repr::HtmlEntity => Some(emoji.chars().map(|c| format!("&#x{:x};", c as u32)).collect()),
+        Repr::Escape => Some(emoji.chars().map(|c| format!("\\u{{{:x}}}", c as u32)).collect()),
+        Repr::Codepoint => Some(
+            emoji
+                .chars()
+                .map(|c| format!("U+{:04X}", c as u32))
+                .collect::<Vec<_>>()
+                .join(" "),
+        ),
+        Repr::Ascii => {
+            // Prefer the devmoji code, it reads better than the emoji name
+            let devmoji = dm.devmojify(&format!(":{}:", code));
+            Some(format!("[{}]", devmoji.trim_matches(':')))
+        }
+    }
+}
+
+/// Guess whether the terminal displays emoji: not on `TERM=dumb` or the Linux
+/// console, and not with a non UTF-8 locale such as `C`.
+pub fn terminal_supports_unicode() -> bool {
+    let term = std::env::var("TERM").unwrap_or_default();
+    if matches!(term.as_str(), "dumb" | "linux" | "vt100" | "vt102" | "vt220" | "ansi") {
+        return false;
+    }
+    // The first locale variable that is set wins, like in setlocale
+    let locale = ["LC_ALL", "LC_CTYPE", "LANG"]
+        .iter()
+        .filter_map(|var| std::env::var(var).ok())
+        .find(|value| !value.is_empty());
+    match locale {
+        Some(locale) => {
+            let locale = locale.to_ascii_lowercase();
+            locale.contains("utf-8") || locale.contains("utf8")
+        }
+        None => true,
+    }
+}
+
+/// Write `text` to `path` through a temporary file renamed over it, so the
+/// file is never partially written. An existing file keeps its permissions,
+/// and with `append` its contents come first.
+pub fn write_file(path: &Path, text: &[u8], append: bool) -> Result<(), String> {
+    let error = |e: std::io::Error| format!("Error writing {}: {}", path.display(), e);
+    // Replace the file a symlink points to rather than the link
+    let target = fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+    let existing = fs::metadata(&target).ok();
+    let mut contents = match &existing {
+        Some(_) if append => fs::read(&target).map_err(error)?,
+        _ => Vec::new(),
+    };
+    contents.extend_from_slice(text);
+
+    let name = target
+        .file_name()
+        .ok_or_else(|| format!("Error writing {}: not a file", path.display()))?;
+    let tmp = target.with_file_name(format!(
+        ".{}.{}.tmp",
+        name.to_string_lossy(),
+        std::process::id()
+    ));
+    let written = OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&tmp)
+        .and_then(|mut file| {
+            file.write_all(&contents)?;
+            if let Some(metadata) = &existing {
+                file.set_permissions(metadata.permissions())?;
+            }
+            file.sync_all()
+        })
+        .and_then(|()| fs::rename(&tmp, &target));
+    if written.is_err() {
+        let _ = fs::remove_file(&tmp);
+    }
+    written.map_err(error)
+}
@@ -0,0 +1,117 @@
+use serde::Serialize;
+
+use crate::commits::ConventionalCommits;
+use crate::devmoji::Devmoji;
+use crate::spec;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum ParseFormat {
+    Json,
+    /// `key='value'` lines for `eval`
+    Shell,
+}
+
+/// The components of a commit message
+#[derive(Debug, Serialize)]
+pub struct Parsed {
+    /// Whether the header follows the configured grammar
+    pub conventional: bool,
+    #[serde(rename = "type")]
+    pub commit_type: Option<String>,
+    pub scope: Option<String>,
+    /// A `!` in the header or a breaking change footer
+    pub breaking: bool,
+    /// The header after type, scope and emoji, or the whole header when it
+    /// isn't conventional
+    pub description: String,
+    pub body: String,
+    pub footers: Vec<ParsedFooter>,
+    /// The emoji formatting adds, as unicode
+    pub emoji: String,
+    /// The same emoji as shortcodes
+    pub emoji_codes: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ParsedFooter {
+    pub token: String,
+    pub value: String,
+}
+
+pub fn parse(dm: &Devmoji, cc: &ConventionalCommits, text: &str) -> Parsed {
+    let message = spec::parse(text);
+    let header = cc.parse_message(text);
+    let emoji = header.as_ref().map_or(String::new(), |h| h.emoji.clone());
+    let emoji_codes = dm
+        .demojify(&emoji)
+        .split_whitespace()
+        .map(|code| code.trim_matches(':').to_string())
+        .collect();
+
+    Parsed {
+        conventional: header.is_some(),
+        commit_type: header.as_ref().map(|h| h.commit_type.clone()),
+        scope: header.as_ref().and_then(|h| h.scope.clone()),
+        breaking: header.as_ref().is_some_and(|h| h.breaking),
+        description: match &header {
+            Some(h) => h.subject.clone(),
+            None => dm.emojify(message.header.trim()),
+        },
+        body: message.body.join("\n"),
+        footers: message
+            .footers
+            .into_iter()
+            .map(|f| ParsedFooter {
+                token: f.token,
+                value: f.value,
+            })
+            .collect(),
+        emoji,
+        emoji_codes,
+    }
+}
+
+pub fn render(parsed: &Parsed, format: ParseFormat) -> String {
+    match format {
+        ParseFormat::Json => {
+            let mut out = serde_json::to_string_pretty(parsed).unwrap();
+            out.push('\n');
+            out
+        }
+        ParseFormat::Shell => {
+            let mut vars = vec![
+                ("conventional", parsed.conventional.to_string()),
+                ("type", parsed.commit_type.clone().unwrap_or_default()),
+                ("scope", parsed.scope.clone().unwrap_or_default()),
+                ("breaking", parsed.breaking.to_string()),
+                ("description", parsed.description.clone()),
+                ("body", parsed.body.clone()),
+                ("emoji", parsed.emoji.clone()),
+                ("emoji_codes", parsed.emoji_codes.join(" ")),
+            ];
+            let tokens: Vec<String> = parsed
+                .footers
+                .iter()
+                .map(|f| format!("footer_{}", shell_name(&f.token)))
+                .collect();
+            for (f, name) in parsed.footers.iter().zip(&tokens) {
+                vars.push((name.as_str(), f.value.clone()));
+            }
+            vars.iter()
+                .map(|(name, value)| format!("{}={}\n", name, shell_quote(value)))
+                .collect()
+        }
+    }
+}
+
+/// A footer token as variable name, e.g. `BREAKING CHANGE` -> `breaking_change`
+fn shell_name(token: &str) -> String {
+    token
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c.to_ascii_lowercase() } else { '_' })
+        .collect()
+}
+
+fn shell_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', r"'\''"))
+}
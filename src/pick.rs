@@ -0,0 +1,185 @@
+use std::io::{self, Write};
+
+use crossterm::cursor::{Hide, MoveTo, Show};
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use crossterm::style::{Attribute, Print, SetAttribute};
+use crossterm::terminal::{self, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen};
+use crossterm::{execute, queue};
+
+use crate::devmoji::Devmoji;
+use crate::search::{self, Hit};
+
+/// Restores the terminal when the picker is done, also on errors
+struct Screen;
+
+impl Screen {
+    fn enter() -> io::Result<Self> {
+        terminal::enable_raw_mode()?;
+        if let Err(e) = execute!(io::stderr(), EnterAlternateScreen, Hide) {
+            let _ = terminal::disable_raw_mode();
+            return Err(e);
+        }
+        Ok(Screen)
+    }
+}
+
+impl Drop for Screen {
+    fn drop(&mut self) {
+        let _ = execute!(io::stderr(), Show, LeaveAlternateScreen);
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+/// The picker's state: the query typed so far and what it matches
+struct Picker<'a> {
+    dm: &'a Devmoji,
+    query: String,
+    hits: Vec<Hit>,
+    total: usize,
+    selected: usize,
+    /// Index of the first hit on screen
+    offset: usize,
+}
+
+impl<'a> Picker<'a> {
+    fn new(dm: &'a Devmoji, query: &str) -> Self {
+        let mut picker = Picker {
+            dm,
+            query: query.to_string(),
+            hits: Vec::new(),
+            total: search::all(dm).len(),
+            selected: 0,
+            offset: 0,
+        };
+        picker.update();
+        picker
+    }
+
+    fn update(&mut self) {
+        self.hits = if self.query.trim().is_empty() {
+            search::all(self.dm)
+        } else {
+            search::search(self.dm, &self.query)
+        };
+        self.selected = 0;
+        self.offset = 0;
+    }
+
+    fn select(&mut self, delta: isize, rows: usize) {
+        if self.hits.is_empty() {
+            return;
+        }
+        let last = self.hits.len() - 1;
+        self.selected = self.selected.saturating_add_signed(delta).min(last);
+        if self.selected < self.offset {
+            self.offset = self.selected;
+        } else if self.selected >= self.offset + rows {
+            self.offset = self.selected + 1 - rows;
+        }
+    }
+
+    fn draw(&self, out: &mut impl Write, width: usize, rows: usize) -> io::Result<()> {
+        queue!(out, MoveTo(0, 0), Clear(ClearType::All))?;
+        queue!(out, Print(format!("> {}", self.query)))?;
+        let count = format!("{}/{}", self.hits.len(), self.total);
+        let prompt = self.query.chars().count() + 2;
+        if prompt + count.len() + 1 < width {
+            queue!(out, MoveTo((width - count.len()) as u16, 0))?;
+            queue!(
+                out,
+                SetAttribute(Attribute::Dim),
+                Print(count),
+                SetAttribute(Attribute::Reset)
+            )?;
+        }
+        for (row, (i, hit)) in self
+            .hits
+            .iter()
+            .enumerate()
+            .skip(self.offset)
+            .take(rows)
+            .enumerate()
+        {
+            let line = format!(
+                "{}  {:30} {}",
+                hit.emoji,
+                format!(":{}:", hit.code),
+                hit.description
+            );
+            // Emoji are two columns wide, which the margin accounts for
+            let line: String = line.chars().take(width.saturating_sub(2)).collect();
+            queue!(out, MoveTo(0, row as u16 + 1))?;
+            if i == self.selected {
+                queue!(
+                    out,
+                    SetAttribute(Attribute::Reverse),
+                    Print(line),
+                    SetAttribute(Attribute::Reset)
+                )?;
+            } else {
+                queue!(out, Print(line))?;
+            }
+        }
+        out.flush()
+    }
+}
+
+/// Let the user pick an emoji, starting with `query` typed. The picker is drawn
+/// on stderr so stdout can be captured, e.g. `git commit -m "$(devmoji pick) ..."`.
+/// `None` when it was cancelled with Esc or Ctrl-C.
+pub fn pick(dm: &Devmoji, query: &str) -> Result<Option<Hit>, String> {
+    let screen = Screen::enter().map_err(|e| format!("Can't use the terminal: {}", e))?;
+    let result = run(&mut Picker::new(dm, query));
+    drop(screen);
+    result.map_err(|e| format!("Error reading the terminal: {}", e))
+}
+
+fn run(picker: &mut Picker) -> io::Result<Option<Hit>> {
+    let mut out = io::stderr();
+    loop {
+        // Some terminals, e.g. a bare pty, report no size
+        let (width, height) = match terminal::size()? {
+            (0, _) | (_, 0) => (80, 24),
+            size => size,
+        };
+        let rows = (height as usize).saturating_sub(1).max(1);
+        picker.draw(&mut out, width as usize, rows)?;
+
+        let Event::Key(KeyEvent {
+            code,
+            modifiers,
+            kind: KeyEventKind::Press | KeyEventKind::Repeat,
+            ..
+        }) = event::read()?
+        else {
+            continue;
+        };
+        let ctrl = modifiers.contains(KeyModifiers::CONTROL);
+        match code {
+            KeyCode::Esc => return Ok(None),
+            KeyCode::Char('c' | 'd' | 'g') if ctrl => return Ok(None),
+            KeyCode::Enter => {
+                return Ok(match picker.hits.is_empty() {
+                    true => None,
+                    false => Some(picker.hits.swap_remove(picker.selected)),
+                });
+            }
+            KeyCode::Up => picker.select(-1, rows),
+            KeyCode::Char('p' | 'k') if ctrl => picker.select(-1, rows),
+            KeyCode::Down | KeyCode::Tab => picker.select(1, rows),
+            KeyCode::Char('n' | 'j') if ctrl => picker.select(1, rows),
+            KeyCode::PageUp => picker.select(-(rows as isize), rows),
+            KeyCode::PageDown => picker.select(rows as isize, rows),
+            KeyCode::Char('u') if ctrl => {
+                picker.query.clear();
+                picker.update();
+            }
+            KeyCode::Backspace if picker.query.pop().is_some() => picker.update(),
+            KeyCode::Char(c) if !ctrl => {
+                picker.query.push(c);
+                picker.update();
+            }
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,109 @@
+use std::path::{Path, PathBuf};
+
+use crate::commits::ConventionalCommits;
+use crate::devmoji::Devmoji;
+use crate::output::Output;
+use crate::{debug, git, info};
+
+/// The pre-commit framework stage devmoji runs in, deciding what the file
+/// arguments are
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Stage {
+    /// Staged files: emoji shortcodes in them are fixed
+    PreCommit,
+    /// The commit message file: its header is formatted
+    PrepareCommitMsg,
+    /// The commit message file: it is linted
+    CommitMsg,
+}
+
+/// What a run did. `failed` is set when a file was changed or had errors,
+/// which pre-commit reports as a failed hook.
+#[derive(Debug, Default)]
+pub struct Outcome {
+    pub done: Vec<String>,
+    pub errors: Vec<String>,
+    pub failed: bool,
+}
+
+pub fn run(
+    dm: &Devmoji,
+    cc: &ConventionalCommits,
+    output: Output,
+    stage: Stage,
+    files: &[PathBuf],
+) -> Outcome {
+    let mut outcome = Outcome::default();
+    match stage {
+        Stage::PreCommit => {
+            for file in files {
+                fix_file(dm, output, file, &mut outcome);
+            }
+        }
+        Stage::PrepareCommitMsg | Stage::CommitMsg => {
+            let Some(file) = files.first() else {
+                outcome.errors.push("Expected the commit message file as argument".to_string());
+                outcome.failed = true;
+                return outcome;
+            };
+            let text = match std::fs::read_to_string(file) {
+                Ok(text) => text,
+                Err(e) => {
+                    outcome.errors.push(format!("Error reading {}: {}", file.display(), e));
+                    outcome.failed = true;
+                    return outcome;
+                }
+            };
+            let comment = git::comment_prefix();
+            if stage == Stage::CommitMsg {
+                let mut lint = Ok(Vec::new());
+                git::map_message(&text, &comment, |message| {
+                    lint = cc.lint(message);
+                    message.to_string()
+                });
+                match lint {
+                    // Warnings are printed, but don't fail the hook
+                    Ok(warnings) => outcome.errors.extend(warnings),
+                    Err(errors) => {
+                        outcome.errors.extend(errors);
+                        outcome.failed = true;
+                    }
+                }
+            } else {
+                // Formatting the message is the point of this stage, not a failure
+                let formatted = git::map_message(&text, &comment, |message| {
+                    let message = cc.complete_message(message);
+                    output.render(dm, &cc.format_commit(&message, false))
+                });
+                if formatted != text {
+                    if let Err(e) = std::fs::write(file, &formatted) {
+                        outcome.errors.push(format!("Error writing {}: {}", file.display(), e));
+                        outcome.failed = true;
+                        return outcome;
+                    }
+                }
+                outcome.done.push(formatted.lines().next().unwrap_or("").to_string());
+            }
+        }
+    }
+    outcome
+}
+
+/// Render the emoji of a staged file like a file target, rewriting it when
+/// that changes anything.
+fn fix_file(dm: &Devmoji, output: Output, file: &Path, outcome: &mut Outcome) {
+    let Ok(text) = std::fs::read_to_string(file) else {
+        debug!("pre-commit", "skipping {}, not a UTF-8 file", file.display());
+        return;
+    };
+    let formatted = output.render(dm, &text);
+    if formatted == text {
+        return;
+    }
+    info!("pre-commit", "rewriting {}", file.display());
+    match std::fs::write(file, formatted) {
+        Ok(()) => outcome.done.push(format!("Fixed {}", file.display())),
+        Err(e) => outcome.errors.push(format!("Error writing {}: {}", file.display(), e)),
+    }
+    outcome.failed = true;
+}
@@ -0,0 +1,116 @@
+//! Reports of linting a range of commits, see `--lint --range`
+
+use serde::Serialize;
+use serde_json::json;
+
+use crate::commits::{ConventionalCommits, Problem};
+use crate::config::Severity;
+use crate::fnv::Fnv;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Reporter {
+    /// The problems of each commit under its hash and header
+    Text,
+    /// GitLab Code Quality JSON, shown in merge request widgets
+    Gitlab,
+}
+
+/// A linted commit
+pub struct CommitLint {
+    pub hash: String,
+    pub header: String,
+    pub problems: Vec<Problem>,
+    /// The lint output, with the warnings over `maxWarnings` when it fails
+    pub messages: Vec<String>,
+    pub passed: bool,
+}
+
+/// Lint the messages of `commits`, given as hash and message
+pub fn lint(cc: &ConventionalCommits, commits: Vec<(String, String)>) -> Vec<CommitLint> {
+    commits
+        .into_iter()
+        .map(|(hash, message)| {
+            let problems = cc.problems(&message);
+            let (passed, messages) = match cc.judge(&problems) {
+                Ok(warnings) => (true, warnings),
+                Err(errors) => (false, errors),
+            };
+            CommitLint {
+                header: message.lines().next().unwrap_or("").to_string(),
+                problems,
+                messages,
+                passed,
+                hash,
+            }
+        })
+        .collect()
+}
+
+pub fn render(commits: &[CommitLint], reporter: Reporter) -> String {
+    match reporter {
+        Reporter::Text => render_text(commits),
+        Reporter::Gitlab => {
+            let mut out = serde_json::to_string_pretty(&gitlab_issues(commits)).unwrap();
+            out.push('\n');
+            out
+        }
+    }
+}
+
+fn render_text(commits: &[CommitLint]) -> String {
+    let mut out = String::new();
+    for commit in commits.iter().filter(|c| !c.messages.is_empty()) {
+        out.push_str(&format!("{} {}\n", short_hash(&commit.hash), commit.header));
+        for message in &commit.messages {
+            out.push_str(&format!("  {}\n", message));
+        }
+    }
+    out
+}
+
+/// A Code Quality issue, see
+/// https://docs.gitlab.com/ci/testing/code_quality/#code-quality-report-format
+#[derive(Serialize)]
+struct Issue {
+    description: String,
+    check_name: String,
+    fingerprint: String,
+    severity: &'static str,
+    location: serde_json::Value,
+}
+
+fn gitlab_issues(commits: &[CommitLint]) -> Vec<Issue> {
+    let mut issues = Vec::new();
+    for commit in commits {
+        for problem in &commit.problems {
+            let rule = serde_json::to_value(problem.rule).unwrap();
+            let rule = rule.as_str().unwrap_or_default();
+            issues.push(Issue {
+                description: format!("{}: {}", short_hash(&commit.hash), problem.message),
+                check_name: format!("devmoji/{}", rule),
+                fingerprint: fingerprint(&[&commit.hash, rule, &problem.message]),
+                severity: match problem.severity {
+                    Severity::Warn => "minor",
+                    _ => "major",
+                },
+                // Commits aren't files, the widget shows the hash as the path
+                location: json!({ "path": commit.hash, "lines": { "begin": 1 } }),
+            });
+        }
+    }
+    issues
+}
+
+/// A stable ID of an issue, so GitLab can tell new issues from fixed ones
+fn fingerprint(parts: &[&str]) -> String {
+    let mut hash = Fnv::new();
+    for part in parts {
+        hash.write(part.as_bytes());
+        hash.write(&[0]);
+    }
+    hash.hex()
+}
+
+fn short_hash(hash: &str) -> &str {
+    &hash[..hash.len().min(7)]
+}
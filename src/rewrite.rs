@@ -0,0 +1,176 @@
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+use crate::info;
+
+/// A commit in the range, with its message before and after formatting
+pub struct Reword {
+    pub hash: String,
+    pub parent: String,
+    pub tree: String,
+    /// Name, email and raw date, kept for the new commit
+    pub author: (String, String, String),
+    pub old: String,
+    pub new: String,
+}
+
+impl Reword {
+    pub fn changed(&self) -> bool {
+        self.old != self.new
+    }
+
+    pub fn short_hash(&self) -> &str {
+        &self.hash[..self.hash.len().min(7)]
+    }
+}
+
+/// The commits to reword, oldest first, with their messages passed through
+/// `format`. `range` is a base commit or `base..HEAD` and defaults to the
+/// commits not pushed to the upstream branch yet.
+pub fn plan(range: Option<&str>, format: impl Fn(&str) -> String) -> Result<Vec<Reword>, String> {
+    let base = match range {
+        Some(range) => base_of(range)?,
+        None => git(&["rev-parse", "--verify", "--quiet", "@{upstream}"]).map_err(|_| {
+            "HEAD has no upstream branch, pass the commits with --range, e.g. --range HEAD~3"
+                .to_string()
+        })?,
+    };
+    let commits = format!("{}..HEAD", base.trim());
+    if !git(&["rev-list", "--merges", &commits])?.trim().is_empty() {
+        return Err(format!(
+            "{} has merge commits, which can't be rewritten",
+            commits
+        ));
+    }
+
+    let mut rewords = Vec::new();
+    for line in git(&["rev-list", "--reverse", "--parents", &commits])?.lines() {
+        let mut hashes = line.split_whitespace();
+        let (Some(hash), Some(parent)) = (hashes.next(), hashes.next()) else {
+            return Err("Can't rewrite the root commit".to_string());
+        };
+        let fields = git(&[
+            "show",
+            "-s",
+            "--date=raw",
+            "--format=%T%x00%an%x00%ae%x00%ad",
+            hash,
+        ])?;
+        let fields: Vec<&str> = fields.trim_end().split('\0').collect();
+        let [tree, name, email, date] = fields[..] else {
+            return Err(format!("Can't read commit {}", hash));
+        };
+        let object = git(&["cat-file", "commit", hash])?;
+        let old = object
+            .split_once("\n\n")
+            .map_or("", |(_, message)| message)
+            .to_string();
+        rewords.push(Reword {
+            hash: hash.to_string(),
+            parent: parent.to_string(),
+            tree: tree.to_string(),
+            author: (name.to_string(), email.to_string(), date.to_string()),
+            new: format(&old),
+            old,
+        });
+    }
+    Ok(rewords)
+}
+
+/// Recreate the commits with their new messages and point HEAD at the last
+/// one. The trees are unchanged, so the index and work tree are too.
+pub fn apply(rewords: &[Reword]) -> Result<Vec<String>, String> {
+    let Some(last) = rewords.last() else {
+        return Ok(vec!["No commits to rewrite".to_string()]);
+    };
+    let mut parent = rewords[0].parent.clone();
+    let mut rewritten = 0;
+    for reword in rewords {
+        // Commits before the first changed one are kept as they are
+        if !reword.changed() && parent == reword.parent {
+            parent = reword.hash.clone();
+            continue;
+        }
+        let (name, email, date) = &reword.author;
+        let output = Command::new("git")
+            .args(["commit-tree", &reword.tree, "-p", &parent])
+            .env("GIT_AUTHOR_NAME", name)
+            .env("GIT_AUTHOR_EMAIL", email)
+            .env("GIT_AUTHOR_DATE", date)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .and_then(|mut child| {
+                child
+                    .stdin
+                    .take()
+                    .unwrap()
+                    .write_all(reword.new.as_bytes())?;
+                child.wait_with_output()
+            })
+            .map_err(|e| format!("Error running git: {}", e))?;
+        if !output.status.success() {
+            return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+        }
+        parent = String::from_utf8_lossy(&output.stdout).trim().to_string();
+        rewritten += usize::from(reword.changed());
+    }
+    if rewritten == 0 {
+        return Ok(vec!["Every message is already formatted".to_string()]);
+    }
+
+    // Fails when HEAD moved in the meantime
+    git(&[
+        "update-ref",
+        "-m",
+        "devmoji rewrite",
+        "HEAD",
+        &parent,
+        &last.hash,
+    ])?;
+    git(&["update-ref", "ORIG_HEAD", &last.hash])?;
+    Ok(vec![
+        format!("Rewrote {} of {} commits", rewritten, rewords.len()),
+        "The old commits are in ORIG_HEAD, git reset --soft ORIG_HEAD undoes it".to_string(),
+    ])
+}
+
+/// Commits in the range that are on a remote branch already
+pub fn pushed(rewords: &[Reword]) -> usize {
+    let (Some(first), Some(last)) = (rewords.first(), rewords.last()) else {
+        return 0;
+    };
+    let range = format!("{}..{}", first.parent, last.hash);
+    git(&["rev-list", "--count", &range, "--not", "--remotes"])
+        .ok()
+        .and_then(|count| count.trim().parse::<usize>().ok())
+        .map_or(0, |unpushed| rewords.len().saturating_sub(unpushed))
+}
+
+/// The base commit of `range`, which has to end at HEAD
+fn base_of(range: &str) -> Result<String, String> {
+    let Some((base, tip)) = range.split_once("..") else {
+        return git(&["rev-parse", "--verify", &format!("{}^{{commit}}", range)]);
+    };
+    let tip = if tip.is_empty() { "HEAD" } else { tip };
+    if git(&["rev-parse", tip])? != git(&["rev-parse", "HEAD"])? {
+        return Err(format!(
+            "{} doesn't end at HEAD, only commits up to HEAD can be rewritten",
+            range
+        ));
+    }
+    git(&["rev-parse", "--verify", &format!("{}^{{commit}}", base)])
+}
+
+fn git(args: &[&str]) -> Result<String, String> {
+    info!("git", "running git {}", args.join(" "));
+    let output = Command::new("git")
+        .args(args)
+        .output()
+        .map_err(|e| format!("Error running git: {}", e))?;
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+}
@@ -0,0 +1,186 @@
+use std::collections::HashSet;
+
+use crate::commits::edit_distance;
+use crate::devmoji::Devmoji;
+use crate::github_emoji::EMOJIS;
+use crate::gitmoji::{self, GITMOJIS};
+
+/// An emoji matching a search, with the shortcode it matched by
+#[derive(Debug)]
+pub struct Hit {
+    pub emoji: String,
+    pub code: String,
+    pub description: String,
+    pub score: u32,
+}
+
+/// An emoji that can be found, with all its shortcodes
+struct Candidate {
+    emoji: String,
+    codes: Vec<String>,
+    description: String,
+}
+
+/// Emoji matching every word of `query` in a shortcode or description, best
+/// first. Devmojis come before gitmojis and GitHub's emoji on equal scores.
+/// The query may be an emoji itself, e.g. `♻️`, to find its shortcodes.
+pub fn search(dm: &Devmoji, query: &str) -> Vec<Hit> {
+    let query = dm.demojify(query).to_lowercase();
+    let words: Vec<&str> = split_words(&query).collect();
+    if words.is_empty() {
+        return Vec::new();
+    }
+
+    let mut hits: Vec<Hit> = candidates(dm)
+        .into_iter()
+        .filter_map(|candidate| {
+            let mut total = 0;
+            let mut best_code = 0;
+            for word in &words {
+                let (score, code) = candidate
+                    .codes
+                    .iter()
+                    .enumerate()
+                    .map(|(i, code)| (code_score(word, code), i))
+                    .max_by_key(|&(score, i)| (score, std::cmp::Reverse(i)))?;
+                // A matching shortcode counts more than a matching description
+                let description = split_words(&candidate.description.to_lowercase())
+                    .map(|w| word_score(word, w) * 3 / 4)
+                    .max()
+                    .unwrap_or(0);
+                if score.max(description) == 0 {
+                    return None;
+                }
+                if score >= description && score > 0 {
+                    best_code = code;
+                }
+                total += score.max(description);
+            }
+            Some(Hit {
+                emoji: candidate.emoji,
+                code: candidate.codes[best_code].clone(),
+                description: candidate.description,
+                score: total,
+            })
+        })
+        .collect();
+    // Stable, so the candidate order breaks ties
+    hits.sort_by_key(|hit| std::cmp::Reverse(hit.score));
+    hits
+}
+
+/// Every emoji a search looks at, in the order ties are broken in
+pub fn all(dm: &Devmoji) -> Vec<Hit> {
+    candidates(dm)
+        .into_iter()
+        .map(|candidate| Hit {
+            emoji: candidate.emoji,
+            code: candidate.codes[0].clone(),
+            description: candidate.description,
+            score: 0,
+        })
+        .collect()
+}
+
+/// The pack, then gitmojis and GitHub's emoji, each shortcode only once
+fn candidates(dm: &Devmoji) -> Vec<Candidate> {
+    let mut seen: HashSet<String> = HashSet::new();
+    let mut candidates = Vec::new();
+    for entry in dm.pack() {
+        if seen.insert(entry.code.clone()) {
+            candidates.push(Candidate {
+                emoji: dm.entry_emoji(entry),
+                codes: vec![entry.code.clone()],
+                description: entry.description.clone(),
+            });
+        }
+    }
+    // GitHub's emoji, described by the gitmoji with the same shortcode
+    for &(emoji, codes) in EMOJIS {
+        let codes: Vec<String> = codes
+            .iter()
+            .filter(|code| seen.insert(code.to_string()))
+            .map(|code| code.to_string())
+            .collect();
+        if codes.is_empty() {
+            continue;
+        }
+        let description = codes
+            .iter()
+            .find_map(|code| gitmoji::index(code))
+            .map_or("", |i| GITMOJIS[i].description);
+        candidates.push(Candidate {
+            emoji: emoji.to_string(),
+            codes,
+            description: description.to_string(),
+        });
+    }
+    // Gitmojis GitHub does not know
+    for gitmoji in GITMOJIS.iter() {
+        if seen.insert(gitmoji.code.to_string()) {
+            candidates.push(Candidate {
+                emoji: gitmoji.emoji.to_string(),
+                codes: vec![gitmoji.code.to_string()],
+                description: gitmoji.description.to_string(),
+            });
+        }
+    }
+    candidates
+}
+
+/// How well a query word matches a shortcode, as a whole or by its parts
+fn code_score(word: &str, code: &str) -> u32 {
+    let parts = split_words(code)
+        .map(|part| word_score(word, part))
+        .max()
+        .unwrap_or(0);
+    let whole = match word_score(word, code) {
+        0 if is_subsequence(word, code) => 20,
+        score => score,
+    };
+    // The whole shortcode matching exactly beats a part of it doing so
+    if code == word {
+        whole + 20
+    } else {
+        whole.max(parts)
+    }
+}
+
+/// How well a query word matches a word, 0 when it doesn't
+fn word_score(query: &str, word: &str) -> u32 {
+    let len = query.chars().count().min(word.chars().count());
+    let common = query
+        .chars()
+        .zip(word.chars())
+        .take_while(|(a, b)| a == b)
+        .count();
+    if query == word {
+        100
+    } else if word.starts_with(query) {
+        70
+    } else if common >= 4 && common * 4 >= len * 3 {
+        // The same stem, e.g. recycling and recycle
+        50
+    } else if query.len() >= 3 && word.contains(query) {
+        40
+    } else if len >= 4 && edit_distance(query, word) <= if len >= 6 { 2 } else { 1 } {
+        30
+    } else {
+        0
+    }
+}
+
+/// Whether the characters of `query` appear in `code` in order, starting
+/// with its first, e.g. `rcyl` in `recycle`
+fn is_subsequence(query: &str, code: &str) -> bool {
+    if query.chars().count() < 2 || code.chars().next() != query.chars().next() {
+        return false;
+    }
+    let mut chars = code.chars();
+    query.chars().all(|q| chars.any(|c| c == q))
+}
+
+fn split_words(text: &str) -> impl Iterator<Item = &str> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+}
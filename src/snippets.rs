@@ -0,0 +1,206 @@
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::config::Config;
+use crate::devmoji::Devmoji;
+
+static PLACEHOLDER_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"\{([a-z_]+)\}").unwrap());
+
+const DEFAULT_TEMPLATE: &str = "{type}({scope}){breaking}: {subject}";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Editor {
+    /// VS Code `.code-snippets` JSON
+    Vscode,
+    /// SnipMate format, loadable by LuaSnip and friends
+    Nvim,
+    /// JetBrains live templates XML
+    Jetbrains,
+}
+
+enum Part {
+    Text(String),
+    /// A tab stop with its default text
+    Stop(String),
+}
+
+struct Snippet {
+    name: String,
+    description: String,
+    parts: Vec<Part>,
+}
+
+/// Generate editor snippets expanding every type and `type-scope` devmoji
+/// into a header following the configured grammar.
+pub fn generate(dm: &Devmoji, cfg: &Config, editor: Editor) -> String {
+    let snippets = collect(dm, cfg);
+    match editor {
+        Editor::Vscode => render_vscode(&snippets),
+        Editor::Nvim => render_snipmate(&snippets),
+        Editor::Jetbrains => render_jetbrains(&snippets),
+    }
+}
+
+fn collect(dm: &Devmoji, cfg: &Config) -> Vec<Snippet> {
+    let template = cfg.header_template.as_deref().unwrap_or(DEFAULT_TEMPLATE);
+    let lookup = |code: &str| dm.pack().iter().find(|e| e.code == code);
+
+    let mut snippets = Vec::new();
+    for commit_type in &cfg.types {
+        let entry = lookup(commit_type);
+        snippets.push(Snippet {
+            name: commit_type.clone(),
+            description: entry.map(|e| e.description.clone()).unwrap_or_default(),
+            parts: expand(template, commit_type, None, entry.map(|e| dm.entry_emoji(e))),
+        });
+    }
+
+    for entry in dm.pack() {
+        let Some((commit_type, scope)) = entry.code.split_once('-') else {
+            continue;
+        };
+        if cfg.types.iter().any(|t| t == commit_type) {
+            snippets.push(Snippet {
+                name: entry.code.clone(),
+                description: entry.description.clone(),
+                parts: expand(template, commit_type, Some(scope), Some(dm.entry_emoji(entry))),
+            });
+        }
+    }
+    snippets
+}
+
+fn expand(template: &str, commit_type: &str, scope: Option<&str>, emoji: Option<String>) -> Vec<Part> {
+    let mut parts = Vec::new();
+    let mut last = 0;
+    for caps in PLACEHOLDER_RE.captures_iter(template) {
+        let m = caps.get(0).unwrap();
+        let mut literal = &template[last..m.start()];
+        last = m.end();
+
+        let part = match &caps[1] {
+            "type" => Part::Text(commit_type.to_string()),
+            "breaking" => Part::Text(String::new()),
+            "scope" => match scope {
+                Some(scope) => Part::Text(scope.to_string()),
+                // Optional scopes keep their parentheses inside the tab stop
+                None if literal.ends_with('(') && template[m.end()..].starts_with(')') => {
+                    literal = &literal[..literal.len() - 1];
+                    last += 1;
+                    Part::Stop("(scope)".to_string())
+                }
+                None => Part::Stop("scope".to_string()),
+            },
+            "subject" => {
+                if let Some(emoji) = &emoji {
+                    parts.push(Part::Text(format!("{}{} ", literal, emoji)));
+                    literal = "";
+                }
+                Part::Stop("description".to_string())
+            }
+            name => Part::Stop(name.to_string()),
+        };
+        if !literal.is_empty() {
+            parts.push(Part::Text(literal.to_string()));
+        }
+        parts.push(part);
+    }
+    if last < template.len() {
+        parts.push(Part::Text(template[last..].to_string()));
+    }
+    parts
+}
+
+fn render_vscode(snippets: &[Snippet]) -> String {
+    let mut map = serde_json::Map::new();
+    for snippet in snippets {
+        let mut body = String::new();
+        let mut stop = 0;
+        for part in &snippet.parts {
+            match part {
+                Part::Text(text) => body.push_str(&text.replace('$', "\\$")),
+                Part::Stop(default) => {
+                    stop += 1;
+                    body.push_str(&format!("${{{}:{}}}", stop, default));
+                }
+            }
+        }
+        map.insert(
+            snippet.name.clone(),
+            serde_json::json!({
+                "scope": "git-commit,scminput",
+                "prefix": snippet.name,
+                "body": [body],
+                "description": snippet.description,
+            }),
+        );
+    }
+    let mut out = serde_json::to_string_pretty(&map).unwrap();
+    out.push('\n');
+    out
+}
+
+fn render_snipmate(snippets: &[Snippet]) -> String {
+    let mut out = String::new();
+    for snippet in snippets {
+        out.push_str(&format!("snippet {} {}\n\t", snippet.name, snippet.description));
+        let mut stop = 0;
+        for part in &snippet.parts {
+            match part {
+                Part::Text(text) => out.push_str(&text.replace('$', "\\$")),
+                Part::Stop(default) => {
+                    stop += 1;
+                    out.push_str(&format!("${{{}:{}}}", stop, default));
+                }
+            }
+        }
+        out.push('\n');
+    }
+    out
+}
+
+fn render_jetbrains(snippets: &[Snippet]) -> String {
+    let mut out = String::from("<templateSet group=\"devmoji\">\n");
+    for snippet in snippets {
+        let mut value = String::new();
+        let mut variables = Vec::new();
+        for part in &snippet.parts {
+            match part {
+                Part::Text(text) => value.push_str(&text.replace('$', "$$")),
+                Part::Stop(default) => {
+                    let name: String = default
+                        .chars()
+                        .filter(|c| c.is_ascii_alphanumeric() || *c == '_')
+                        .collect::<String>()
+                        .to_uppercase();
+                    value.push_str(&format!("${}$", name));
+                    variables.push((name, default));
+                }
+            }
+        }
+        out.push_str(&format!(
+            "  <template name=\"{}\" value=\"{}\" description=\"{}\" toReformat=\"false\" toShortenFQNames=\"false\">\n",
+            xml_escape(&snippet.name),
+            xml_escape(&value),
+            xml_escape(&snippet.description)
+        ));
+        for (name, default) in variables {
+            out.push_str(&format!(
+                "    <variable name=\"{}\" expression=\"\" defaultValue=\"{}\" alwaysStopAt=\"true\" />\n",
+                name,
+                xml_escape(&format!("\"{}\"", default))
+            ));
+        }
+        out.push_str("    <context>\n      <option name=\"OTHER\" value=\"true\" />\n    </context>\n");
+        out.push_str("  </template>\n");
+    }
+    out.push_str("</templateSet>\n");
+    out
+}
+
+fn xml_escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
@@ -0,0 +1,265 @@
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::tr;
+
+/// Conventional Commits specification versions devmoji can enforce
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum, serde::Deserialize, schemars::JsonSchema,
+)]
+pub enum Spec {
+    #[value(name = "1.0.0")]
+    #[serde(rename = "1.0.0")]
+    V1_0_0,
+}
+
+/// Header grammar used for formatting in spec mode: a noun type, an optional
+/// scope in parentheses, an optional `!` and a mandatory colon and space.
+pub static HEADER_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?m)(?P<type>:?[A-Za-z]+)(?:\((?P<scope>[^()\r\n]+)\))?(?P<breaking>!?): (?:(?P<other>(?::[a-z0-9_+-]+:\s*)+)\s*)?")
+        .unwrap()
+});
+
+static STRICT_HEADER_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^(?P<type>[A-Za-z]+)(?:\((?P<scope>[^()\r\n]+)\))?(?P<breaking>!)?: (?P<description>\S.*)$")
+        .unwrap()
+});
+
+/// The loose header devmoji accepts by default, used to explain divergences
+static LOOSE_HEADER_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)^(?P<type>[a-z][a-z0-9-]*)(?:\((?P<scope>[a-z0-9-]+)\))?(?P<breaking>!?):\s*(?P<description>.*)$")
+        .unwrap()
+});
+
+/// A footer line: `Token: value` or `Token #value`
+pub static FOOTER_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^(?P<token>BREAKING CHANGE|[A-Za-z0-9-]+)(?P<separator>: | #)(?P<value>.*)$").unwrap()
+});
+
+/// Lines that look like a footer but have whitespace in the token
+static LOOSE_FOOTER_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^(?P<token>[A-Za-z][A-Za-z0-9 -]*[A-Za-z0-9]):\s").unwrap());
+
+static BREAKING_TOKEN_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^(?P<indent>\s*)(?P<token>(?i:breaking[ -]change))\b(?P<rest>.*)$").unwrap()
+});
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Footer {
+    pub token: String,
+    pub value: String,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Message<'a> {
+    pub header: &'a str,
+    /// Lines between the header and the footers, without the separating blank line
+    pub body: Vec<&'a str>,
+    pub footers: Vec<Footer>,
+    /// Index of the first footer line in the message, if there are footers
+    pub footer_line: Option<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Violation {
+    pub message: String,
+    /// Whether devmoji's default rules would have accepted the message here
+    pub diverges: bool,
+}
+
+impl Violation {
+    fn new(message: impl Into<String>, diverges: bool) -> Self {
+        Violation {
+            message: message.into(),
+            diverges,
+        }
+    }
+}
+
+impl std::fmt::Display for Violation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.diverges {
+            let message = tr!("{message} (accepted without --spec)", message = self.message);
+            write!(f, "{}", message)
+        } else {
+            write!(f, "{}", self.message)
+        }
+    }
+}
+
+/// Split a message into header, body and footers. The footer section is the
+/// last paragraph when it starts with a footer token.
+pub fn parse(text: &str) -> Message<'_> {
+    let mut lines = text.lines();
+    let header = lines.next().unwrap_or("");
+    let mut rest: Vec<&str> = lines.collect();
+    while rest.last().is_some_and(|l| l.trim().is_empty()) {
+        rest.pop();
+    }
+
+    let footer_start = rest
+        .iter()
+        .rposition(|l| l.trim().is_empty())
+        .map_or(0, |i| i + 1);
+    let has_footers = rest.get(footer_start).is_some_and(|l| FOOTER_RE.is_match(l));
+
+    let (body, footer_lines) = if has_footers {
+        rest.split_at(footer_start)
+    } else {
+        (&rest[..], &[][..])
+    };
+
+    let mut footers: Vec<Footer> = Vec::new();
+    for line in footer_lines {
+        match FOOTER_RE.captures(line) {
+            Some(caps) => footers.push(Footer {
+                token: caps["token"].to_string(),
+                value: caps["value"].to_string(),
+            }),
+            // Continuation of a multi-line footer value
+            None => {
+                if let Some(last) = footers.last_mut() {
+                    last.value.push('\n');
+                    last.value.push_str(line);
+                }
+            }
+        }
+    }
+
+    let mut body = body.to_vec();
+    if body.first().is_some_and(|l| l.trim().is_empty()) {
+        body.remove(0);
+    }
+    while body.last().is_some_and(|l| l.trim().is_empty()) {
+        body.pop();
+    }
+
+    Message {
+        header,
+        body,
+        footers,
+        footer_line: has_footers.then_some(footer_start + 1),
+    }
+}
+
+/// Whether the footers contain a breaking change (`BREAKING-CHANGE` is a synonym).
+pub fn has_breaking_footer(text: &str) -> bool {
+    parse(text).footers.iter().any(is_breaking)
+}
+
+fn is_breaking(footer: &Footer) -> bool {
+    footer.token == "BREAKING CHANGE" || footer.token == "BREAKING-CHANGE"
+}
+
+/// Check a commit message against the Conventional Commits 1.0.0 grammar.
+pub fn lint(text: &str) -> Vec<Violation> {
+    let mut violations = Vec::new();
+    let msg = parse(text);
+
+    lint_header(msg.header, &mut violations);
+
+    // The body must be separated from the header by a blank line
+    if text.lines().nth(1).is_some_and(|l| !l.trim().is_empty()) {
+        violations.push(Violation::new(
+            tr!("The body must begin one blank line after the description"),
+            true,
+        ));
+    }
+
+    // Footer tokens use `-` for whitespace, only BREAKING CHANGE is exempt.
+    // Only paragraphs made of footer-like lines are considered footers.
+    let paragraphs: Vec<&str> = text.trim_end().split("\n\n").collect();
+    if let Some(last) = paragraphs.last().filter(|_| paragraphs.len() > 1) {
+        let footer_like = |l: &str| {
+            FOOTER_RE.is_match(l) || LOOSE_FOOTER_RE.is_match(l) || BREAKING_TOKEN_RE.is_match(l)
+        };
+        if last.lines().all(footer_like) {
+            for line in last.lines() {
+                if FOOTER_RE.is_match(line) || BREAKING_TOKEN_RE.is_match(line) {
+                    continue;
+                }
+                if let Some(caps) = LOOSE_FOOTER_RE.captures(line) {
+                    let token = &caps["token"];
+                    violations.push(Violation::new(
+                        tr!(
+                            "Footer token '{token}' must use - in place of whitespace, e.g. '{suggestion}'",
+                            token = token,
+                            suggestion = token.replace(' ', "-")
+                        ),
+                        true,
+                    ));
+                }
+            }
+        }
+    }
+
+    // BREAKING CHANGE must be an uppercase footer with a description
+    for (i, line) in text.lines().enumerate().skip(1) {
+        let Some(caps) = BREAKING_TOKEN_RE.captures(line) else {
+            continue;
+        };
+        let token = &caps["token"];
+        let rest = &caps["rest"];
+        let uppercase = token == "BREAKING CHANGE" || token == "BREAKING-CHANGE";
+        // Lowercase mentions are only footers when followed by a colon
+        if !uppercase && !rest.starts_with(':') {
+            continue;
+        }
+        let problem = if !uppercase {
+            Some(("BREAKING CHANGE must be uppercase", true))
+        } else if !caps["indent"].is_empty() {
+            Some(("BREAKING CHANGE must start at the beginning of the line", true))
+        } else if !rest.starts_with(": ") || rest[1..].trim().is_empty() {
+            Some((
+                "BREAKING CHANGE must be followed by a colon, a space and a description",
+                true,
+            ))
+        } else if msg.footer_line.is_none_or(|start| i < start) {
+            Some(("BREAKING CHANGE must be a footer after the body", true))
+        } else {
+            None
+        };
+        if let Some((message, diverges)) = problem {
+            let message = tr!("Line {line}: {message}", line = i + 1, message = tr!(message));
+            violations.push(Violation::new(message, diverges));
+        }
+    }
+
+    violations
+}
+
+fn lint_header(header: &str, violations: &mut Vec<Violation>) {
+    if STRICT_HEADER_RE.is_match(header) {
+        return;
+    }
+
+    // Explain what is off, and whether devmoji would accept it without --spec
+    let Some(caps) = LOOSE_HEADER_RE.captures(header) else {
+        violations.push(Violation::new(
+            tr!("Expecting a header like: type(scope)!: description"),
+            false,
+        ));
+        return;
+    };
+
+    let commit_type = &caps["type"];
+    if !commit_type.chars().all(|c| c.is_ascii_alphabetic()) {
+        violations.push(Violation::new(
+            tr!(
+                "Type '{commit_type}' must be a noun made of letters only",
+                commit_type = commit_type
+            ),
+            true,
+        ));
+    }
+
+    let after_colon = header[caps.name("breaking").unwrap().end() + 1..].to_string();
+    if caps["description"].trim().is_empty() {
+        violations.push(Violation::new(tr!("Missing description"), false));
+    } else if !after_colon.starts_with(' ') || after_colon.starts_with("  ") {
+        violations.push(Violation::new(
+            tr!("The colon must be followed by exactly one space and the description"),
+            true,
+        ));
+    }
+}
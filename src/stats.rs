@@ -0,0 +1,257 @@
+use std::collections::HashMap;
+
+use serde::Serialize;
+
+use crate::commits::ConventionalCommits;
+use crate::config::Config;
+
+const SPARKS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum StatsFormat {
+    Text,
+    Json,
+}
+
+/// A commit to count, with its commit time when known. The message is only
+/// the subject when read from `--stdin`.
+pub struct Commit {
+    pub time: Option<i64>,
+    pub message: String,
+}
+
+impl Commit {
+    /// Parse a line of `git log --format=%ct%x09%s`, `git log --oneline` or a
+    /// plain subject.
+    pub fn parse(line: &str) -> Self {
+        if let Some((time, subject)) = line.split_once('\t') {
+            if let Ok(time) = time.trim().parse() {
+                return Commit {
+                    time: Some(time),
+                    message: subject.to_string(),
+                };
+            }
+        }
+        let is_hash = |s: &str| s.len() >= 7 && s.chars().all(|c| c.is_ascii_hexdigit());
+        let subject = match line.split_once(' ') {
+            Some((hash, rest)) if is_hash(hash) => rest,
+            _ => line,
+        };
+        Commit {
+            time: None,
+            message: subject.to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct Stats {
+    pub total: usize,
+    pub conventional: usize,
+    pub breaking: usize,
+    pub types: Vec<TypeStats>,
+    /// Commits without a conventional header
+    pub other: usize,
+    /// Commits over time, when every commit has a time
+    pub timeline: Option<Timeline>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TypeStats {
+    #[serde(rename = "type")]
+    pub commit_type: String,
+    pub emoji: String,
+    pub count: usize,
+    pub percent: f64,
+    pub scopes: Vec<ScopeStats>,
+    /// Commits per timeline bucket
+    pub timeline: Vec<usize>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ScopeStats {
+    pub scope: String,
+    pub emoji: String,
+    pub count: usize,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Timeline {
+    /// Unix time of the oldest commit
+    pub start: i64,
+    /// Unix time of the newest commit
+    pub end: i64,
+    pub bucket_seconds: i64,
+    pub counts: Vec<usize>,
+}
+
+/// Count commits per type and scope, and over time in `buckets` buckets.
+pub fn collect(cc: &ConventionalCommits, commits: &[Commit], buckets: usize) -> Stats {
+    let buckets = buckets.max(1);
+    let times: Option<Vec<i64>> = commits.iter().map(|c| c.time).collect();
+    let range = times
+        .as_ref()
+        .and_then(|t| Some((*t.iter().min()?, *t.iter().max()?)));
+    let bucket_seconds = range.map_or(1, |(start, end)| (end - start) / buckets as i64 + 1);
+    let bucket = |time: Option<i64>| -> Option<usize> {
+        let (start, _) = range?;
+        Some((((time? - start) / bucket_seconds) as usize).min(buckets - 1))
+    };
+
+    let mut types: HashMap<String, TypeStats> = HashMap::new();
+    let mut scopes: HashMap<(String, String), usize> = HashMap::new();
+    let mut timeline = vec![0; buckets];
+    let mut breaking = 0;
+    let mut other = 0;
+
+    for commit in commits {
+        if let Some(i) = bucket(commit.time) {
+            timeline[i] += 1;
+        }
+        // The whole message, so `BREAKING CHANGE:` footers count too
+        let Some(header) = cc.parse_message(&commit.message) else {
+            other += 1;
+            continue;
+        };
+        if header.breaking {
+            breaking += 1;
+        }
+
+        let commit_type = header.commit_type.to_lowercase();
+        let entry = types.entry(commit_type.clone()).or_insert_with(|| TypeStats {
+            emoji: cc.type_emoji(&commit_type, None),
+            commit_type: commit_type.clone(),
+            count: 0,
+            percent: 0.0,
+            scopes: Vec::new(),
+            timeline: vec![0; if range.is_some() { buckets } else { 0 }],
+        });
+        entry.count += 1;
+        if let Some(i) = bucket(commit.time) {
+            entry.timeline[i] += 1;
+        }
+        if let Some(scope) = header.scope {
+            *scopes.entry((commit_type, scope.to_lowercase())).or_default() += 1;
+        }
+    }
+
+    let total = commits.len();
+    for ((commit_type, scope), count) in scopes {
+        let emoji = cc.type_emoji(&commit_type, Some(&scope));
+        let entry = types.get_mut(&commit_type).unwrap();
+        entry.scopes.push(ScopeStats { scope, emoji, count });
+    }
+
+    let mut types: Vec<TypeStats> = types.into_values().collect();
+    for t in &mut types {
+        t.percent = percent(t.count, total);
+        t.scopes
+            .sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.scope.cmp(&b.scope)));
+    }
+    types.sort_by(|a, b| {
+        b.count
+            .cmp(&a.count)
+            .then_with(|| a.commit_type.cmp(&b.commit_type))
+    });
+
+    Stats {
+        total,
+        conventional: total - other,
+        breaking,
+        types,
+        other,
+        timeline: range.map(|(start, end)| Timeline {
+            start,
+            end,
+            bucket_seconds,
+            counts: timeline,
+        }),
+    }
+}
+
+pub fn render(stats: &Stats, cfg: &Config, format: StatsFormat, color: bool) -> String {
+    match format {
+        StatsFormat::Json => {
+            let mut out = serde_json::to_string_pretty(stats).unwrap();
+            out.push('\n');
+            out
+        }
+        StatsFormat::Text => render_text(stats, cfg, color),
+    }
+}
+
+fn render_text(stats: &Stats, cfg: &Config, color: bool) -> String {
+    let mut out = format!(
+        "{} commits, {} conventional, {} breaking\n",
+        stats.total, stats.conventional, stats.breaking
+    );
+    if let Some(timeline) = &stats.timeline {
+        let days = (timeline.end - timeline.start) / 86400 + 1;
+        let unit = if days == 1 { "day" } else { "days" };
+        out.push_str(&format!("{} over {} {}\n", sparkline(&timeline.counts), days, unit));
+    }
+    out.push('\n');
+
+    let width = stats
+        .types
+        .iter()
+        .flat_map(|t| {
+            let scopes = t.scopes.iter().map(|s| s.scope.len() + 2);
+            std::iter::once(t.commit_type.len()).chain(scopes)
+        })
+        .chain(std::iter::once("other".len()))
+        .max()
+        .unwrap_or(0);
+
+    for t in &stats.types {
+        let name = format!("{:width$}", t.commit_type, width = width);
+        let name = if color {
+            cfg.type_style(&t.commit_type).paint(&name)
+        } else {
+            name
+        };
+        // Emoji are two columns wide
+        let emoji = if t.emoji.is_empty() { "  " } else { &t.emoji };
+        let line = format!(
+            "{} {} {:5} {:5.1}%  {}",
+            emoji,
+            name,
+            t.count,
+            t.percent,
+            sparkline(&t.timeline)
+        );
+        out.push_str(line.trim_end());
+        out.push('\n');
+        for s in &t.scopes {
+            // Only show scope emoji that differ from the type's
+            let emoji = if s.emoji == t.emoji { "" } else { &s.emoji };
+            let name = format!("  {}", s.scope);
+            let line = format!("   {:width$} {:5}         {}", name, s.count, emoji, width = width);
+            out.push_str(line.trim_end());
+            out.push('\n');
+        }
+    }
+    if stats.other > 0 {
+        out.push_str(&format!(
+            "   {:width$} {:5} {:5.1}%\n",
+            "other",
+            stats.other,
+            percent(stats.other, stats.total),
+            width = width
+        ));
+    }
+    out
+}
+
+/// Percentage with one decimal
+fn percent(count: usize, total: usize) -> f64 {
+    (count as f64 * 1000.0 / total.max(1) as f64).round() / 10.0
+}
+
+fn sparkline(counts: &[usize]) -> String {
+    let max = counts.iter().copied().max().unwrap_or(0).max(1);
+    counts
+        .iter()
+        .map(|&c| SPARKS[(c * (SPARKS.len() - 1)).div_ceil(max)])
+        .collect()
+}
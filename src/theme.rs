@@ -0,0 +1,185 @@
+use std::collections::HashMap;
+
+#[cfg(feature = "color")]
+pub use colored::Colorize;
+#[cfg(feature = "color")]
+use colored::{Color, ColoredString};
+#[cfg(not(feature = "color"))]
+pub use plain::Colorize;
+#[cfg(not(feature = "color"))]
+use plain::Color;
+
+/// Turn colored output on or off, whatever the terminal supports
+pub fn set_override(color: bool) {
+    #[cfg(feature = "color")]
+    colored::control::set_override(color);
+    #[cfg(not(feature = "color"))]
+    let _ = color;
+}
+
+/// Style of a commit type in colored output, parsed from a spec like
+/// `green`, `bright red bold` or `#ff8800 italic`
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(not(feature = "color"), allow(dead_code))]
+pub struct Style {
+    color: Option<Color>,
+    bold: bool,
+    dimmed: bool,
+    italic: bool,
+    underline: bool,
+}
+
+impl Style {
+    pub fn parse(spec: &str) -> Result<Self, String> {
+        let mut style = Style::default();
+        let mut words = spec.split_whitespace().peekable();
+        while let Some(word) = words.next() {
+            match word.to_ascii_lowercase().as_str() {
+                "bold" => style.bold = true,
+                "dim" | "dimmed" => style.dimmed = true,
+                "italic" => style.italic = true,
+                "underline" => style.underline = true,
+                "bright" => {
+                    let name = words
+                        .next()
+                        .ok_or_else(|| format!("'{}': bright needs a color", spec))?;
+                    style.color = Some(parse_color(&format!("bright {}", name), spec)?);
+                }
+                name => style.color = Some(parse_color(name, spec)?),
+            }
+        }
+        Ok(style)
+    }
+
+    #[cfg(not(feature = "color"))]
+    pub fn paint(&self, text: &str) -> String {
+        text.to_string()
+    }
+
+    #[cfg(feature = "color")]
+    pub fn paint(&self, text: &str) -> String {
+        let mut s = ColoredString::from(text);
+        if let Some(color) = self.color {
+            s = s.color(color);
+        }
+        if self.bold {
+            s = s.bold();
+        }
+        if self.dimmed {
+            s = s.dimmed();
+        }
+        if self.italic {
+            s = s.italic();
+        }
+        if self.underline {
+            s = s.underline();
+        }
+        s.to_string()
+    }
+}
+
+fn parse_color(name: &str, spec: &str) -> Result<Color, String> {
+    if let Some(hex) = name.strip_prefix('#') {
+        let channel = |i: usize| {
+            hex.get(i..i + 2)
+                .and_then(|c| u8::from_str_radix(c, 16).ok())
+                .ok_or_else(|| format!("'{}': invalid hex color {}", spec, name))
+        };
+        if hex.len() != 6 {
+            return Err(format!("'{}': invalid hex color {}", spec, name));
+        }
+        return Ok(Color::TrueColor {
+            r: channel(0)?,
+            g: channel(2)?,
+            b: channel(4)?,
+        });
+    }
+    name.parse()
+        .map_err(|_| format!("'{}': unknown color or style {}", spec, name))
+}
+
+/// Type colors used unless the config overrides them. Other types are blue.
+pub fn default_type_styles() -> HashMap<String, Style> {
+    [
+        ("feat", "green"),
+        ("fix", "red"),
+        ("perf", "yellow"),
+        ("security", "bright red"),
+        ("docs", "cyan"),
+        ("chore", "dimmed"),
+        ("build", "dimmed"),
+        ("ci", "dimmed"),
+    ]
+    .into_iter()
+    .map(|(t, spec)| (t.to_string(), Style::parse(spec).unwrap()))
+    .collect()
+}
+
+/// Fallback style of types without a color
+pub fn default_style() -> Style {
+    Style {
+        color: Some(Color::Blue),
+        ..Style::default()
+    }
+}
+
+/// Stand-ins for `colored` without the `color` feature: styles are still
+/// checked, but text is never painted
+#[cfg(not(feature = "color"))]
+mod plain {
+    const NAMES: &[&str] =
+        &["black", "red", "green", "yellow", "blue", "magenta", "purple", "cyan", "white"];
+
+    /// The variants of `colored::Color` the styles are built from
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    #[allow(dead_code, clippy::enum_variant_names)]
+    pub enum Color {
+        Blue,
+        Named,
+        TrueColor { r: u8, g: u8, b: u8 },
+    }
+
+    /// The names `colored` knows
+    impl std::str::FromStr for Color {
+        type Err = ();
+
+        fn from_str(name: &str) -> Result<Self, ()> {
+            let name = name.to_lowercase();
+            let known = match name.strip_prefix("bright ") {
+                Some(name) => name != "purple" && NAMES.contains(&name),
+                None => NAMES.contains(&name.as_str()),
+            };
+            known.then_some(Color::Named).ok_or(())
+        }
+    }
+
+    pub trait Colorize {
+        fn red(&self) -> String;
+        fn green(&self) -> String;
+        fn yellow(&self) -> String;
+        fn cyan(&self) -> String;
+        fn bold(&self) -> String;
+        fn dimmed(&self) -> String;
+    }
+
+    impl<T: AsRef<str> + ?Sized> Colorize for T {
+        fn red(&self) -> String {
+            self.as_ref().to_string()
+        }
+        fn green(&self) -> String {
+            self.as_ref().to_string()
+        }
+        fn yellow(&self) -> String {
+            self.as_ref().to_string()
+        }
+        fn cyan(&self) -> String {
+            self.as_ref().to_string()
+        }
+        fn bold(&self) -> String {
+            self.as_ref().to_string()
+        }
+        fn dimmed(&self) -> String {
+            self.as_ref().to_string()
+        }
+    }
+}
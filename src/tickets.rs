@@ -0,0 +1,58 @@
+use regex::{Captures, Regex};
+
+use crate::config::ConfigTicket;
+
+/// An issue/ticket key pattern from the config, e.g. `JIRA-\d+` or `#\d+`
+#[derive(Debug, Clone)]
+pub struct Ticket {
+    pub pattern: Regex,
+    /// Link template, `{key}` is the matched key and `{name}` any named group
+    pub url: Option<String>,
+    /// Shortcode added to the header emoji when the key is referenced
+    pub emoji: Option<String>,
+}
+
+impl Ticket {
+    pub fn compile(cfg: &ConfigTicket) -> Result<Self, String> {
+        let pattern = Regex::new(&cfg.pattern).map_err(|e| e.to_string())?;
+        Ok(Ticket {
+            pattern,
+            url: cfg.url.clone(),
+            emoji: cfg.emoji.clone(),
+        })
+    }
+
+    fn link(&self, caps: &Captures) -> Option<String> {
+        let mut url = self.url.as_ref()?.replace("{key}", &caps[0]);
+        for name in self.pattern.capture_names().flatten() {
+            if let Some(m) = caps.name(name) {
+                url = url.replace(&format!("{{{}}}", name), m.as_str());
+            }
+        }
+        Some(url)
+    }
+}
+
+/// Emoji codes of the tickets referenced in `text`, in config order.
+pub fn referenced_emoji<'t>(tickets: &'t [Ticket], text: &str) -> Vec<&'t str> {
+    tickets
+        .iter()
+        .filter(|t| t.pattern.is_match(text))
+        .filter_map(|t| t.emoji.as_deref())
+        .collect()
+}
+
+/// Wrap every ticket key that has a url in an OSC 8 terminal hyperlink.
+pub fn hyperlink(tickets: &[Ticket], text: &str) -> String {
+    let mut text = text.to_string();
+    for ticket in tickets.iter().filter(|t| t.url.is_some()) {
+        text = ticket
+            .pattern
+            .replace_all(&text, |caps: &Captures| match ticket.link(caps) {
+                Some(url) => format!("\x1b]8;;{}\x1b\\{}\x1b]8;;\x1b\\", url, &caps[0]),
+                None => caps[0].to_string(),
+            })
+            .into_owned();
+    }
+    text
+}
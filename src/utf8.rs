@@ -0,0 +1,43 @@
+//! Input that isn't valid UTF-8, e.g. a log with Latin-1 author names, is
+//! processed with each invalid byte stood in for by a private use character,
+//! and the bytes are put back on output. Nothing is lost or replaced.
+
+use std::borrow::Cow;
+
+/// The first of the 256 characters standing in for bytes, in the
+/// supplementary private use area B that text hardly ever uses
+const ESCAPE_BASE: u32 = 0x10FF00;
+
+/// `bytes` as text, with the bytes that aren't valid UTF-8 escaped
+pub fn decode(bytes: &[u8]) -> Cow<'_, str> {
+    if let Ok(text) = std::str::from_utf8(bytes) {
+        return Cow::Borrowed(text);
+    }
+    let mut text = String::with_capacity(bytes.len());
+    for chunk in bytes.utf8_chunks() {
+        text.push_str(chunk.valid());
+        for &byte in chunk.invalid() {
+            text.push(char::from_u32(ESCAPE_BASE + u32::from(byte)).unwrap());
+        }
+    }
+    Cow::Owned(text)
+}
+
+/// `text` as bytes, with the bytes `decode` escaped put back
+pub fn encode(text: &str) -> Cow<'_, [u8]> {
+    if !text.chars().any(|c| escaped_byte(c).is_some()) {
+        return Cow::Borrowed(text.as_bytes());
+    }
+    let mut bytes = Vec::with_capacity(text.len());
+    for c in text.chars() {
+        match escaped_byte(c) {
+            Some(byte) => bytes.push(byte),
+            None => bytes.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes()),
+        }
+    }
+    Cow::Owned(bytes)
+}
+
+fn escaped_byte(c: char) -> Option<u8> {
+    u8::try_from(u32::from(c).checked_sub(ESCAPE_BASE)?).ok()
+}
@@ -0,0 +1,142 @@
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::{Command, Output, Stdio};
+
+use unicode_width::UnicodeWidthStr;
+
+/// An empty directory to run devmoji in, also as its home, so no config
+/// around the checkout is picked up
+fn sandbox(name: &str) -> PathBuf {
+    let dir = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join(name);
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn devmoji(dir: &PathBuf, args: &[&str], stdin: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_devmoji"))
+        .args(args)
+        .current_dir(dir)
+        .env("HOME", dir)
+        .env("XDG_CONFIG_HOME", dir.join(".config"))
+        .env("XDG_CACHE_HOME", dir.join(".cache"))
+        .env("LANG", "en_US.UTF-8")
+        .env_remove("DEVMOJI_LOG")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child.stdin.take().unwrap().write_all(stdin.as_bytes()).unwrap();
+    child.wait_with_output().unwrap()
+}
+
+fn stdout(output: &Output) -> String {
+    String::from_utf8(output.stdout.clone()).unwrap()
+}
+
+#[test]
+fn log_format_revert() {
+    let dir = sandbox("log_format_revert");
+    let template = "%{emoji} %{type}: %{subject}";
+    let output = devmoji(
+        &dir,
+        &["--color", "false", "--log-format", template],
+        "abc1234 Revert \"fix: crash\"\n",
+    );
+    assert_eq!(stdout(&output), "abc1234 \u{23ea} Revert \"fix: \u{1f41b} crash\"\n");
+}
+
+#[test]
+fn log_format_merge() {
+    let dir = sandbox("log_format_merge");
+    let template = "%{emoji} %{type}: %{subject} %{[hash]}";
+    let output = devmoji(
+        &dir,
+        &["--color", "false", "--log-format", template],
+        "abc1234 Merge branch 'dev'\n",
+    );
+    assert_eq!(stdout(&output), "abc1234 \u{1f500} Merge branch 'dev'\n");
+}
+
+fn git(dir: &PathBuf, args: &[&str]) {
+    let status = Command::new("git")
+        .args(args)
+        .current_dir(dir)
+        .env("HOME", dir)
+        .env("GIT_AUTHOR_NAME", "devmoji")
+        .env("GIT_AUTHOR_EMAIL", "devmoji@example.com")
+        .env("GIT_COMMITTER_NAME", "devmoji")
+        .env("GIT_COMMITTER_EMAIL", "devmoji@example.com")
+        .status()
+        .unwrap();
+    assert!(status.success());
+}
+
+#[test]
+fn stats_breaking_footer() {
+    let dir = sandbox("stats_breaking_footer");
+    git(&dir, &["init", "-q"]);
+    let message = "feat: new config\n\nBREAKING CHANGE: the old one is gone";
+    git(&dir, &["commit", "-q", "--allow-empty", "-m", message]);
+    git(&dir, &["commit", "-q", "--allow-empty", "-m", "fix: crash"]);
+    let output = devmoji(&dir, &["--color", "false", "stats"], "");
+    let first = stdout(&output).lines().next().unwrap().to_string();
+    assert_eq!(first, "2 commits, 2 conventional, 1 breaking");
+    assert!(stdout(&output).contains(" over 1 day\n"));
+}
+
+#[test]
+fn filter_uninstall_keeps_other_attributes() {
+    let dir = sandbox("filter_uninstall_keeps_other_attributes");
+    git(&dir, &["init", "-q"]);
+    let attributes = "*.md text eol=lf filter=devmoji\n*.txt filter=devmoji\n*.sh eol=lf\n";
+    std::fs::write(dir.join(".gitattributes"), attributes).unwrap();
+    let output = devmoji(&dir, &["filter", "uninstall"], "");
+    assert!(output.status.success());
+    let attributes = std::fs::read_to_string(dir.join(".gitattributes")).unwrap();
+    assert_eq!(attributes, "*.md text eol=lf\n*.sh eol=lf\n");
+}
+
+#[test]
+fn hints_align_wide_types() {
+    let dir = sandbox("hints_align_wide_types");
+    git(&dir, &["init", "-q"]);
+    let config = r#"{
+        "types": ["feat", "修正"],
+        "devmoji": [{"code": "修正", "emoji": "wrench", "description": "a fix"}]
+    }"#;
+    std::fs::write(dir.join("devmoji.config.json"), config).unwrap();
+    std::fs::write(dir.join("MSG"), "feat: x\n").unwrap();
+    let output = devmoji(&dir, &["--color", "false", "-e", "MSG", "--hints"], "");
+    assert!(output.status.success());
+    let message = std::fs::read_to_string(dir.join("MSG")).unwrap();
+    let column = |needle: &str| {
+        let line = message.lines().find(|l| l.contains(needle)).unwrap();
+        line[..line.find(needle).unwrap()].width()
+    };
+    assert_eq!(column("a fix"), column("a new feature"));
+}
+
+#[test]
+fn edit_rebase_todo_fixup_flags() {
+    let dir = sandbox("edit_rebase_todo_fixup_flags");
+    git(&dir, &["init", "-q"]);
+    let todo = dir.join(".git").join("git-rebase-todo");
+    std::fs::write(&todo, "pick abc1234 feat: a\nfixup -C def5678 fix: b\n# fix: c\n").unwrap();
+    let output = devmoji(&dir, &["--color", "false", "-e", ".git/git-rebase-todo"], "");
+    assert!(output.status.success());
+    assert_eq!(
+        std::fs::read_to_string(&todo).unwrap(),
+        "pick abc1234 feat: \u{2728} a\nfixup -C def5678 fix: \u{1f41b} b\n# fix: c\n"
+    );
+}
+
+#[test]
+fn texts_named_like_subcommands() {
+    let dir = sandbox("texts_named_like_subcommands");
+    let output = devmoji(&dir, &["--color", "false", "feat: a", "--", "stats"], "");
+    assert_eq!(stdout(&output), "feat: \u{2728} a\nstats\n");
+    let output = devmoji(&dir, &["--color", "false", "--text", "stats"], "");
+    assert_eq!(stdout(&output), "stats\n");
+}